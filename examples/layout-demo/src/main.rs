@@ -4,7 +4,7 @@ use lipgloss::security::safe_str_repeat;
 use lipgloss::{
     blend_1d, join_horizontal, join_vertical, normal_border, place, rounded_border,
     whitespace::{with_whitespace_chars, with_whitespace_foreground},
-    width, AdaptiveColor, Color, Style, BOTTOM, CENTER, LEFT, RIGHT, TOP,
+    width, AdaptiveColor, BorderPiece, Color, Style, BOTTOM, CENTER, LEFT, RIGHT, TOP,
 };
 use std::cmp::max;
 use std::env;
@@ -71,18 +71,10 @@ fn main() {
     let url = |s: &str| Style::new().foreground(special.clone()).render(s);
 
     // Tabs
-    let mut active_tab_border = rounded_border();
-    active_tab_border.bottom = " ";
-    active_tab_border.bottom_left = "┘";
-    active_tab_border.bottom_right = "└";
-
-    let mut tab_border = rounded_border();
-    tab_border.bottom = "─";
-    tab_border.bottom_left = "┴";
-    tab_border.bottom_right = "┴";
-
     let tab = Style::new()
-        .border_style(tab_border)
+        .border_style(rounded_border())
+        .border_char(BorderPiece::BottomLeft, "┴")
+        .border_char(BorderPiece::BottomRight, "┴")
         .border_foreground(highlight.clone())
         .padding(0, 1, 0, 1)
         .border_top(true)
@@ -92,7 +84,11 @@ fn main() {
 
     // Active tab: keep bottom border enabled but draw it as spaces (Go parity)
     // This preserves equal height across tabs while visually hiding the bottom edge.
-    let active_tab = tab.clone().border_style(active_tab_border);
+    let active_tab = tab
+        .clone()
+        .border_char(BorderPiece::Bottom, " ")
+        .border_char(BorderPiece::BottomLeft, "┘")
+        .border_char(BorderPiece::BottomRight, "└");
 
     // Title
     let title_style = Style::new()
@@ -0,0 +1,13 @@
+//! Simple list example.
+
+use lipgloss_list::{roman, List};
+
+/// Builds the simple list example: a bullet list with a nested roman-numeral sublist.
+pub fn simple_list() -> List {
+    List::new()
+        .item("A")
+        .item("B")
+        .item("C")
+        .item_list(List::new().items(vec!["D", "E", "F"]).enumerator(roman))
+        .item("G")
+}
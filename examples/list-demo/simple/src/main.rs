@@ -1,12 +1,5 @@
-use lipgloss_list::{roman, List};
+use simple_list_demo::simple_list;
 
 fn main() {
-    let l = List::new()
-        .item("A")
-        .item("B")
-        .item("C")
-        .item_list(List::new().items(vec!["D", "E", "F"]).enumerator(roman))
-        .item("G");
-
-    println!("{}", l);
+    println!("{}", simple_list());
 }
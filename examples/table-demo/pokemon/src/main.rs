@@ -43,16 +43,18 @@ fn main() {
 
     let capitalize_headers = |h: Vec<&str>| h.iter().map(|s| s.to_uppercase()).collect::<Vec<_>>();
 
-    let data_clone = data.clone();
-    let style_func = move |row: i32, col: usize| {
+    // The style function gets each cell's content, so only the selected
+    // row needs to be looked up ahead of time.
+    let selected_row = data.iter().position(|row| row[1] == "Pikachu");
+    let style_func = move |row: i32, col: usize, content: &str| {
         if row == HEADER_ROW {
             return header_style.clone();
         }
-        if data_clone[row as usize][1] == "Pikachu" {
+        if Some(row as usize) == selected_row {
             return selected_style.clone();
         }
         if col == 2 || col == 3 {
-            if let Some(color) = type_colors.get(data_clone[row as usize][col]) {
+            if let Some(color) = type_colors.get(content) {
                 return base_style.clone().foreground(color.clone());
             }
         }
@@ -72,7 +74,7 @@ fn main() {
         ]))
         .width(80)
         .rows(data)
-        .style_func_boxed(style_func);
+        .style_func_with_data(style_func);
 
     println!("{}", t);
 }
@@ -10,6 +10,7 @@
 //! - **Text Color Hierarchy**: Primary, muted, subtle, and header text colors
 //! - **Status Colors**: Success, warning, error, and info indicators
 //! - **Surface Colors**: Background colors for cards and elevated elements
+//! - **Adaptive Gradients**: Blended colors that keep their light and dark variants
 //! - **Interactive Elements**: Accent colors for buttons and highlights
 //! - **Component Integration**: Lists, tables, and complex layouts with unified theming
 //! - **Theme Detection**: Automatic light/dark background detection
@@ -84,10 +85,11 @@ use lipgloss_table::{Table, HEADER_ROW};
 /// 3. **Accent & Interactive Colors**: Brand colors and interactive element styling
 /// 4. **Status Colors**: Success, warning, error, and info indicators
 /// 5. **Surfaces & Borders**: Card backgrounds and border styling
-/// 6. **List Components**: Styled lists with adaptive enumerators and items
-/// 7. **Table Components**: Complex tables with alternating row colors and headers
-/// 8. **Complex Layouts**: Multi-panel dashboard-style layouts
-/// 9. **Theme Testing Instructions**: Guide for testing different themes
+/// 6. **Adaptive Gradients**: A gradient bar blended from adaptive accent colors
+/// 7. **List Components**: Styled lists with adaptive enumerators and items
+/// 8. **Table Components**: Complex tables with alternating row colors and headers
+/// 9. **Complex Layouts**: Multi-panel dashboard-style layouts
+/// 10. **Theme Testing Instructions**: Guide for testing different themes
 ///
 /// ## Color Adaptation
 ///
@@ -282,6 +284,18 @@ fn main() {
         println!("{}", elevated_card);
     });
 
+    // Adaptive Gradient Showcase
+    print_section("Adaptive Gradients", || {
+        // Blending keeps both variants, so the bar still adapts when drawn.
+        let gradient =
+            lipgloss::blend_1d_adaptive(40, vec![accent_primary.clone(), accent_secondary.clone()]);
+        let bar: String = gradient
+            .into_iter()
+            .map(|color| Style::new().background(color).render(" "))
+            .collect();
+        println!("  {}", bar);
+    });
+
     // Define list colors
     let list_enumerator = AdaptiveColor {
         Light: "#7C3AED",
@@ -0,0 +1,38 @@
+use lipgloss::{Color, Style};
+use lipgloss_tree::{IndentContext, Tree};
+
+/// Dim dotted guides: `┊` below top-level items, `╎` further in.
+fn dotted_guides(ctx: &IndentContext) -> String {
+    if ctx.is_last {
+        return "    ".to_string();
+    }
+    let guide = if ctx.depth == 0 { "┊" } else { "╎" };
+    format!(
+        "{}   ",
+        Style::new().foreground(Color::from("8")).render(guide)
+    )
+}
+
+/// A tree node whose children are indented with dotted guides.
+fn node(root: &str) -> Tree {
+    Tree::new().root(root).indenter_with_context(dotted_guides)
+}
+
+fn main() {
+    let t = node("~/projects").child(vec![
+        node("lipgloss")
+            .child(vec![
+                node("src")
+                    .child(vec!["lib.rs".into(), "style.rs".into()])
+                    .into(),
+                "Cargo.toml".into(),
+            ])
+            .into(),
+        node("notes")
+            .child(vec!["todo.md".into(), "ideas.md".into()])
+            .into(),
+        "README.md".into(),
+    ]);
+
+    println!("{}", t);
+}
@@ -77,15 +77,41 @@ fn main() {
         .enumerator(rounded_enumerator)
         .enumerator_style(enumerator_style)
         .child(vec![
-            Box::new(Leaf::new(
-                Dir {
-                    name: "ayman".to_string(),
-                    open: false,
-                    styles: s.clone(),
-                }
-                .to_string(),
-                false,
-            )) as Box<dyn Node>,
+            // A closed directory collapses: its files are drawn as a marker.
+            Box::new(
+                Tree::new()
+                    .root(
+                        Dir {
+                            name: "ayman".to_string(),
+                            open: false,
+                            styles: s.clone(),
+                        }
+                        .to_string(),
+                    )
+                    .child(vec![
+                        Box::new(Leaf::new(
+                            File {
+                                name: "dotfiles".to_string(),
+                                styles: s.clone(),
+                            }
+                            .to_string(),
+                            false,
+                        )) as Box<dyn Node>,
+                        Box::new(Leaf::new(
+                            File {
+                                name: "notes.md".to_string(),
+                                styles: s.clone(),
+                            }
+                            .to_string(),
+                            false,
+                        )) as Box<dyn Node>,
+                    ])
+                    .collapse(true)
+                    .collapsed_label_fn({
+                        let file = s.file.clone();
+                        move |hidden| file.render(&format!("{} files", hidden))
+                    }),
+            ) as Box<dyn Node>,
             Box::new(
                 Tree::new()
                     .root(
@@ -177,8 +203,5 @@ fn main() {
             )) as Box<dyn Node>,
         ]);
     // Render the tree and apply block styling with background
-    // Remove reset codes from tree content to prevent background interference
-    let tree_content = t.to_string();
-    let cleaned_content = tree_content.replace("\x1b[0m", "");
-    println!("{}", block.render(&cleaned_content));
+    println!("{}", block.render(&t.to_string()));
 }
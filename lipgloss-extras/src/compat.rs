@@ -0,0 +1,62 @@
+//! Compile-time checks that the component crates share one `lipgloss`.
+//!
+//! Each function here hands a [`Style`] from the facade's `lipgloss` to a
+//! component crate. If a component was built against a different release of
+//! `lipgloss`, its `Style` is a different type and this module fails to
+//! compile, pointing at the mismatch rather than at user code. The functions
+//! are also usable as-is, to apply one style throughout a component.
+//!
+//! See [`version_report`](crate::version_report) for the versions in use.
+
+#[cfg(any(feature = "lists", feature = "trees", feature = "tables"))]
+use crate::lipgloss::Style;
+
+/// Styles a list's enumerators and items with `style`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_extras::{compat, lipgloss::Style, list::List};
+///
+/// let list = compat::style_list(List::new().item("a"), Style::new().bold(true));
+/// assert!(list.to_string().contains('a'));
+/// ```
+#[cfg(feature = "lists")]
+pub fn style_list(list: crate::list::List, style: Style) -> crate::list::List {
+    list.enumerator_style(style.clone()).item_style(style)
+}
+
+/// Styles a tree's root, enumerators and items with `style`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_extras::{compat, lipgloss::Style, tree::Tree};
+///
+/// let tree = compat::style_tree(Tree::new().root("r"), Style::new().bold(true));
+/// assert!(tree.to_string().contains('r'));
+/// ```
+#[cfg(feature = "trees")]
+pub fn style_tree(tree: crate::tree::Tree, style: Style) -> crate::tree::Tree {
+    tree.root_style(style.clone())
+        .enumerator_style(style.clone())
+        .item_style(style)
+}
+
+/// Styles a table's border and every cell with `style`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_extras::{compat, lipgloss::Style, table::Table};
+///
+/// let mut table = compat::style_table(Table::new().row(vec!["x"]), Style::new());
+/// assert!(table.render().contains('x'));
+/// ```
+#[cfg(feature = "tables")]
+pub fn style_table(table: crate::table::Table, style: Style) -> crate::table::Table {
+    let cells = style.clone();
+    table
+        .border_style(style)
+        .style_func_boxed(move |_, _| cells.clone())
+}
@@ -6,6 +6,10 @@
 //!   - `trees`  → `lipgloss_extras::tree`
 //!   - `tables` → `lipgloss_extras::table`
 //! - `full` enables all of the above.
+//! - [`macros`] provides the `styled!` shorthand, plus `table!` and
+//!   `print_table!` when `tables` is enabled.
+//! - [`version_report`] lists the version of each component, and [`compat`]
+//!   checks at compile time that they share one `lipgloss`.
 //!
 //! Example:
 //! ```toml
@@ -21,6 +25,9 @@
 
 pub use lipgloss;
 
+pub mod compat;
+pub mod macros;
+
 #[cfg(feature = "lists")]
 pub use lipgloss_list as list;
 
@@ -30,10 +37,72 @@ pub use lipgloss_tree as tree;
 #[cfg(feature = "tables")]
 pub use lipgloss_table as table;
 
+/// Lists the version of this crate and of each component crate.
+///
+/// One line per crate, as `name version`. Components whose feature is off
+/// are listed as not enabled, and a component released separately from
+/// this crate is flagged, since mixing releases is the usual cause of
+/// "expected `Style`, found `Style`" errors. Each version comes from the
+/// component's own `version()`, so the report shows the releases that were
+/// actually linked, not the ones `Cargo.toml` asked for.
+///
+/// # Examples
+///
+/// ```rust
+/// let report = lipgloss_extras::version_report();
+/// let first = report.lines().next().unwrap();
+/// assert_eq!(first, format!("lipgloss-extras {}", env!("CARGO_PKG_VERSION")));
+/// assert!(report.contains("lipgloss "));
+/// ```
+pub fn version_report() -> String {
+    let own = env!("CARGO_PKG_VERSION");
+    let components: [(&str, Option<&str>); 4] = [
+        ("lipgloss", Some(lipgloss::version())),
+        (
+            "lipgloss-list",
+            #[cfg(feature = "lists")]
+            Some(list::version()),
+            #[cfg(not(feature = "lists"))]
+            None,
+        ),
+        (
+            "lipgloss-tree",
+            #[cfg(feature = "trees")]
+            Some(tree::version()),
+            #[cfg(not(feature = "trees"))]
+            None,
+        ),
+        (
+            "lipgloss-table",
+            #[cfg(feature = "tables")]
+            Some(table::version()),
+            #[cfg(not(feature = "tables"))]
+            None,
+        ),
+    ];
+
+    let mut report = format!("lipgloss-extras {own}");
+    for (name, version) in components {
+        report.push('\n');
+        match version {
+            Some(version) if version == own => report.push_str(&format!("{name} {version}")),
+            Some(version) => {
+                report.push_str(&format!("{name} {version} (differs from lipgloss-extras)"))
+            }
+            None => report.push_str(&format!("{name} (not enabled)")),
+        }
+    }
+    report
+}
+
 /// Commonly-used items re-exported for convenience.
 #[allow(ambiguous_glob_reexports)]
 pub mod prelude {
     pub use crate::lipgloss::{self, *};
+    pub use crate::styled;
+
+    #[cfg(feature = "tables")]
+    pub use crate::print_table;
 
     #[cfg(feature = "lists")]
     pub use crate::list::*;
@@ -0,0 +1,195 @@
+//! Shorthand macros for quick styled output.
+//!
+//! [`styled!`](crate::styled) expands to a
+//! [`Style`](lipgloss::Style) builder chain followed by `render`, and
+//! [`table!`](crate::table) / [`print_table!`](crate::print_table) (which also
+//! need the `tables` feature) build a plain [`Table`](lipgloss_table::Table).
+//!
+//! Attribute names are checked at compile time: an unknown key is reported as
+//! a compile error that lists the accepted keys.
+//!
+//! ```rust
+//! use lipgloss_extras::styled;
+//!
+//! let title = styled!("Hello", bold, fg = "#ff0000", pad = (0, 1));
+//! println!("{}", title);
+//! ```
+
+/// Renders text with a style built from a list of attributes.
+///
+/// Flags: `bold`, `italic`, `underline`, `strikethrough`, `faint`, `blink`,
+/// `reverse`.
+///
+/// Keys: `fg`, `bg`, `width`, `height`, `pad` and `margin`. `pad` and
+/// `margin` take one value (all sides), a `(vertical, horizontal)` pair, or
+/// a `(top, right, bottom, left)` tuple.
+///
+/// ```rust
+/// use lipgloss_extras::lipgloss::Style;
+/// use lipgloss_extras::styled;
+///
+/// let by_macro = styled!("Hi", bold, fg = "#ff0000", pad = (0, 1));
+/// let by_hand = Style::new()
+///     .bold(true)
+///     .foreground("#ff0000")
+///     .padding(0, 1, 0, 1)
+///     .render("Hi");
+/// assert_eq!(by_macro, by_hand);
+/// ```
+#[macro_export]
+macro_rules! styled {
+    ($text:expr $(,)?) => {
+        $crate::lipgloss::Style::new().render(&$text)
+    };
+    ($text:expr, $($attrs:tt)+) => {
+        $crate::__styled_attrs!($crate::lipgloss::Style::new(); $($attrs)+).render(&$text)
+    };
+}
+
+/// Applies `styled!` attributes one at a time.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __styled_attrs {
+    ($style:expr;) => { $style };
+
+    // Flags
+    ($style:expr; bold $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.bold(true); $($($rest)*)?)
+    };
+    ($style:expr; italic $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.italic(true); $($($rest)*)?)
+    };
+    ($style:expr; underline $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.underline(true); $($($rest)*)?)
+    };
+    ($style:expr; strikethrough $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.strikethrough(true); $($($rest)*)?)
+    };
+    ($style:expr; faint $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.faint(true); $($($rest)*)?)
+    };
+    ($style:expr; blink $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.blink(true); $($($rest)*)?)
+    };
+    ($style:expr; reverse $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.reverse(true); $($($rest)*)?)
+    };
+
+    // Keys with values
+    ($style:expr; fg = $v:expr $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.foreground($v); $($($rest)*)?)
+    };
+    ($style:expr; bg = $v:expr $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.background($v); $($($rest)*)?)
+    };
+    ($style:expr; width = $v:expr $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.width($v); $($($rest)*)?)
+    };
+    ($style:expr; height = $v:expr $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.height($v); $($($rest)*)?)
+    };
+    ($style:expr; pad = ($t:expr, $r:expr, $b:expr, $l:expr) $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.padding($t, $r, $b, $l); $($($rest)*)?)
+    };
+    ($style:expr; pad = ($v:expr, $h:expr) $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.padding($v, $h, $v, $h); $($($rest)*)?)
+    };
+    ($style:expr; pad = $a:expr $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.padding($a, $a, $a, $a); $($($rest)*)?)
+    };
+    ($style:expr; margin = ($t:expr, $r:expr, $b:expr, $l:expr) $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.margin($t, $r, $b, $l); $($($rest)*)?)
+    };
+    ($style:expr; margin = ($v:expr, $h:expr) $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.margin($v, $h, $v, $h); $($($rest)*)?)
+    };
+    ($style:expr; margin = $a:expr $(, $($rest:tt)*)?) => {
+        $crate::__styled_attrs!($style.margin($a, $a, $a, $a); $($($rest)*)?)
+    };
+
+    // Errors
+    ($style:expr; $key:ident = $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown styled! key `", ::core::stringify!($key),
+            "`; expected one of: fg, bg, width, height, pad, margin"
+        ))
+    };
+    ($style:expr; $flag:ident $(, $($rest:tt)*)?) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown styled! flag `", ::core::stringify!($flag),
+            "`; expected one of: bold, italic, underline, strikethrough, faint, blink, reverse"
+        ))
+    };
+    ($style:expr; $($rest:tt)*) => {
+        ::core::compile_error!(::core::concat!(
+            "invalid styled! attribute `", ::core::stringify!($($rest)*),
+            "`; expected a flag like `bold` or a key like `fg = \"#ff0000\"`"
+        ))
+    };
+}
+
+/// Builds a plain table from headers and rows.
+///
+/// The table uses the plain [`normal_border`](lipgloss::normal_border) and the unstyled
+/// [`default_styles`](lipgloss_table::default_styles) cells. Both keys are
+/// optional, but must appear in this order.
+///
+/// ```rust
+/// use lipgloss_extras::lipgloss::normal_border;
+/// use lipgloss_extras::table;
+/// use lipgloss_extras::table::Table;
+///
+/// let mut by_macro = table!(headers = ["Name", "Age"], rows = [["Alice", "30"], ["Bob", "25"]]);
+/// let mut by_hand = Table::new()
+///     .border(normal_border())
+///     .headers(vec!["Name", "Age"])
+///     .rows(vec![vec!["Alice", "30"], vec!["Bob", "25"]]);
+/// assert_eq!(by_macro.render(), by_hand.render());
+/// ```
+#[cfg(feature = "tables")]
+#[macro_export]
+macro_rules! table {
+    ($(headers = [$($h:expr),* $(,)?])? $(,)? $(rows = [$([$($c:expr),* $(,)?]),* $(,)?])? $(,)?) => {{
+        #[allow(unused_mut)]
+        let mut table = $crate::table::Table::new().border($crate::lipgloss::normal_border());
+        $( table = table.headers(::std::vec![$(::std::string::ToString::to_string(&$h)),*]); )?
+        $( $( table = table.row(::std::vec![$(::std::string::ToString::to_string(&$c)),*]); )* )?
+        table
+    }};
+    ($($key:ident = $value:tt),* $(,)?) => {
+        $crate::__table_keys!($($key)*)
+    };
+}
+
+/// Reports the first unknown `table!` key, or a misordered key list.
+#[cfg(feature = "tables")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __table_keys {
+    () => {
+        ::core::compile_error!("table! expects `headers = [...]` followed by `rows = [[...], ...]`")
+    };
+    (headers $($rest:ident)*) => { $crate::__table_keys!($($rest)*) };
+    (rows $($rest:ident)*) => { $crate::__table_keys!($($rest)*) };
+    ($key:ident $($rest:ident)*) => {
+        ::core::compile_error!(::core::concat!(
+            "unknown table! key `", ::core::stringify!($key),
+            "`; expected `headers` or `rows`"
+        ))
+    };
+}
+
+/// Builds a plain table with [`table!`](crate::table) and prints it.
+///
+/// ```rust
+/// use lipgloss_extras::print_table;
+///
+/// print_table!(headers = ["Name", "Age"], rows = [["Alice", "30"]]);
+/// ```
+#[cfg(feature = "tables")]
+#[macro_export]
+macro_rules! print_table {
+    ($($args:tt)*) => {
+        ::std::println!("{}", $crate::table!($($args)*).render())
+    };
+}
@@ -0,0 +1,81 @@
+use lipgloss_extras::lipgloss::{ColorProfileKind, Renderer, Style};
+use lipgloss_extras::list::List;
+use lipgloss_extras::table::Table;
+use lipgloss_extras::tree::{Node, Tree};
+use lipgloss_extras::{compat, lipgloss, list, table, tree, version_report};
+
+/// A red style, rendered in true color so the output shows it was applied.
+fn red() -> Style {
+    let mut renderer = Renderer::new();
+    renderer.set_color_profile(ColorProfileKind::TrueColor);
+    Style::new().renderer(renderer).foreground("#ff0000")
+}
+
+const RED: &str = "\x1b[38;2;255;0;0m";
+
+#[test]
+fn version_report_lists_every_component() {
+    let report = version_report();
+    let expected = [
+        ("lipgloss-extras", env!("CARGO_PKG_VERSION")),
+        ("lipgloss", lipgloss::version()),
+        ("lipgloss-list", list::version()),
+        ("lipgloss-tree", tree::version()),
+        ("lipgloss-table", table::version()),
+    ];
+    let lines: Vec<&str> = report.lines().collect();
+    assert_eq!(lines.len(), expected.len());
+    for (line, (name, version)) in lines.iter().zip(expected) {
+        assert_eq!(*line, format!("{name} {version}"));
+    }
+}
+
+#[test]
+fn components_are_released_together() {
+    let own = env!("CARGO_PKG_VERSION");
+    for version in [
+        lipgloss::version(),
+        list::version(),
+        tree::version(),
+        table::version(),
+    ] {
+        assert_eq!(version, own);
+    }
+    assert!(!version_report().contains("differs"));
+}
+
+#[test]
+fn style_reaches_list() {
+    let list = compat::style_list(List::new().items(vec!["a", "b"]), red());
+    let output = list.to_string();
+    assert!(output.contains(RED), "{output:?}");
+    assert_eq!(lipgloss::strip_ansi(&output), "•a\n•b");
+}
+
+#[test]
+fn style_reaches_tree() {
+    let tree = compat::style_tree(Tree::new().root("r").child(vec!["x".into()]), red());
+    let output = tree.to_string();
+    assert!(output.contains(RED), "{output:?}");
+    assert_eq!(lipgloss::strip_ansi(&output), "r\n└──x");
+}
+
+#[test]
+fn style_reaches_table() {
+    let mut table = compat::style_table(Table::new().row(vec!["x"]), red());
+    let output = table.render();
+    assert!(output.contains(RED), "{output:?}");
+    assert_eq!(lipgloss::strip_ansi(&output), "╭─╮\n│x│\n╰─╯");
+}
+
+#[test]
+fn list_and_tree_share_node_types() {
+    // Lists are built on trees: the facade's tree nodes nest in its lists,
+    // and the traits lists re-export are the tree crate's own.
+    let sub: Box<dyn Node> = Box::new(Tree::new().root("sub").child(vec!["leaf".into()]));
+    let list = List::new().item("top").item_node(sub);
+    assert!(list.to_string().contains("leaf"));
+
+    let _: fn(&dyn tree::Stylize) -> &dyn list::Stylize = |s| s;
+    let _: fn(&dyn tree::Enumerate) -> &dyn list::Enumerate = |e| e;
+}
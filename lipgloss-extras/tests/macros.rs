@@ -0,0 +1,77 @@
+use lipgloss_extras::lipgloss::{normal_border, Style};
+use lipgloss_extras::table::Table;
+use lipgloss_extras::{styled, table};
+
+#[test]
+fn styled_without_attributes_matches_plain_style() {
+    assert_eq!(styled!("plain"), Style::new().render("plain"));
+}
+
+#[test]
+fn styled_flags_and_keys_match_builder() {
+    let by_macro = styled!(
+        "Hello",
+        bold,
+        italic,
+        fg = "#ff0000",
+        bg = "#000000",
+        pad = (0, 1)
+    );
+    let by_hand = Style::new()
+        .bold(true)
+        .italic(true)
+        .foreground("#ff0000")
+        .background("#000000")
+        .padding(0, 1, 0, 1)
+        .render("Hello");
+    assert_eq!(by_macro, by_hand);
+}
+
+#[test]
+fn styled_spacing_shorthands_match_builder() {
+    assert_eq!(
+        styled!("x", pad = 1),
+        Style::new().padding(1, 1, 1, 1).render("x")
+    );
+    assert_eq!(
+        styled!("x", margin = (1, 2, 3, 4), width = 10),
+        Style::new().margin(1, 2, 3, 4).width(10).render("x")
+    );
+}
+
+#[test]
+fn styled_accepts_expressions_and_trailing_comma() {
+    let text = String::from("dynamic");
+    let color = "#00ff00";
+    assert_eq!(
+        styled!(text, underline, fg = color,),
+        Style::new().underline(true).foreground(color).render(&text)
+    );
+}
+
+#[test]
+fn table_matches_builder() {
+    let mut by_macro = table!(
+        headers = ["Name", "Age"],
+        rows = [["Alice", 30], ["Bob", 25]],
+    );
+    let mut by_hand = Table::new()
+        .border(normal_border())
+        .headers(vec!["Name", "Age"])
+        .rows(vec![vec!["Alice", "30"], vec!["Bob", "25"]]);
+    assert_eq!(by_macro.render(), by_hand.render());
+}
+
+#[test]
+fn table_headers_only() {
+    let mut by_macro = table!(headers = ["A", "B"]);
+    let mut by_hand = Table::new().border(normal_border()).headers(vec!["A", "B"]);
+    assert_eq!(by_macro.render(), by_hand.render());
+    assert!(by_macro.render().starts_with('┌'));
+}
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
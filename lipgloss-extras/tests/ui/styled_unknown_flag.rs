@@ -0,0 +1,5 @@
+use lipgloss_extras::styled;
+
+fn main() {
+    let _ = styled!("text", bold, shiny);
+}
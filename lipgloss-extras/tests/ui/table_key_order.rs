@@ -0,0 +1,5 @@
+use lipgloss_extras::table;
+
+fn main() {
+    let _ = table!(rows = [["1"]], headers = ["A"]);
+}
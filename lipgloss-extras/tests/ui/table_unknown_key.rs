@@ -0,0 +1,5 @@
+use lipgloss_extras::table;
+
+fn main() {
+    let _ = table!(headers = ["A"], columns = [["1"]]);
+}
@@ -21,6 +21,9 @@ use lipgloss_tree::Children;
 /// - dash
 /// - roman
 /// - asterisk
+/// - progress
+/// - checklist
+/// - checklist_ascii
 ///
 /// Or, define your own.
 pub type Enumerator = fn(&dyn Children, usize) -> String;
@@ -93,6 +96,25 @@ pub fn arabic(_items: &dyn Children, i: usize) -> String {
     format!("{}.", i + 1)
 }
 
+/// Progress numbers items out of the number shown, for task runners.
+///
+/// The count is [`Children::visible_length`], so hidden items and items
+/// outside the list's offset are left out of it. Positions are padded to the
+/// width of the count so the brackets line up.
+///
+/// Example:
+/// ```text
+/// [ 1/10] Fetch
+/// [ 2/10] Build
+/// ...
+/// [10/10] Deploy
+/// ```
+pub fn progress(items: &dyn Children, i: usize) -> String {
+    let total = items.visible_length();
+    let digits = total.max(1).to_string().len();
+    format!("[{:>digits$}/{}]", i + 1, total)
+}
+
 /// Roman is the enumeration for roman numerals listing.
 ///
 /// Example:
@@ -160,3 +182,31 @@ pub fn asterisk(_items: &dyn Children, _i: usize) -> String {
 pub fn dash(_items: &dyn Children, _i: usize) -> String {
     "-".to_string()
 }
+
+/// Checklist marks items done with a check mark and the rest with a bullet.
+///
+/// Items are marked done with [`List::item_done`](crate::List::item_done) or
+/// [`List::set_done`](crate::List::set_done).
+///
+/// Example:
+/// ```text
+///   ✓ Foo
+///   • Bar
+///   ✓ Baz
+/// ```
+pub fn checklist(items: &dyn Children, i: usize) -> String {
+    if items.is_done(i) { "✓" } else { "•" }.to_string()
+}
+
+/// ChecklistAscii is the [`checklist`] enumeration for terminals without
+/// Unicode, using checkboxes.
+///
+/// Example:
+/// ```text
+///   [x] Foo
+///   [ ] Bar
+///   [x] Baz
+/// ```
+pub fn checklist_ascii(items: &dyn Children, i: usize) -> String {
+    if items.is_done(i) { "[x]" } else { "[ ]" }.to_string()
+}
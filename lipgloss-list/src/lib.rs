@@ -14,13 +14,21 @@
 //! ```
 
 pub mod enumerator;
+pub mod steps;
 
-use lipgloss::Style;
+use lipgloss::{color::TEXT_MUTED, Style};
 use lipgloss_tree::{Children, Leaf, Node, Tree};
+use std::borrow::Cow;
 use std::fmt;
+use std::sync::{Arc, Mutex};
 
 // Re-export enumerators for convenience
-pub use enumerator::{alphabet, arabic, asterisk, bullet, dash, roman, Enumerator, Indenter};
+pub use enumerator::{
+    alphabet, arabic, asterisk, bullet, checklist, checklist_ascii, dash, progress, roman,
+    Enumerator, Indenter,
+};
+pub use lipgloss_tree::{Enumerate, Indent, NodeContext, Stylize};
+pub use steps::{steps, StepLine, StepStatus, Steps};
 
 /// Items represents the list items.
 pub type Items = Box<dyn Children>;
@@ -54,6 +62,13 @@ pub type StyleFunc = fn(&dyn Children, usize) -> Style;
 /// In fact, lists can contain anything as items, like Table or Tree.
 pub struct List {
     tree: Tree,
+    enumerator: Arc<dyn Enumerate>,
+    start: usize,
+    indent: Arc<IndentWidth>,
+    columns: usize,
+    column_gap: usize,
+    row_major: bool,
+    window: Option<Window>,
 }
 
 impl List {
@@ -69,21 +84,40 @@ impl List {
     /// Items can be other lists, trees, tables, rendered markdown;
     /// anything you want, really.
     pub fn new() -> Self {
-        let mut tree = Tree::new();
-        tree = tree
-            .enumerator(bullet as lipgloss_tree::Enumerator)
-            .indenter(list_indenter);
+        let indent = Arc::new(IndentWidth::default());
+        let enumerator: Arc<dyn Enumerate> = Arc::new(bullet);
+        let tree = Tree::new()
+            .enumerator_impl(StartAt(0, Arc::clone(&enumerator)))
+            .indenter_impl(ListIndenter(Arc::clone(&indent)))
+            .done_style(Style::new().strikethrough(true).foreground(TEXT_MUTED))
+            .description_style(Style::new().faint(true));
 
-        Self { tree }
+        Self {
+            tree,
+            enumerator,
+            start: 0,
+            indent,
+            columns: 1,
+            column_gap: 2,
+            row_major: false,
+            window: None,
+        }
     }
 
     /// Creates a new list with initial items.
-    pub fn from_items(items: Vec<&str>) -> Self {
-        let mut list = Self::new();
-        for item in items {
-            list = list.item(item);
-        }
-        list
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::from_items(vec!["Foo", "Bar"]);
+    /// let owned = List::from_items((1..=2).map(|i| format!("Item {}", i)));
+    /// ```
+    pub fn from_items<I, S>(items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::new().items(items)
     }
 
     /// Returns whether this list is hidden.
@@ -100,6 +134,9 @@ impl List {
 
     /// Sets the start and end offset for the list.
     ///
+    /// `start` visible items are skipped from the front and `end` visible
+    /// items are trimmed from the back; hidden items are not counted.
+    ///
     /// Example:
     /// ```rust
     /// use lipgloss_list::List;
@@ -117,6 +154,109 @@ impl List {
         self
     }
 
+    /// Shows a window of `height` items centered on the item at `selected`,
+    /// for scrolling through a long list.
+    ///
+    /// Only top-level items are counted; a sublist is shown with the item
+    /// it follows. Near either end the window is moved to stay within the
+    /// list, and the selection is clamped to the last item. The window is
+    /// worked out when the list is rendered, so items may still be added
+    /// afterwards, and it replaces any [`List::offset`]. See
+    /// [`List::window_marker`] to mark the items left out.
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["A", "B", "C", "D", "E", "F"])
+    ///     .window(3, 3);
+    /// assert_eq!(l.to_string(), "• C\n• D\n• E");
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["A", "B", "C", "D", "E", "F"])
+    ///     .window(5, 3);
+    /// assert_eq!(l.to_string(), "• D\n• E\n• F");
+    /// ```
+    pub fn window(mut self, selected: usize, height: usize) -> Self {
+        self.window = Some(Window::Around { selected, height });
+        self
+    }
+
+    /// Shows only the last `n` items, for tail views such as logs.
+    ///
+    /// Counts top-level items like [`List::window`], and like it replaces
+    /// any [`List::offset`].
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["A", "B", "C", "D"])
+    ///     .offset_from_end(2);
+    /// assert_eq!(l.to_string(), "• C\n• D");
+    /// ```
+    pub fn offset_from_end(mut self, n: usize) -> Self {
+        self.window = Some(Window::Tail(n));
+        self
+    }
+
+    /// Draws `marker` on a line of its own where items are left out above
+    /// or below the ones shown, by a window or an offset.
+    ///
+    /// The marker is styled with the enumerator style and sits in the
+    /// enumerator column. No marker is drawn by default.
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["A", "B", "C", "D", "E"])
+    ///     .window(2, 3)
+    ///     .window_marker("…");
+    /// assert_eq!(l.to_string(), "…\n• B\n• C\n• D\n…");
+    /// ```
+    pub fn window_marker(mut self, marker: impl Into<String>) -> Self {
+        self.tree = self.tree.offset_marker(marker);
+        self
+    }
+
+    /// The tree to draw: the list's own, or a copy scrolled to the window.
+    fn shown_tree(&self) -> Cow<'_, Tree> {
+        let Some(window) = self.window else {
+            return Cow::Borrowed(&self.tree);
+        };
+
+        // Group the visible children by top-level item: a sublist belongs
+        // to the item before it.
+        let tree = self.tree.clone().offset(0, 0);
+        let children = tree.children();
+        let mut groups: Vec<(usize, usize)> = Vec::new();
+        for i in 0..children.length() {
+            let is_item = children.at(i).is_some_and(|c| !c.value().is_empty());
+            match groups.last_mut() {
+                Some(group) if !is_item => group.1 = i,
+                _ => groups.push((i, i)),
+            }
+        }
+
+        let items = groups.len();
+        let (first, count) = match window {
+            Window::Around { selected, height } => {
+                let height = height.min(items);
+                let selected = selected.min(items.saturating_sub(1));
+                let first = selected.saturating_sub(height / 2);
+                (first.min(items - height), height)
+            }
+            Window::Tail(n) => (items.saturating_sub(n), n.min(items)),
+        };
+        if count == 0 {
+            return Cow::Owned(tree.offset(children.length(), 0));
+        }
+        let start = groups[first].0;
+        let end = children.length() - 1 - groups[first + count - 1].1;
+        Cow::Owned(tree.offset(start, end))
+    }
+
     /// Returns the value of this node.
     pub fn value(&self) -> String {
         self.tree.value()
@@ -151,10 +291,32 @@ impl List {
     ///         }
     ///     });
     /// ```
-    pub fn enumerator_style_func(mut self, f: StyleFunc) -> Self {
-        self.tree = self
-            .tree
-            .enumerator_style_func(f as lipgloss_tree::StyleFunc);
+    pub fn enumerator_style_func<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> Style + Send + Sync + 'static,
+    {
+        self.tree = self.tree.enumerator_style_func(f);
+        self
+    }
+
+    /// Sets the enumerator style function from any [`Stylize`] implementation.
+    ///
+    /// Use this instead of [`List::enumerator_style_func`] for struct-based implementations
+    /// that carry their own state.
+    pub fn enumerator_style_func_impl(mut self, f: impl Stylize + 'static) -> Self {
+        self.tree = self.tree.enumerator_style_func_impl(f);
+        self
+    }
+
+    /// Sets the enumerator style from the item itself and where it sits.
+    ///
+    /// Used instead of [`List::enumerator_style_func`] when both are set.
+    /// See [`Tree::enumerator_style_for`].
+    pub fn enumerator_style_for<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&dyn Node, NodeContext) -> Style + Send + Sync + 'static,
+    {
+        self.tree = self.tree.enumerator_style_for(f);
         self
     }
 
@@ -176,11 +338,68 @@ impl List {
     ///     .items(vec!["Foo", "Bar", "Baz"])
     ///     .indenter(arrow_indenter);
     /// ```
-    pub fn indenter(mut self, indenter: Indenter) -> Self {
-        self.tree = self.tree.indenter(indenter as lipgloss_tree::Indenter);
+    pub fn indenter<F>(mut self, indenter: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> String + Send + Sync + 'static,
+    {
+        self.tree = self.tree.indenter(indenter);
         self
     }
 
+    /// Sets the indenter from any [`Indent`] implementation.
+    ///
+    /// Use this instead of [`List::indenter`] for struct-based implementations
+    /// that carry their own state.
+    pub fn indenter_impl(mut self, indenter: impl Indent + 'static) -> Self {
+        self.tree = self.tree.indenter_impl(indenter);
+        self
+    }
+
+    /// Sets the number of spaces nested content is indented by, per level.
+    ///
+    /// Sublists added with [`List::item_list`] that do not set a width of
+    /// their own inherit this one, whether they were added before or after
+    /// this call, so one call on the outermost list sets the indentation of
+    /// every level. Trees and other content added with [`List::item_node`]
+    /// start at the same indentation and keep their own branch layout.
+    /// Defaults to 2. This replaces any custom [`List::indenter`].
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::new()
+    ///     .item("Fruits")
+    ///     .item_list(List::new().items(vec!["Apple", "Banana"]))
+    ///     .indent_width(4);
+    ///
+    /// assert_eq!(l.to_string(), "• Fruits\n    • Apple\n    • Banana");
+    /// ```
+    pub fn indent_width(mut self, spaces: usize) -> Self {
+        *lock(&self.indent.width) = Some(spaces);
+        self.tree = self
+            .tree
+            .indenter_impl(ListIndenter(Arc::clone(&self.indent)));
+        self
+    }
+
+    /// Sets the per-level indentation width; shorthand for
+    /// [`List::indent_width`].
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let l = List::new()
+    ///     .item("Files")
+    ///     .item_node(Box::new(Tree::new().child(vec!["a".into(), "b".into()])))
+    ///     .indent(4);
+    ///
+    /// assert_eq!(l.to_string(), "• Files\n    ├── a\n    └── b");
+    /// ```
+    pub fn indent(self, width: usize) -> Self {
+        self.indent_width(width)
+    }
+
     /// Sets the item style for all items.
     ///
     /// To set the item style conditionally based on the item value or index,
@@ -210,8 +429,43 @@ impl List {
     ///         }
     ///     });
     /// ```
-    pub fn item_style_func(mut self, f: StyleFunc) -> Self {
-        self.tree = self.tree.item_style_func(f as lipgloss_tree::StyleFunc);
+    pub fn item_style_func<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> Style + Send + Sync + 'static,
+    {
+        self.tree = self.tree.item_style_func(f);
+        self
+    }
+
+    /// Sets the item style function from any [`Stylize`] implementation.
+    ///
+    /// Use this instead of [`List::item_style_func`] for struct-based implementations
+    /// that carry their own state.
+    pub fn item_style_func_impl(mut self, f: impl Stylize + 'static) -> Self {
+        self.tree = self.tree.item_style_func_impl(f);
+        self
+    }
+
+    /// Sets the item style from the item itself and where it sits.
+    ///
+    /// Used instead of [`List::item_style_func`] when both are set.
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_list::List;
+    /// use lipgloss_tree::Node;
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["Foo", "Bar"])
+    ///     .item_style_for(|item: &dyn Node, _| {
+    ///         Style::new().bold(item.value() == "Bar")
+    ///     });
+    /// ```
+    pub fn item_style_for<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&dyn Node, NodeContext) -> Style + Send + Sync + 'static,
+    {
+        self.tree = self.tree.item_style_for(f);
         self
     }
 
@@ -223,14 +477,87 @@ impl List {
     /// let l = List::new()
     ///     .item("Foo")
     ///     .item("Bar")
-    ///     .item("Baz");
+    ///     .item("Baz")
+    ///     .item(format!("Item {}", 4));
     /// ```
-    pub fn item(mut self, item: &str) -> Self {
+    pub fn item(mut self, item: impl Into<String>) -> Self {
         let leaf: Box<dyn Node> = Box::new(Leaf::new(item, false));
         self.tree = self.tree.add_child(leaf);
         self
     }
 
+    /// Appends an item rendered with its [`Display`](fmt::Display)
+    /// implementation.
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::new().item_any(42).item_any('x');
+    /// assert_eq!(l.to_string(), "• 42\n• x");
+    /// ```
+    pub fn item_any(self, item: impl fmt::Display) -> Self {
+        self.item(item.to_string())
+    }
+
+    /// Appends an item with a description on the line below it, such as a
+    /// timestamp or a summary.
+    ///
+    /// The description lines up with the title, past the enumerator, which
+    /// is only drawn on the title line; a multi-line enumerator stretches
+    /// down over both. The item style applies to the title alone, and the
+    /// description gets the [`List::description_style`].
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_list::{arabic, List};
+    ///
+    /// let l = List::new()
+    ///     .enumerator(arabic)
+    ///     .item_with_desc("Foo Document", "1 day ago")
+    ///     .item("Bar Document")
+    ///     .description_style(Style::new());
+    /// assert_eq!(l.to_string(), "1. Foo Document\n   1 day ago\n2. Bar Document");
+    /// ```
+    pub fn item_with_desc(
+        mut self,
+        title: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        let leaf = Leaf::new(title, false).with_description(description);
+        self.tree = self.tree.add_child(leaf);
+        self
+    }
+
+    /// Sets the style of item descriptions, faint by default. See
+    /// [`List::item_with_desc`].
+    pub fn description_style(mut self, style: Style) -> Self {
+        self.tree = self.tree.description_style(style);
+        self
+    }
+
+    /// Appends an item that is already checked off.
+    ///
+    /// Done items are drawn with the [`List::done_style`], and the
+    /// [`checklist`] enumerators mark them. Enumerators and style functions
+    /// of your own can ask for it with [`Children::is_done`].
+    ///
+    /// ```rust
+    /// use lipgloss_list::{checklist_ascii, List};
+    ///
+    /// let l = List::new()
+    ///     .enumerator(checklist_ascii)
+    ///     .item_done("Milk")
+    ///     .item("Eggs")
+    ///     .done_style(lipgloss::Style::new());
+    /// assert_eq!(l.to_string(), "[x] Milk\n[ ] Eggs");
+    /// ```
+    pub fn item_done(mut self, item: impl Into<String>) -> Self {
+        let mut leaf = Leaf::new(item, false);
+        leaf.set_done(true);
+        self.tree = self.tree.add_child(leaf);
+        self
+    }
+
     /// Appends a generic node to the list.
     ///
     /// This allows adding any `lipgloss_tree::Node` implementation directly.
@@ -241,8 +568,9 @@ impl List {
 
     /// Appends another list as a sublist (nested list).
     pub fn item_list(mut self, list: List) -> Self {
+        *lock(&list.indent.parent) = Some(Arc::clone(&self.indent));
         // `Tree` implements `Node`, so we can add it directly as a child.
-        let node: Box<dyn Node> = Box::new(list.tree);
+        let node: Box<dyn Node> = Box::new(list.shown_tree().into_owned());
         self.tree = self.tree.add_child(node);
         self
     }
@@ -253,15 +581,81 @@ impl List {
     /// use lipgloss_list::List;
     ///
     /// let l = List::new()
-    ///     .items(vec!["Foo", "Bar", "Baz"]);
+    ///     .items(vec!["Foo", "Bar", "Baz"])
+    ///     .items(["Qux", "Quux"].map(String::from));
     /// ```
-    pub fn items(mut self, items: Vec<&str>) -> Self {
+    pub fn items<I, S>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
         for item in items {
             self = self.item(item);
         }
         self
     }
 
+    /// Marks the item at `index` done or not done. An out-of-range index is
+    /// ignored.
+    ///
+    /// The index counts items in the order they were added, sublists
+    /// included.
+    ///
+    /// ```rust
+    /// use lipgloss_list::{checklist_ascii, List};
+    ///
+    /// let l = List::new()
+    ///     .enumerator(checklist_ascii)
+    ///     .items(vec!["Milk", "Eggs"])
+    ///     .set_done(1, true)
+    ///     .done_style(lipgloss::Style::new());
+    /// assert_eq!(l.to_string(), "[ ] Milk\n[x] Eggs");
+    /// ```
+    pub fn set_done(mut self, index: usize, done: bool) -> Self {
+        self.tree = self.tree.child_done(index, done);
+        self
+    }
+
+    /// Sets the style of done items, struck through and muted by default.
+    ///
+    /// It is applied to the item's text before the item style. Sublists
+    /// keep their own done style.
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_list::{checklist, List};
+    ///
+    /// let l = List::new()
+    ///     .enumerator(checklist)
+    ///     .item_done("Milk")
+    ///     .done_style(Style::new().faint(true));
+    /// ```
+    pub fn done_style(mut self, style: Style) -> Self {
+        self.tree = self.tree.done_style(style);
+        self
+    }
+
+    /// Sets how markers of different widths line up.
+    ///
+    /// Markers are padded to the widest one in the list, measured after
+    /// the enumerator style. They are right-aligned by default, as in Go's
+    /// lipgloss; pass [`LEFT`](lipgloss::LEFT) to left-align them. Each
+    /// sublist aligns its own markers.
+    ///
+    /// ```rust
+    /// use lipgloss_list::{roman, List};
+    ///
+    /// let l = List::new()
+    ///     .enumerator(roman)
+    ///     .items(vec!["Foo", "Bar", "Baz"])
+    ///     .enumerator_alignment(lipgloss::LEFT);
+    /// assert_eq!(l.to_string(), "I.   Foo\nII.  Bar\nIII. Baz");
+    /// ```
+    pub fn enumerator_alignment(mut self, position: lipgloss::Position) -> Self {
+        self.tree = self.tree.enumerator_alignment(position);
+        self
+    }
+
     /// Sets the list enumerator.
     ///
     /// There are several predefined enumerators:
@@ -281,12 +675,229 @@ impl List {
     ///     .items(vec!["Foo", "Bar", "Baz"])
     ///     .enumerator(arabic);
     /// ```
-    pub fn enumerator(mut self, enumerator: Enumerator) -> Self {
+    ///
+    /// Closures may capture state, such as the selected item:
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let selected = 1;
+    /// let l = List::new()
+    ///     .items(vec!["Foo", "Bar"])
+    ///     .enumerator(move |_items, i| if i == selected { ">" } else { " " }.to_string());
+    /// assert_eq!(l.to_string(), "  Foo\n> Bar");
+    /// ```
+    pub fn enumerator<F>(self, enumerator: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> String + Send + Sync + 'static,
+    {
+        self.enumerator_impl(enumerator)
+    }
+
+    /// Sets the enumerator from any [`Enumerate`] implementation.
+    ///
+    /// Use this instead of [`List::enumerator`] for struct-based implementations
+    /// that carry their own state.
+    ///
+    /// ```rust
+    /// use lipgloss_list::{Enumerate, List};
+    /// use lipgloss_tree::Children;
+    ///
+    /// /// Numbers items from a configurable starting point.
+    /// struct StartAt(usize);
+    ///
+    /// impl Enumerate for StartAt {
+    ///     fn enumerate(&self, _items: &dyn Children, i: usize) -> String {
+    ///         format!("{}.", self.0 + i)
+    ///     }
+    /// }
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["Foo", "Bar"])
+    ///     .enumerator_impl(StartAt(7));
+    /// assert_eq!(l.to_string(), "7. Foo\n8. Bar");
+    /// ```
+    pub fn enumerator_impl(mut self, enumerator: impl Enumerate + 'static) -> Self {
+        self.enumerator = Arc::new(enumerator);
+        self.tree = self
+            .tree
+            .enumerator_impl(StartAt(self.start, Arc::clone(&self.enumerator)));
+        self
+    }
+
+    /// Numbers the items from `start` rather than 0, to continue the
+    /// numbering of a list split into several, such as the pages of a long
+    /// one.
+    ///
+    /// The enumerator sees each item's index plus `start`, and its items
+    /// shifted along with it, so [`Children::is_done`] and the like still
+    /// answer for the item being numbered. Only this list's own items are
+    /// renumbered: sublists keep their own numbering.
+    ///
+    /// ```rust
+    /// use lipgloss_list::{alphabet, List};
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["Foo", "Bar"])
+    ///     .enumerator(alphabet)
+    ///     .enumerator_start(26);
+    /// assert_eq!(l.to_string(), "AA. Foo\nAB. Bar");
+    /// ```
+    pub fn enumerator_start(mut self, start: usize) -> Self {
+        self.start = start;
         self.tree = self
             .tree
-            .enumerator(enumerator as lipgloss_tree::Enumerator);
+            .enumerator_impl(StartAt(start, Arc::clone(&self.enumerator)));
+        self
+    }
+
+    /// Lays the items out in `n` columns, like `ls`, to save vertical space.
+    ///
+    /// Items fill the columns top to bottom (see [`List::row_major`] to fill
+    /// them left to right instead), and the columns are balanced so their item
+    /// counts differ by at most one. Each item keeps its enumerator and styles,
+    /// and enumerators stay aligned across columns.
+    ///
+    /// Columns only apply to flat lists: a list with any nested items
+    /// (sublists, trees) ignores this setting and renders in one column.
+    /// `0` and `1` both mean a single column.
+    ///
+    /// ```rust
+    /// use lipgloss_list::{List, arabic};
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["A", "B", "C", "D", "E"])
+    ///     .enumerator(arabic)
+    ///     .columns(2);
+    ///
+    /// assert_eq!(
+    ///     l.to_string(),
+    ///     "1. A  4. D\n2. B  5. E\n3. C      "
+    /// );
+    /// ```
+    pub fn columns(mut self, n: usize) -> Self {
+        self.columns = n.max(1);
+        self
+    }
+
+    /// Sets the number of spaces between columns. Defaults to 2.
+    pub fn column_gap(mut self, gap: usize) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    /// Fills columns left to right, row by row, instead of top to bottom.
+    ///
+    /// ```rust
+    /// use lipgloss_list::{List, arabic};
+    ///
+    /// let l = List::new()
+    ///     .items(vec!["A", "B", "C", "D", "E"])
+    ///     .enumerator(arabic)
+    ///     .columns(2)
+    ///     .row_major(true);
+    ///
+    /// assert_eq!(
+    ///     l.to_string(),
+    ///     "1. A  2. B\n3. C  4. D\n5. E      "
+    /// );
+    /// ```
+    pub fn row_major(mut self, row_major: bool) -> Self {
+        self.row_major = row_major;
         self
     }
+
+    /// Renders the list with `prefix` in front of every line, for embedding
+    /// it in code comments or indented logs.
+    ///
+    /// The prefix is not part of the list's layout, and it stays outside the
+    /// list's styling, see [`lipgloss::utils::write_prefixed`].
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l = List::new().items(vec!["Foo", "Bar"]);
+    /// assert_eq!(l.render_prefixed("    "), "    • Foo\n    • Bar");
+    /// ```
+    pub fn render_prefixed(&self, prefix: &str) -> String {
+        lipgloss::utils::prefix_lines(&self.to_string(), prefix)
+    }
+
+    /// Writes the list to `w` with `prefix` in front of every line. See
+    /// [`List::render_prefixed`].
+    pub fn render_prefixed_to<W: fmt::Write>(&self, w: &mut W, prefix: &str) -> fmt::Result {
+        lipgloss::utils::write_prefixed(w, &self.to_string(), prefix)
+    }
+
+    /// Whether any visible item has items of its own.
+    fn is_nested(&self) -> bool {
+        let children = self.tree.children();
+        (0..children.length())
+            .filter_map(|i| children.at(i))
+            .any(|child| child.children().length() > 0)
+    }
+
+    /// Renders the items in balanced columns joined side by side.
+    fn render_columns(&self) -> String {
+        let parts = self.shown_tree().render_parts();
+        let n = self.columns.min(parts.len()).max(1);
+
+        // The first `parts.len() % n` columns get one extra item.
+        let per_column = parts.len() / n;
+        let extra = parts.len() % n;
+        let mut columns: Vec<Vec<&str>> = vec![Vec::new(); n];
+        if self.row_major {
+            for (i, part) in parts.iter().enumerate() {
+                columns[i % n].push(part);
+            }
+        } else {
+            let mut items = parts.iter();
+            for (c, column) in columns.iter_mut().enumerate() {
+                let len = per_column + usize::from(c < extra);
+                column.extend(items.by_ref().take(len).map(String::as_str));
+            }
+        }
+
+        let gap = " ".repeat(self.column_gap);
+        let mut blocks = Vec::with_capacity(n * 2);
+        for (c, column) in columns.iter().enumerate() {
+            if c > 0 && !gap.is_empty() {
+                blocks.push(gap.clone());
+            }
+            blocks.push(column.join("\n"));
+        }
+        let blocks: Vec<&str> = blocks.iter().map(String::as_str).collect();
+        lipgloss::join_horizontal(lipgloss::TOP, &blocks)
+    }
+}
+
+#[cfg(feature = "html")]
+impl List {
+    /// Renders the list as an HTML `<ul>` or `<ol>`.
+    ///
+    /// Counting enumerators such as [`enumerator::arabic`] give an `<ol>`
+    /// with the matching `type`, glyph enumerators such as
+    /// [`enumerator::bullet`] give a `<ul>`, and sublists are nested inside
+    /// the item they follow. Item styles become inline CSS and content is
+    /// escaped. The column layout only affects terminal rendering. See
+    /// [`Tree::to_html`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_list::{enumerator::roman, List};
+    ///
+    /// let list = List::new().items(vec!["Foo", "Bar"]).enumerator(roman);
+    /// assert_eq!(
+    ///     list.to_html(),
+    ///     "<div style=\"font-family:monospace;white-space:pre-wrap\">\
+    ///      <ol type=\"I\"><li>Foo</li><li>Bar</li></ol>\
+    ///      </div>"
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        self.tree.to_html()
+    }
 }
 
 impl Default for List {
@@ -295,38 +906,144 @@ impl Default for List {
     }
 }
 
+impl<S: Into<String>> FromIterator<S> for List {
+    /// Collects items into a new list.
+    ///
+    /// ```rust
+    /// use lipgloss_list::List;
+    ///
+    /// let l: List = (1..=3).map(|i| format!("Item {}", i)).collect();
+    /// assert_eq!(l.to_string(), "• Item 1\n• Item 2\n• Item 3");
+    /// ```
+    fn from_iter<T: IntoIterator<Item = S>>(iter: T) -> Self {
+        Self::from_items(iter)
+    }
+}
+
 impl fmt::Display for List {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.tree)
+        if self.columns > 1 && !self.hidden() && !self.is_nested() {
+            write!(f, "{}", self.render_columns())
+        } else {
+            write!(f, "{}", self.shown_tree())
+        }
     }
 }
 
-/// List indenter for nested content within list items.
+/// The part of a list to show, set with [`List::window`] or
+/// [`List::offset_from_end`].
+#[derive(Debug, Clone, Copy)]
+enum Window {
+    Around { selected: usize, height: usize },
+    Tail(usize),
+}
+
+/// A list's enumerator, numbering the items from `.0`. Set with
+/// [`List::enumerator_start`].
+struct StartAt(usize, Arc<dyn Enumerate>);
+
+impl Enumerate for StartAt {
+    fn enumerate(&self, items: &dyn Children, index: usize) -> String {
+        if self.0 == 0 {
+            return self.1.enumerate(items, index);
+        }
+        let shifted = Shifted {
+            items,
+            start: self.0,
+        };
+        self.1.enumerate(&shifted, index + self.0)
+    }
+}
+
+/// Items seen `start` places further along, so an enumerator numbering from
+/// `start` finds the item it numbers at the index it is given.
+struct Shifted<'a> {
+    items: &'a dyn Children,
+    start: usize,
+}
+
+impl Children for Shifted<'_> {
+    fn at(&self, index: usize) -> Option<&dyn Node> {
+        self.items.at(index.checked_sub(self.start)?)
+    }
+
+    fn length(&self) -> usize {
+        self.items.length() + self.start
+    }
+
+    fn visible_length(&self) -> usize {
+        self.items.visible_length() + self.start
+    }
+
+    fn is_done(&self, index: usize) -> bool {
+        index
+            .checked_sub(self.start)
+            .is_some_and(|i| self.items.is_done(i))
+    }
+}
+
+/// The default number of spaces a list indents nested content by.
+///
+/// Go's list indenter returns a single space, which its renderer pads with
+/// the enumerator style; the default list enumerator style pads one column to
+/// the right, so sublists end up two columns in.
+const DEFAULT_INDENT_WIDTH: usize = 2;
+
+/// The indentation width of a list.
 ///
-/// CRITICAL SPACING REQUIREMENTS:
-/// - Regular sublists MUST have 2-space indentation before bullets
-/// - Example: "• Parent\n  • Child" (2 spaces before child bullet)
-/// - Golden files expect this 2-space pattern for proper visual hierarchy
+/// A sublist links to its parent list's width when it is added, and uses it
+/// until it sets a width of its own.
+#[derive(Debug, Default)]
+struct IndentWidth {
+    width: Mutex<Option<usize>>,
+    parent: Mutex<Option<Arc<IndentWidth>>>,
+}
+
+impl IndentWidth {
+    /// The width set on this list or the nearest ancestor that sets one.
+    fn resolve(&self) -> usize {
+        if let Some(width) = *lock(&self.width) {
+            return width;
+        }
+        match &*lock(&self.parent) {
+            Some(parent) => parent.resolve(),
+            None => DEFAULT_INDENT_WIDTH,
+        }
+    }
+}
+
+/// List indenter for nested content within list items.
 ///
-/// GO IMPLEMENTATION DISCREPANCY:
-/// - Go's list.New() sets indenter to return single space: `return " "`
-/// - However, Go's output produces 2-space indentation for sublists
-/// - This suggests Go has additional logic that doubles indentation for nested lists
-/// - Our implementation achieves correct output by returning 2 spaces directly
+/// Indents by the list's width in spaces. It is not applied to the
+/// enumerator itself, only to continuation lines and nested content. Trees
+/// nested in a list start at this indentation and draw their own branches
+/// from there, so their enumerator keeps its single space of padding.
+struct ListIndenter(Arc<IndentWidth>);
+
+impl Indent for ListIndenter {
+    fn indent(&self, _children: &dyn Children, _index: usize) -> String {
+        " ".repeat(self.0.resolve())
+    }
+
+    fn is_list(&self) -> bool {
+        true
+    }
+}
+
+/// Locks `mutex`, recovering the value if a panicking thread poisoned it.
+fn lock<T>(mutex: &Mutex<T>) -> std::sync::MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Returns the version of this crate.
 ///
-/// TREE-IN-LIST ISSUE:
-/// - When trees are nested in lists (via item_node), this 2-space indenter
-///   can cause extra spacing after tree symbols
-/// - Tree symbols have padding_right(1) built-in, expecting 1-space list_indenter
-/// - With 2-space list_indenter: tree gets "├──  content" instead of "├── content"
-/// - This affects golden_complex_sublist test specifically
+/// # Examples
 ///
-/// USAGE:
-/// - Called by tree renderer for each child's indentation
-/// - Applied to continuation lines and nested content
-/// - NOT applied to the enumerator/bullet itself
-fn list_indenter(_children: &dyn Children, _index: usize) -> String {
-    "  ".to_string() // 2 spaces required for sublist visual hierarchy
+/// ```rust
+/// assert!(!lipgloss_list::version().is_empty());
+/// ```
+pub const fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
 }
 
 // Go API compatibility aliases
@@ -338,6 +1055,10 @@ pub fn new() -> List {
 }
 
 /// Creates a new list with items.
-pub fn from_items(items: Vec<&str>) -> List {
+pub fn from_items<I, S>(items: I) -> List
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
     List::from_items(items)
 }
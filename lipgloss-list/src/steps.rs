@@ -0,0 +1,235 @@
+//! A preset for the output of CLI step runners.
+//!
+//! [`Steps`] turns a list of [`StepLine`]s into a [`List`] numbered with the
+//! [`progress`] enumerator, a glyph per [`StepStatus`] in the status color,
+//! and each step's duration right-aligned in a column of its own:
+//!
+//! ```text
+//! [1/3] ✓ Compile   2.3s
+//! [2/3] ◐ Test     41.0s
+//! [3/3] ○ Deploy
+//! ```
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use lipgloss::color::{STATUS_ERROR, STATUS_INFO, STATUS_SUCCESS, TEXT_MUTED};
+use lipgloss::utils::ELLIPSIS;
+use lipgloss::Style;
+
+use crate::enumerator::progress;
+use crate::List;
+
+/// Gap between the longest label and the duration column when no width is
+/// set.
+const DURATION_GAP: usize = 2;
+
+/// Where a step of a run stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepStatus {
+    /// Not started yet.
+    Pending,
+    /// Under way.
+    Running,
+    /// Finished successfully.
+    Done,
+    /// Finished with an error.
+    Failed,
+}
+
+impl StepStatus {
+    /// The glyph drawn after the step number.
+    pub fn glyph(self) -> &'static str {
+        match self {
+            StepStatus::Pending => "○",
+            StepStatus::Running => "◐",
+            StepStatus::Done => "✓",
+            StepStatus::Failed => "✗",
+        }
+    }
+
+    fn index(self) -> usize {
+        match self {
+            StepStatus::Pending => 0,
+            StepStatus::Running => 1,
+            StepStatus::Done => 2,
+            StepStatus::Failed => 3,
+        }
+    }
+}
+
+/// One step of a run, see [`Steps`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepLine {
+    /// What the step does.
+    pub label: String,
+    /// Where the step stands.
+    pub status: StepStatus,
+    /// How long the step took, or has taken so far.
+    pub duration: Option<Duration>,
+}
+
+impl StepLine {
+    /// Creates a step without a duration.
+    pub fn new(label: impl Into<String>, status: StepStatus) -> Self {
+        Self {
+            label: label.into(),
+            status,
+            duration: None,
+        }
+    }
+
+    /// Sets how long the step took.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+}
+
+/// Builds the [`List`] a step runner prints, see the [module
+/// docs](crate::steps).
+///
+/// The number and glyph of each step are drawn in the style of its status:
+/// muted while pending, blue while running, green once done and red when
+/// failed. Durations are right-aligned, at the given width or two columns
+/// past the longest label; labels that leave no room for them are cut with
+/// an ellipsis.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use lipgloss_list::{StepLine, StepStatus, Steps};
+///
+/// let run = Steps::new(vec![
+///     StepLine::new("Compile", StepStatus::Done).duration(Duration::from_millis(2300)),
+///     StepLine::new("Deploy", StepStatus::Pending),
+/// ])
+/// .width(24)
+/// .build();
+/// assert_eq!(
+///     lipgloss::strip_ansi(&run.to_string()),
+///     "[1/2] ✓ Compile     2.3s\n[2/2] ○ Deploy          "
+/// );
+/// ```
+pub struct Steps {
+    lines: Vec<StepLine>,
+    width: Option<usize>,
+    styles: [Style; 4],
+}
+
+impl Steps {
+    /// Creates the preset for these steps, in the order they run.
+    pub fn new(lines: Vec<StepLine>) -> Self {
+        Self {
+            lines,
+            width: None,
+            styles: [
+                Style::new().foreground(TEXT_MUTED).padding_right(1),
+                Style::new().foreground(STATUS_INFO).padding_right(1),
+                Style::new().foreground(STATUS_SUCCESS).padding_right(1),
+                Style::new().foreground(STATUS_ERROR).padding_right(1),
+            ],
+        }
+    }
+
+    /// Sets the width of every line, the durations ending at its last
+    /// column.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Sets the style of the number and glyph of steps with this status.
+    ///
+    /// As with [`List::enumerator_style`], the style's right padding is
+    /// the gap before the label; the defaults have one column of it.
+    pub fn status_style(mut self, status: StepStatus, style: Style) -> Self {
+        self.styles[status.index()] = style;
+        self
+    }
+
+    /// Builds the list.
+    pub fn build(self) -> List {
+        let total = self.lines.len();
+        let digits = total.max(1).to_string().len();
+        // "[i/n] g" and the one-column gap ahead of every label: the counter
+        // is padded to the same width on each line, and every glyph is one
+        // column wide.
+        let marker_width = (2 * digits + 3) + 2 + 1;
+
+        let durations: Vec<String> = self
+            .lines
+            .iter()
+            .map(|line| line.duration.map(format_duration).unwrap_or_default())
+            .collect();
+        let duration_width = durations
+            .iter()
+            .map(|d| lipgloss::width(d))
+            .max()
+            .unwrap_or(0);
+        let label_width = match self.width {
+            Some(width) => width
+                .saturating_sub(marker_width + duration_width)
+                .saturating_sub(if duration_width > 0 { 1 } else { 0 }),
+            None => {
+                let longest = self
+                    .lines
+                    .iter()
+                    .map(|line| lipgloss::width(&line.label))
+                    .max()
+                    .unwrap_or(0);
+                if duration_width > 0 {
+                    longest + DURATION_GAP
+                } else {
+                    longest
+                }
+            }
+        };
+        let item_width = match self.width {
+            Some(width) => width.saturating_sub(marker_width),
+            None => label_width + duration_width,
+        };
+
+        let items = self.lines.iter().zip(&durations).map(|(line, duration)| {
+            let label = lipgloss::truncate_ellipsis(&line.label, label_width, ELLIPSIS);
+            let gap =
+                item_width.saturating_sub(lipgloss::width(&label) + lipgloss::width(duration));
+            format!("{}{}{}", label, " ".repeat(gap), duration)
+        });
+
+        let statuses: Arc<Vec<StepStatus>> =
+            Arc::new(self.lines.iter().map(|line| line.status).collect());
+        let glyphs = Arc::clone(&statuses);
+        let styles = self.styles;
+        List::new()
+            .items(items)
+            .enumerator(move |items, i| {
+                let glyph = glyphs.get(i).map_or(" ", |status| status.glyph());
+                format!("{} {}", progress(items, i), glyph)
+            })
+            .enumerator_style_func(move |_, i| {
+                statuses
+                    .get(i)
+                    .map_or_else(Style::new, |status| styles[status.index()].clone())
+            })
+    }
+}
+
+/// Builds the [`List`] a step runner prints with the default [`Steps`]
+/// settings.
+pub fn steps(lines: Vec<StepLine>) -> List {
+    Steps::new(lines).build()
+}
+
+/// Formats a duration the way step runners show it: milliseconds under a
+/// second, tenths of a second under a minute, minutes and seconds above.
+fn format_duration(duration: Duration) -> String {
+    let millis = duration.as_millis();
+    if millis < 1000 {
+        format!("{}ms", millis)
+    } else if millis < 60_000 {
+        format!("{:.1}s", duration.as_secs_f64())
+    } else {
+        let secs = duration.as_secs();
+        format!("{}m{:02}s", secs / 60, secs % 60)
+    }
+}
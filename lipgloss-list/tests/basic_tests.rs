@@ -84,3 +84,148 @@ fn test_hidden_list() {
     let output = format!("{}", list);
     assert_eq!(output, ""); // Hidden list should produce empty output
 }
+
+#[test]
+fn test_columns_are_balanced() {
+    for (items, n) in [(7usize, 3usize), (10, 4), (2, 5), (9, 3)] {
+        let values: Vec<String> = (0..items).map(|i| format!("i{}", i)).collect();
+        let list = List::new()
+            .items(values.iter().map(String::as_str))
+            .columns(n);
+        let output = list.to_string();
+
+        // Balanced columns need exactly ceil(items / n) rows.
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), items.div_ceil(n), "{}", output);
+        for v in &values {
+            assert_eq!(output.matches(&format!("• {}", v)).count(), 1);
+        }
+        // Column-major: the first column is always full.
+        assert!(lines.iter().all(|l| l.starts_with("• i")), "{}", output);
+    }
+}
+
+#[test]
+fn test_columns_keep_multiline_items() {
+    let list = List::new()
+        .items(vec!["one\nline two", "three", "four"])
+        .enumerator(arabic)
+        .columns(2)
+        .column_gap(1);
+    assert_eq!(
+        list.to_string(),
+        "1. one      3. four\n   line two        \n2. three           "
+    );
+}
+
+#[test]
+fn test_columns_ignored_for_nested_lists() {
+    let nested = List::new()
+        .item("A")
+        .item_list(List::new().items(vec!["A1", "A2"]))
+        .item("B");
+    let flat = nested.to_string();
+    let nested = List::new()
+        .item("A")
+        .item_list(List::new().items(vec!["A1", "A2"]))
+        .item("B")
+        .columns(3);
+    assert_eq!(nested.to_string(), flat);
+}
+
+#[test]
+fn test_enumerator_start_continues_split_lists() {
+    let items: Vec<String> = (1..=60).map(|i| format!("Item {}", i)).collect();
+    let enumerators: [fn(&dyn lipgloss_tree::Children, usize) -> String; 3] =
+        [arabic, roman, alphabet];
+    for enumerator in enumerators {
+        let whole = List::new().items(items.clone()).enumerator(enumerator);
+        let first = List::new()
+            .items(items[..30].to_vec())
+            .enumerator(enumerator);
+        let second = List::new()
+            .items(items[30..].to_vec())
+            .enumerator(enumerator)
+            .enumerator_start(30);
+
+        // Markers are padded to the widest in each list, so compare trimmed.
+        let lines = |list: &List| -> Vec<String> {
+            list.to_string()
+                .lines()
+                .map(|l| l.trim().to_string())
+                .collect()
+        };
+        let mut split = lines(&first);
+        split.extend(lines(&second));
+        assert_eq!(split, lines(&whole));
+    }
+
+    let second = List::new()
+        .items(items[30..].to_vec())
+        .enumerator(arabic)
+        .enumerator_start(30);
+    let output = second.to_string();
+    assert!(output.starts_with("31. Item 31"), "{}", output);
+    assert!(output.ends_with("60. Item 60"), "{}", output);
+}
+
+#[test]
+fn test_enumerator_start_leaves_sublists_alone() {
+    let list = List::new()
+        .enumerator_start(50)
+        .item("Fruits")
+        .item_list(List::new().items(vec!["Apple", "Pear"]).enumerator(arabic))
+        .item("Veggies")
+        .enumerator(arabic);
+    assert_eq!(
+        list.to_string(),
+        "51. Fruits\n  1. Apple\n  2. Pear\n52. Veggies"
+    );
+}
+
+#[test]
+fn test_enumerator_start_keeps_done_items() {
+    let list = List::new()
+        .enumerator(lipgloss_list::checklist_ascii)
+        .items(vec!["Milk", "Eggs"])
+        .set_done(1, true)
+        .done_style(lipgloss::Style::new())
+        .enumerator_start(10);
+    assert_eq!(list.to_string(), "[ ] Milk\n[x] Eggs");
+}
+
+#[test]
+fn test_item_with_desc_stretches_multiline_enumerator() {
+    let list = List::new()
+        .enumerator(|_items, i| if i == 0 { "│\n│" } else { " " }.to_string())
+        .item_with_desc("Foo", "today")
+        .item_with_desc("Bar", "now")
+        .description_style(lipgloss::Style::new());
+    assert_eq!(list.to_string(), "│ Foo\n│ today\n  Bar\n  now");
+}
+
+#[test]
+fn test_item_with_desc_keeps_item_style_off_description() {
+    let marked = lipgloss::Style::new().transform(|s| s.to_uppercase());
+    let list = List::new()
+        .item_with_desc("Foo", "today")
+        .item_style(marked)
+        .description_style(lipgloss::Style::new());
+    assert_eq!(list.to_string(), "• FOO\n  today");
+}
+
+#[test]
+fn test_item_with_desc_leaves_title_background_unpadded() {
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::ANSI);
+    let list = List::new()
+        .item_with_desc("Foo", "a longer description")
+        .item_style(lipgloss::Style::new().renderer(r).background("4"))
+        .description_style(lipgloss::Style::new());
+    let out = list.to_string();
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines[0], "• \x1b[44mFoo\x1b[0m");
+    assert_eq!(lines[1], "  a longer description");
+}
@@ -1,5 +1,7 @@
-use lipgloss_list::{alphabet, arabic, List};
-use lipgloss_tree::{Leaf, Node, Tree};
+use lipgloss_list::{alphabet, arabic, checklist, checklist_ascii, Enumerate, List};
+use lipgloss_tree::{Children, Leaf, Node, Tree};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
 
 // Hidden nodes between visible siblings should not increment the parent list's
 // enumeration index.
@@ -122,3 +124,297 @@ fn empty_nested_list_is_noop() {
     );
     assert!(lines[0].contains("A. only"), "expected 'A. only'\n{}", out);
 }
+
+/// Enumerates with a letter table chosen at runtime.
+struct LetterTable {
+    letters: Vec<char>,
+}
+
+impl Enumerate for LetterTable {
+    fn enumerate(&self, _items: &dyn Children, i: usize) -> String {
+        format!("{}.", self.letters[i % self.letters.len()])
+    }
+}
+
+#[test]
+fn struct_enumerator_uses_its_own_state() {
+    let hebrew = LetterTable {
+        letters: "אבגד".chars().collect(),
+    };
+    let l = List::new()
+        .items(vec!["Foo", "Bar", "Baz"])
+        .enumerator_impl(hebrew);
+    assert_eq!(l.to_string(), "א. Foo\nב. Bar\nג. Baz");
+}
+
+#[test]
+fn struct_enumerator_skips_hidden_items() {
+    let l = List::new()
+        .item("first")
+        .item_node(Box::new(Leaf::new("hidden", true)))
+        .item("second")
+        .enumerator_impl(LetterTable {
+            letters: vec!['x', 'y'],
+        });
+    assert_eq!(l.to_string(), "x. first\ny. second");
+}
+
+/// Counts how often the renderer asks for a marker.
+struct Counting {
+    calls: Arc<AtomicUsize>,
+}
+
+impl Enumerate for Counting {
+    fn enumerate(&self, _items: &dyn Children, i: usize) -> String {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+        format!("{})", i + 1)
+    }
+}
+
+#[test]
+fn struct_enumerator_can_keep_mutable_state() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let l = List::new().items(vec!["a", "b"]).enumerator_impl(Counting {
+        calls: Arc::clone(&calls),
+    });
+    assert_eq!(l.to_string(), "1) a\n2) b");
+    let first = calls.load(Ordering::SeqCst);
+    assert!(first > 0);
+    assert_eq!(l.to_string(), "1) a\n2) b");
+    assert_eq!(calls.load(Ordering::SeqCst), 2 * first);
+}
+
+#[test]
+fn capturing_closures_are_accepted() {
+    let prefix = String::from("->");
+    let l = List::new()
+        .items(vec!["a", "b"])
+        .enumerator(move |_items, _i| prefix.clone());
+    assert_eq!(l.to_string(), "-> a\n-> b");
+}
+
+// The progress enumerator counts only the items that render, and pads the
+// position so the brackets line up.
+#[test]
+fn progress_counts_rendered_items_only() {
+    let mut l = List::new().enumerator(lipgloss_list::progress);
+    for i in 0..12 {
+        l = l.item(format!("task {}", i));
+        if i % 4 == 0 {
+            l = l.item_node(Box::new(Leaf::new("skipped", true)));
+        }
+    }
+    let out = format!("{}", l);
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 12, "{}", out);
+    assert_eq!(lines[0], "[ 1/12] task 0");
+    assert_eq!(lines[9], "[10/12] task 9");
+    assert_eq!(lines[11], "[12/12] task 11");
+
+    // Offsets drop items from the count, and a one-digit count needs no pad.
+    let out = format!("{}", l.offset(2, 3));
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 7, "{}", out);
+    assert_eq!(lines[0], "[1/7] task 2");
+    assert_eq!(lines[6], "[7/7] task 8");
+}
+
+#[test]
+fn visible_length_skips_hidden_and_empty_items() {
+    // A nested list hangs off an unnamed container, which has no item.
+    let tree = Tree::new().child(vec![
+        "a".into(),
+        Box::new(Leaf::new("hidden", true)),
+        "b".into(),
+        Tree::new().child(vec!["nested".into()]).into(),
+    ]);
+    assert_eq!(tree.all_children().length(), 4);
+    assert_eq!(tree.all_children().visible_length(), 2);
+}
+
+#[test]
+fn owned_and_display_items_match_str_items() {
+    let expected = format!("{}", List::new().items(vec!["1", "2", "3"]));
+
+    let owned: Vec<String> = (1..=3).map(|i| i.to_string()).collect();
+    assert_eq!(format!("{}", List::new().items(owned.clone())), expected);
+    assert_eq!(format!("{}", List::from_items(owned.clone())), expected);
+    assert_eq!(format!("{}", owned.into_iter().collect::<List>()), expected);
+
+    let mut l = List::new();
+    for i in 1..=3 {
+        l = l.item_any(i);
+    }
+    assert_eq!(format!("{}", l), expected);
+}
+
+#[test]
+fn checklist_marks_done_items_in_sublists() {
+    let plain = lipgloss::Style::new();
+    let l = List::new()
+        .enumerator(checklist)
+        .done_style(plain.clone())
+        .item_done("Milk")
+        .item("Eggs")
+        .item_list(
+            List::new()
+                .enumerator(checklist_ascii)
+                .done_style(plain.clone())
+                .items(vec!["Flour", "Sugar"])
+                .set_done(1, true),
+        )
+        .item("Butter")
+        .set_done(3, true)
+        .set_done(0, false)
+        .set_done(9, true);
+    assert_eq!(
+        format!("{}", l),
+        "• Milk\n• Eggs\n  [ ] Flour\n  [x] Sugar\n✓ Butter"
+    );
+}
+
+#[test]
+fn done_state_is_visible_to_custom_enumerators_and_styled() {
+    let l = List::new()
+        .enumerator(|items: &dyn Children, i: usize| {
+            if items.is_done(i) { "done" } else { "todo" }.to_string()
+        })
+        .item("a")
+        .item_done("b");
+    let out = format!("{}", l);
+    assert!(out.starts_with("todo a\n"), "{}", out);
+    assert!(out.contains("done "), "{}", out);
+
+    // The done style only touches done items.
+    let marked = List::new()
+        .done_style(lipgloss::Style::new().transform(|s| s.to_uppercase()))
+        .item("a")
+        .item_done("b");
+    assert_eq!(format!("{}", marked), "• a\n• B");
+}
+
+#[test]
+fn tree_in_list_has_one_space_after_branch_glyphs() {
+    use lipgloss::Style;
+
+    for enum_style in [Style::new().padding_right(1), Style::new().padding_right(2)] {
+        for width in [2, 4] {
+            let tree = Tree::new().root("Tree").child(vec!["a".into(), "b".into()]);
+            let l = List::new()
+                .enumerator_style(enum_style.clone())
+                .item("Item")
+                .item_node(Box::new(tree))
+                .indent(width);
+            let out = l.to_string();
+            let branches: Vec<&str> = out.lines().filter(|line| line.contains("──")).collect();
+            assert_eq!(branches.len(), 2, "{}", out);
+            for line in branches {
+                assert!(
+                    line.ends_with("── a") || line.ends_with("── b"),
+                    "expected one space after the branch glyph in {:?}",
+                    line
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn enumerator_alignment_is_per_level_and_measures_styled_markers() {
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+    use lipgloss::{Style, LEFT};
+    use lipgloss_list::roman;
+
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::TrueColor);
+    let styled = Style::new()
+        .renderer(r)
+        .foreground("#ff0000")
+        .margin_right(1);
+
+    let l = List::new()
+        .enumerator(roman)
+        .enumerator_style(styled)
+        .items(vec!["a", "b", "c"])
+        .item_list(List::new().enumerator(roman).items(vec!["x", "y", "z"]))
+        .enumerator_alignment(LEFT);
+    let out = l.to_string();
+    assert!(out.contains('\x1b'), "markers should be styled: {:?}", out);
+
+    let plain: Vec<String> = out.lines().map(lipgloss::strip_ansi).collect();
+    // The outer level is left-aligned; widths are measured without escapes.
+    assert_eq!(&plain[..3], ["I.   a", "II.  b", "III. c"]);
+    // The sublist keeps the default right alignment.
+    for (line, text) in plain[3..].iter().zip(["x", "y", "z"]) {
+        assert!(line.ends_with(&format!(". {}", text)), "{:?}", line);
+        assert_eq!(lipgloss::width(line), plain[3].len());
+    }
+    assert!(plain[3].ends_with("  I. x"), "{:?}", plain[3]);
+}
+
+#[test]
+fn window_counts_top_level_items_and_marks_hidden_ones() {
+    let list = || {
+        List::new()
+            .items(vec!["A", "B"])
+            .item_list(List::new().items(vec!["b1", "b2"]))
+            .items(vec!["C", "D", "E"])
+            .window_marker("…")
+    };
+
+    // The sublist travels with B and does not count toward the height.
+    assert_eq!(
+        list().window(1, 2).to_string(),
+        "• A\n• B\n  • b1\n  • b2\n…"
+    );
+    assert_eq!(
+        list().window(2, 2).to_string(),
+        "…\n• B\n  • b1\n  • b2\n• C\n…"
+    );
+    // Clamped at the ends, and the selection past the end is the last item.
+    assert_eq!(
+        list().window(0, 3).to_string(),
+        "• A\n• B\n  • b1\n  • b2\n• C\n…"
+    );
+    assert_eq!(list().window(99, 2).to_string(), "…\n• D\n• E");
+    assert_eq!(list().window(0, 10).to_string(), list().to_string());
+    assert_eq!(list().offset_from_end(1).to_string(), "…\n• E");
+    assert_eq!(list().offset_from_end(0).to_string(), "…");
+}
+
+#[test]
+fn window_markers_use_the_enumerator_column_and_style() {
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+    use lipgloss::Style;
+
+    // Right-aligned like the enumerators, without trailing padding.
+    let l = List::new()
+        .enumerator(lipgloss_list::roman)
+        .items(vec!["a", "b", "c", "d", "e"])
+        .window(2, 3)
+        .window_marker("…");
+    assert_eq!(l.to_string(), "   …\n  I. b\n II. c\nIII. d\n   …");
+
+    // Sublists window their own items, marked at their own indentation.
+    let l = List::new()
+        .item("Logs")
+        .item_list(
+            List::new()
+                .items(vec!["one", "two", "three"])
+                .offset_from_end(1)
+                .window_marker("…"),
+        )
+        .item("Done");
+    assert_eq!(l.to_string(), "• Logs\n  …\n  • three\n• Done");
+
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::TrueColor);
+    let l = List::new()
+        .items(vec!["A", "B", "C"])
+        .enumerator_style(Style::new().renderer(r).foreground("#ff0000"))
+        .window(0, 1)
+        .window_marker("…");
+    let last = l.to_string().lines().last().unwrap().to_string();
+    assert!(last.starts_with("\x1b[38;2;255;0;0m"), "{:?}", last);
+    assert_eq!(lipgloss::strip_ansi(&last), "…");
+}
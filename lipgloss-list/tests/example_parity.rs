@@ -0,0 +1,44 @@
+//! Checks the ported list examples against the output of the Go examples.
+
+use std::fs;
+use std::path::PathBuf;
+
+use lipgloss::renderer::{set_color_profile, ColorProfileKind};
+use lipgloss::strip_ansi;
+use lipgloss_list::List;
+
+/// An example's golden file name and the function that builds its list.
+type Example = (&'static str, fn() -> List);
+
+fn read_golden(name: &str) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/testdata/examples")
+        .join(format!("{}.golden", name));
+    fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("failed to read golden {}: {}", path.display(), e))
+}
+
+#[test]
+fn test_examples_match_go_reference() {
+    let examples: [Example; 6] = [
+        (
+            "duckduckgoose",
+            duckduckgoose_list_demo::duck_duck_goose_list,
+        ),
+        ("glow", glow_list_demo::glow_list),
+        ("grocery", grocery_list_demo::grocery_list),
+        ("roman", roman_list_demo::roman_list),
+        ("simple", simple_list_demo::simple_list),
+        ("sublist", sublist_demo::sublist),
+    ];
+
+    // The references come from Go's Ascii profile, which drops text
+    // attributes such as faint along with colors. NoColor only drops colors,
+    // so this is not a byte-for-byte comparison: what is left of the styling
+    // is stripped first.
+    set_color_profile(ColorProfileKind::NoColor);
+    for (name, build) in examples {
+        let got = strip_ansi(&build().to_string());
+        assert_eq!(got, read_golden(name), "{}", name);
+    }
+}
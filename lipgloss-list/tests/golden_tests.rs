@@ -136,15 +136,61 @@ fn golden_sublist() {
     assert_golden("TestSublist.golden", &format!("{}", outer));
 }
 
+// Three levels whose enumerator styles carry a right margin: each level is
+// indented by the styled width of a one-column indent, as in Go, so nested
+// items line up with their parent's text even when the items are styled too.
+fn margin_sublist(enumerator_style: lipgloss::Style, item_style: lipgloss::Style) -> List {
+    let level = |list: List| {
+        list.enumerator_style(enumerator_style.clone())
+            .item_style(item_style.clone())
+    };
+    level(List::new())
+        .item("Fruits")
+        .item_list(level(
+            List::new()
+                .enumerator(alphabet)
+                .item("Citrus")
+                .item_list(level(
+                    List::new()
+                        .enumerator(roman)
+                        .items(vec!["Lemon", "Lime", "Orange"]),
+                ))
+                .item("Berries"),
+        ))
+        .item("Vegetables")
+}
+
+#[test]
+fn golden_sublist_enumerator_margin() {
+    use lipgloss::Style;
+
+    assert_golden(
+        "TestSublistEnumeratorMargin.golden",
+        &margin_sublist(Style::new().margin_right(1), Style::new()).to_string(),
+    );
+    assert_golden(
+        "TestSublistEnumeratorMarginItemStyle.golden",
+        &margin_sublist(Style::new().margin_right(2), Style::new().padding_left(1)).to_string(),
+    );
+}
+
 // TestComplexSublist: deep nesting, mixed enumerators, and trees
-// TODO: Fix tree-in-list spacing issue - see @docs/TREE_IN_LIST_SPACING_ISSUE.md
-// The test fails because trees nested in lists get extra spacing after enumerator symbols.
-// This is an architectural issue where tree padding_right(1) conflicts with list_indenter(2).
-// All other list functionality works correctly (18/19 tests passing).
 #[test]
-#[ignore]
 fn golden_complex_sublist() {
-    use lipgloss_tree::{Leaf, Node, Tree};
+    assert_golden(
+        "TestComplexSublist.golden",
+        &format!("{}", complex_sublist()),
+    );
+}
+
+fn complex_sublist() -> List {
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+    use lipgloss_tree::{children, Tree};
+
+    // The golden output is true color, whatever the terminal running the
+    // tests supports.
+    let mut renderer = Renderer::new();
+    renderer.set_color_profile(ColorProfileKind::TrueColor);
 
     // • Foo
     // • Bar
@@ -216,19 +262,18 @@ fn golden_complex_sublist() {
     let tree = Tree::new()
         .enumerator_style(
             lipgloss::Style::new()
+                .renderer(renderer)
                 .foreground(lipgloss::Color::from("212"))
                 .padding_right(1),
         )
-        .child(vec![
-            Box::new(Tree::new().root("another\nmultine\nstring")) as Box<dyn Node>,
-            Box::new(Leaf::new("something", false)) as Box<dyn Node>,
-            Box::new(Tree::new().root("a subtree").child(vec![
-                Box::new(Leaf::new("yup", false)) as Box<dyn Node>,
-                Box::new(Leaf::new("many itens", false)) as Box<dyn Node>,
-                Box::new(Leaf::new("another", false)) as Box<dyn Node>,
-            ])) as Box<dyn Node>,
-            Box::new(Leaf::new("hallo", false)) as Box<dyn Node>,
-            Box::new(Leaf::new("wunderbar!", false)) as Box<dyn Node>,
+        .child(children![
+            Tree::new().root("another\nmultine\nstring"),
+            "something",
+            Tree::new()
+                .root("a subtree")
+                .child(children!["yup", "many itens", "another"]),
+            "hallo",
+            "wunderbar!",
         ]);
 
     // Another tree from free text
@@ -254,9 +299,48 @@ fn golden_complex_sublist() {
         .item("bar");
 
     // Attach Deep branch to root and add Baz
-    root = root.item("Deep").item_list(deep_children).item("Baz");
+    root.item("Deep").item_list(deep_children).item("Baz")
+}
 
-    assert_golden("TestComplexSublist.golden", &format!("{}", root));
+fn two_level_list() -> List {
+    List::new()
+        .item("Fruits")
+        .item_list(
+            List::new()
+                .enumerator(arabic)
+                .item("Apple")
+                .item_list(List::new().items(vec!["Gala", "Fuji"]))
+                .item("Banana"),
+        )
+        .item("Vegetables")
+}
+
+#[test]
+fn golden_indent_width() {
+    for width in [1, 2, 4] {
+        assert_golden(
+            &format!("TestIndentWidth{}.golden", width),
+            &two_level_list().indent_width(width).to_string(),
+        );
+    }
+    // The default matches Go's two columns.
+    assert_golden("TestIndentWidth2.golden", &two_level_list().to_string());
+}
+
+#[test]
+fn golden_complex_sublist_indent_width() {
+    // Nested trees start at the list's indentation and keep their own
+    // single space after each branch glyph.
+    assert_golden(
+        "TestComplexSublist.golden",
+        &complex_sublist().indent_width(2).to_string(),
+    );
+    for width in [1, 4] {
+        assert_golden(
+            &format!("TestComplexSublistIndentWidth{}.golden", width),
+            &complex_sublist().indent_width(width).to_string(),
+        );
+    }
 }
 
 // TestSublistItems and TestSubListItems2
@@ -349,3 +433,41 @@ fn golden_enumerators_transform_roman_within_parens() {
         &format!("{}", l),
     );
 }
+
+const PACKAGES: [&str; 10] = [
+    "apt",
+    "bash",
+    "coreutils",
+    "curl",
+    "git",
+    "grep",
+    "less",
+    "openssl",
+    "sed",
+    "zsh",
+];
+
+#[test]
+fn golden_columns_column_major() {
+    let l = List::new()
+        .items(PACKAGES.to_vec())
+        .enumerator(arabic)
+        .columns(3);
+    assert_golden("TestColumns/column_major.golden", &format!("{}", l));
+}
+
+#[test]
+fn golden_columns_row_major() {
+    let l = List::new()
+        .items(PACKAGES.to_vec())
+        .enumerator(arabic)
+        .columns(3)
+        .row_major(true);
+    assert_golden("TestColumns/row_major.golden", &format!("{}", l));
+}
+
+#[test]
+fn golden_columns_bullet() {
+    let l = List::new().items(PACKAGES.to_vec()).columns(3);
+    assert_golden("TestColumns/bullet.golden", &format!("{}", l));
+}
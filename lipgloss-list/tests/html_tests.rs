@@ -0,0 +1,57 @@
+use lipgloss::{html, Style};
+use lipgloss_list::{enumerator, List};
+
+#[test]
+fn test_to_html_nested_lists_pick_tags_from_enumerators() {
+    let list = List::new()
+        .item("Fruit")
+        .item_list(
+            List::new()
+                .items(vec!["Apple", "Banana"])
+                .enumerator(enumerator::arabic),
+        )
+        .item("Veg \"greens\"")
+        .item_list(
+            List::new()
+                .items(vec!["Kale"])
+                .enumerator(enumerator::alphabet)
+                .item_style(Style::new().underline(true).strikethrough(true)),
+        )
+        .item_style(Style::new().background("#0000ff"));
+
+    let html = list.to_html();
+    assert_eq!(
+        html,
+        "<div style=\"font-family:monospace;white-space:pre-wrap\"><ul>\
+         <li><span style=\"background-color:#0000ff\">Fruit</span>\
+         <ol><li><span style=\"background-color:#0000ff\">Apple</span></li>\
+         <li><span style=\"background-color:#0000ff\">Banana</span></li></ol></li>\
+         <li><span style=\"background-color:#0000ff\">Veg &quot;greens&quot;</span>\
+         <ol type=\"A\"><li><span style=\"text-decoration:underline line-through\">Kale</span></li></ol></li>\
+         </ul></div>"
+    );
+    assert_eq!(
+        html::check_tags(&html).unwrap(),
+        [
+            "div", "ul", "li", "span", "ol", "li", "span", "li", "span", "li", "span", "ol", "li",
+            "span"
+        ]
+    );
+}
+
+#[test]
+fn test_to_html_enumerator_kinds() {
+    let cases: [(enumerator::Enumerator, &str); 6] = [
+        (enumerator::bullet, "<ul>"),
+        (enumerator::dash, "<ul>"),
+        (enumerator::asterisk, "<ul>"),
+        (enumerator::arabic, "<ol>"),
+        (enumerator::alphabet, "<ol type=\"A\">"),
+        (enumerator::roman, "<ol type=\"I\">"),
+    ];
+    for (e, open) in cases {
+        let html = List::new().items(vec!["a", "b"]).enumerator(e).to_html();
+        html::check_tags(&html).unwrap();
+        assert!(html.contains(open), "{}", html);
+    }
+}
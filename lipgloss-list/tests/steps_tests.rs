@@ -0,0 +1,67 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use lipgloss::renderer::{set_color_profile, ColorProfileKind};
+use lipgloss_list::{steps, StepLine, StepStatus, Steps};
+
+fn run() -> Vec<StepLine> {
+    vec![
+        StepLine::new("Fetch dependencies", StepStatus::Done).duration(Duration::from_millis(850)),
+        StepLine::new("Compile", StepStatus::Done).duration(Duration::from_millis(2300)),
+        StepLine::new(
+            "Run the integration test suite against staging",
+            StepStatus::Failed,
+        )
+        .duration(Duration::from_secs(83)),
+        StepLine::new("Package", StepStatus::Running).duration(Duration::from_millis(12_400)),
+        StepLine::new("Deploy", StepStatus::Pending),
+    ]
+}
+
+#[test]
+fn test_steps_golden_width_50() {
+    let out = Steps::new(run()).width(50).build().to_string();
+    let plain = lipgloss::strip_ansi(&out);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/testdata/TestSteps.golden");
+    let want = fs::read_to_string(&path).unwrap();
+    assert_eq!(plain, want);
+    assert!(plain.lines().all(|line| lipgloss::width(line) == 50));
+}
+
+#[test]
+fn test_steps_align_durations_without_width() {
+    let out = lipgloss::strip_ansi(&steps(run()[..2].to_vec()).to_string());
+    assert_eq!(
+        out,
+        "[1/2] ✓ Fetch dependencies  850ms\n[2/2] ✓ Compile              2.3s"
+    );
+}
+
+#[test]
+fn test_steps_status_colors_degrade_to_ansi() {
+    set_color_profile(ColorProfileKind::ANSI);
+    let out = Steps::new(run())
+        .status_style(
+            StepStatus::Running,
+            lipgloss::Style::new()
+                .foreground("#0000ff")
+                .padding_right(1),
+        )
+        .build()
+        .to_string();
+    let markers: Vec<&str> = out
+        .lines()
+        .map(|line| line.split("\x1b[0m").next().unwrap())
+        .collect();
+    assert_eq!(
+        markers,
+        [
+            "\x1b[32m[1/5] ✓",
+            "\x1b[32m[2/5] ✓",
+            "\x1b[91m[3/5] ✗",
+            "\x1b[94m[4/5] ◐",
+            "\x1b[37m[5/5] ○",
+        ]
+    );
+}
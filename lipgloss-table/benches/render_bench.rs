@@ -0,0 +1,47 @@
+//! Rendering benchmarks for tables.
+//!
+//! A second render of an unchanged table should reuse the cached layout
+//! and only draw the table again.
+
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use lipgloss::Style;
+use lipgloss_table::Table;
+use std::hint::black_box;
+
+fn build_table() -> Table {
+    let rows: Vec<Vec<String>> = (0..200)
+        .map(|i| {
+            vec![
+                i.to_string(),
+                format!("name {}", i),
+                "a longer description that will need wrapping".repeat(1 + i % 3),
+            ]
+        })
+        .collect();
+    Table::new()
+        .headers(vec!["ID", "Name", "Description"])
+        .rows(rows)
+        .width(80)
+        .style_func_boxed(|_, _| Style::new().padding(0, 1, 0, 1))
+}
+
+fn benchmark_first_render(c: &mut Criterion) {
+    c.bench_function("table_first_render", |b| {
+        b.iter_batched(
+            build_table,
+            |mut table| black_box(table.render()),
+            BatchSize::LargeInput,
+        )
+    });
+}
+
+fn benchmark_cached_render(c: &mut Criterion) {
+    let mut table = build_table();
+    table.render();
+    c.bench_function("table_cached_render", |b| {
+        b.iter(|| black_box(table.render()))
+    });
+}
+
+criterion_group!(benches, benchmark_first_render, benchmark_cached_render);
+criterion_main!(benches);
@@ -0,0 +1,139 @@
+//! Totals computed from the data for the aggregate row.
+//!
+//! [`Table::aggregate_row`](crate::Table::aggregate_row) takes one
+//! [`Aggregate`] per column and fills the footer with what they compute over
+//! the table's rows.
+
+/// How one column of the [aggregate row](crate::Table::aggregate_row) is
+/// computed.
+///
+/// The numeric aggregates read cells that parse as numbers, ignoring escape
+/// sequences, surrounding whitespace and `,` thousands separators, and skip
+/// any other cell. A column with no numbers shows the
+/// [placeholder](crate::Table::aggregate_placeholder) instead.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregate {
+    /// The sum of the numbers, with as many decimals as the most precise
+    /// of them.
+    Sum,
+    /// The mean of the numbers, with `precision` decimals.
+    Avg {
+        /// Decimals shown.
+        precision: usize,
+    },
+    /// How many cells are not blank, numbers or not.
+    Count,
+    /// The smallest number, as written in its cell.
+    Min,
+    /// The largest number, as written in its cell.
+    Max,
+    /// An empty cell.
+    None,
+    /// A fixed text, such as `"Total"`.
+    Label(String),
+}
+
+impl Aggregate {
+    /// Computes the cell of this aggregate over `cells`, or `placeholder`
+    /// for a numeric aggregate that finds no numbers.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_table::Aggregate;
+    ///
+    /// let cells = ["1.5", "n/a", "", "2,000"];
+    /// assert_eq!(Aggregate::Sum.compute(cells, "-"), "2001.5");
+    /// assert_eq!(Aggregate::Avg { precision: 2 }.compute(cells, "-"), "1000.75");
+    /// assert_eq!(Aggregate::Count.compute(cells, "-"), "3");
+    /// assert_eq!(Aggregate::Max.compute(cells, "-"), "2,000");
+    /// assert_eq!(Aggregate::Min.compute(["n/a"], "-"), "-");
+    /// ```
+    pub fn compute<I, S>(&self, cells: I, placeholder: &str) -> String
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let cells = cells.into_iter();
+        match self {
+            Aggregate::None => String::new(),
+            Aggregate::Label(label) => label.clone(),
+            Aggregate::Count => cells
+                .filter(|cell| !lipgloss::strip_ansi(cell.as_ref()).trim().is_empty())
+                .count()
+                .to_string(),
+            Aggregate::Sum | Aggregate::Avg { .. } | Aggregate::Min | Aggregate::Max => {
+                let numbers: Vec<(f64, String)> = cells
+                    .filter_map(|cell| {
+                        let text = lipgloss::strip_ansi(cell.as_ref()).trim().to_string();
+                        Some((parse_number(&text)?, text))
+                    })
+                    .collect();
+                if numbers.is_empty() {
+                    return placeholder.to_string();
+                }
+                let total: f64 = numbers.iter().map(|(value, _)| value).sum();
+                match self {
+                    Aggregate::Sum => {
+                        let decimals = numbers.iter().map(|(_, text)| decimals(text)).max();
+                        format!("{:.*}", decimals.unwrap_or(0), total)
+                    }
+                    Aggregate::Avg { precision } => {
+                        format!("{:.*}", precision, total / numbers.len() as f64)
+                    }
+                    Aggregate::Min => extreme(&numbers, |a, b| a < b),
+                    _ => extreme(&numbers, |a, b| a > b),
+                }
+            }
+        }
+    }
+}
+
+/// Parses a number as written in a cell, allowing `,` thousands separators.
+fn parse_number(text: &str) -> Option<f64> {
+    text.replace(',', "")
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+}
+
+/// Number of digits after the decimal point.
+fn decimals(text: &str) -> usize {
+    text.split_once('.').map_or(0, |(_, fraction)| {
+        fraction.chars().take_while(char::is_ascii_digit).count()
+    })
+}
+
+/// The text of the first number that `better` prefers over all others.
+fn extreme(numbers: &[(f64, String)], better: fn(f64, f64) -> bool) -> String {
+    let mut best = &numbers[0];
+    for number in &numbers[1..] {
+        if better(number.0, best.0) {
+            best = number;
+        }
+    }
+    best.1.clone()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sum_keeps_the_inputs_precision() {
+        assert_eq!(Aggregate::Sum.compute(["0.1", "0.2"], ""), "0.3");
+        assert_eq!(Aggregate::Sum.compute(["1", "2.50", "-3"], ""), "0.50");
+        assert_eq!(Aggregate::Sum.compute(["\x1b[1m7\x1b[0m", " 5 "], ""), "12");
+    }
+
+    #[test]
+    fn test_numeric_aggregates_skip_other_cells() {
+        let cells = ["4", "", "n/a", "NaN", "2"];
+        assert_eq!(Aggregate::Avg { precision: 1 }.compute(cells, "?"), "3.0");
+        assert_eq!(Aggregate::Min.compute(cells, "?"), "2");
+        assert_eq!(Aggregate::Max.compute(cells, "?"), "4");
+        assert_eq!(Aggregate::Count.compute(cells, "?"), "4");
+        assert_eq!(Aggregate::Sum.compute(["", "x"], "?"), "?");
+        assert_eq!(Aggregate::Avg { precision: 2 }.compute([""; 0], "?"), "?");
+    }
+}
@@ -0,0 +1,134 @@
+//! HTML export for tables, enabled with the `html` feature.
+
+use crate::{Table, FOOTER_ROW, HEADER_ROW};
+use lipgloss::html::{escape_text, padding_css, style_attr, style_css, CONTAINER_CSS};
+
+impl Table {
+    /// Renders the table as an HTML `<table>`.
+    ///
+    /// Headers go in a `<thead>`, data rows in a `<tbody>` and footers in a
+    /// `<tfoot>`. Each cell gets inline CSS from the style function (colors,
+    /// text attributes, alignment and padding), with column alignments applied
+    /// the same way as in the terminal. Content is escaped and stripped of ANSI sequences.
+    ///
+    /// All rows and columns are exported; height, offsets and width limits
+    /// only affect terminal rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_table::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(vec!["Name"])
+    ///     .row(vec!["<Alice>"]);
+    ///
+    /// assert_eq!(
+    ///     table.to_html(),
+    ///     "<table style=\"font-family:monospace;white-space:pre-wrap;border-collapse:collapse\">\n\
+    ///      <thead>\n<tr><th>Name</th></tr>\n</thead>\n\
+    ///      <tbody>\n<tr><td>&lt;Alice&gt;</td></tr>\n</tbody>\n\
+    ///      </table>"
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        let columns = self
+            .headers
+            .len()
+            .max(self.footers.len())
+            .max(self.data.columns());
+        let mut out = format!(
+            "<table{}>\n",
+            style_attr(&format!("{};border-collapse:collapse", CONTAINER_CSS))
+        );
+
+        if !self.headers.is_empty() {
+            out.push_str("<thead>\n");
+            out.push_str(&self.html_row("th", HEADER_ROW, columns, |c| {
+                self.headers.get(c).cloned().unwrap_or_default()
+            }));
+            out.push_str("</thead>\n");
+        }
+
+        if self.data.rows() > 0 {
+            out.push_str("<tbody>\n");
+            for r in 0..self.data.rows() {
+                out.push_str(&self.html_row("td", r as i32, columns, |c| {
+                    if c < self.data.columns() {
+                        self.data.at(r, c)
+                    } else {
+                        String::new()
+                    }
+                }));
+            }
+            out.push_str("</tbody>\n");
+        }
+
+        if !self.footers.is_empty() {
+            out.push_str("<tfoot>\n");
+            out.push_str(&self.html_row("td", FOOTER_ROW, columns, |c| {
+                self.footers.get(c).cloned().unwrap_or_default()
+            }));
+            out.push_str("</tfoot>\n");
+        }
+
+        out.push_str("</table>");
+        out
+    }
+
+    /// Renders one `<tr>` with a cell per column. Spanning cells get a
+    /// `colspan` and the cells they cover are left out.
+    fn html_row(
+        &self,
+        tag: &str,
+        row: i32,
+        columns: usize,
+        cell: impl Fn(usize) -> String,
+    ) -> String {
+        let spans = usize::try_from(row)
+            .ok()
+            .and_then(|r| self.cell_spans.get(&r));
+        let rows = self.data.rows();
+        let rowspan = |c: usize| {
+            let row = usize::try_from(row).ok()?;
+            self.cell_rowspans
+                .iter()
+                .find(|&&(r, col, n)| col == c && (r..r.saturating_add(n)).contains(&row))
+                .map(|&(r, _, n)| (r == row, n.min(rows - r)))
+        };
+        let mut out = String::from("<tr>");
+        let mut c = 0;
+        while c < columns {
+            // Cells under a row span from a row above are left out.
+            let rowspan = match rowspan(c) {
+                Some((false, _)) => {
+                    c += 1;
+                    continue;
+                }
+                Some((true, n)) if n > 1 => format!(" rowspan=\"{}\"", n),
+                _ => String::new(),
+            };
+            let span = spans
+                .and_then(|spans| spans.iter().find(|&&(col, _)| col == c))
+                .map_or(1, |&(_, n)| n.min(columns - c));
+            let colspan = if span > 1 {
+                format!(" colspan=\"{}\"", span)
+            } else {
+                String::new()
+            };
+            let style = self.get_cell_style(row, c);
+            let css: Vec<String> = [style_css(&style), padding_css(&style)]
+                .into_iter()
+                .filter(|s| !s.is_empty())
+                .collect();
+            out.push_str(&format!(
+                "<{tag}{colspan}{rowspan}{}>{}</{tag}>",
+                style_attr(&css.join(";")),
+                escape_text(&cell(c)),
+            ));
+            c += span;
+        }
+        out.push_str("</tr>\n");
+        out
+    }
+}
@@ -0,0 +1,266 @@
+//! Column type inference for default alignment.
+//!
+//! [`Table::infer_alignment`](crate::Table::infer_alignment) samples the
+//! cells of each column and right-aligns the columns that hold only numbers
+//! or only dates. The classification is exposed here so it can be reused and
+//! tested on its own.
+
+use lipgloss::position::{LEFT, RIGHT};
+use lipgloss::Position;
+
+/// The kind of value a cell holds, as far as alignment is concerned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CellKind {
+    /// Blank, or whitespace only. Empty cells do not affect inference.
+    Empty,
+    /// A number, optionally signed, with `,` thousands separators, a
+    /// fraction, an exponent or a trailing `%`: `42`, `-1,234.5`, `12.5%`.
+    Number,
+    /// An ISO 8601 date, optionally followed by a time: `2024-03-01`,
+    /// `2024-03-01T12:30:00Z`, `2024-03-01 12:30`.
+    Date,
+    /// Anything else.
+    Text,
+}
+
+/// Classifies a single cell. Escape sequences are ignored and surrounding
+/// whitespace is trimmed.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_table::infer::{classify, CellKind};
+///
+/// assert_eq!(classify("1,234.50"), CellKind::Number);
+/// assert_eq!(classify("99%"), CellKind::Number);
+/// assert_eq!(classify("2024-03-01"), CellKind::Date);
+/// assert_eq!(classify("n/a"), CellKind::Text);
+/// assert_eq!(classify("  "), CellKind::Empty);
+/// ```
+pub fn classify(cell: &str) -> CellKind {
+    let plain = lipgloss::strip_ansi(cell);
+    let cell = plain.trim();
+    if cell.is_empty() {
+        CellKind::Empty
+    } else if is_number(cell) {
+        CellKind::Number
+    } else if is_iso_date(cell) {
+        CellKind::Date
+    } else {
+        CellKind::Text
+    }
+}
+
+/// Picks the alignment for a column from its cells.
+///
+/// A column whose non-empty cells are all numbers, or all dates, is aligned
+/// right; anything else, including a column with no non-empty cells, is
+/// aligned left.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::position::{LEFT, RIGHT};
+/// use lipgloss_table::infer::infer_alignment;
+///
+/// assert_eq!(infer_alignment(["1", "", "2,500"]), RIGHT);
+/// assert_eq!(infer_alignment(["1", "two"]), LEFT);
+/// ```
+pub fn infer_alignment<'a>(cells: impl IntoIterator<Item = &'a str>) -> Position {
+    let mut kind = CellKind::Empty;
+    for cell in cells {
+        match (kind, classify(cell)) {
+            (_, CellKind::Empty) => {}
+            (CellKind::Empty, next) => kind = next,
+            (current, next) if current == next => {}
+            _ => return LEFT,
+        }
+    }
+    match kind {
+        CellKind::Number | CellKind::Date => RIGHT,
+        CellKind::Empty | CellKind::Text => LEFT,
+    }
+}
+
+/// Whether `s` is a number: optional sign, digits with optional `,`
+/// grouping, optional fraction and exponent, optional trailing `%`.
+fn is_number(s: &str) -> bool {
+    let s = s.strip_suffix('%').unwrap_or(s);
+    let s = s.strip_prefix(['+', '-']).unwrap_or(s);
+
+    let (mantissa, exponent) = match s.find(['e', 'E']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+    if let Some(exponent) = exponent {
+        let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+        if !is_digits(digits) {
+            return false;
+        }
+    }
+
+    let (integer, fraction) = match mantissa.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (mantissa, None),
+    };
+    match fraction {
+        Some(fraction) if !is_digits(fraction) => return false,
+        Some(_) if integer.is_empty() => return true,
+        _ => {}
+    }
+    is_grouped_integer(integer)
+}
+
+/// Digits, either plain or grouped in threes with `,`: `1234`, `1,234`.
+fn is_grouped_integer(s: &str) -> bool {
+    if !s.contains(',') {
+        return is_digits(s);
+    }
+    let mut groups = s.split(',');
+    let first = groups.next().unwrap_or_default();
+    is_digits(first) && first.len() <= 3 && groups.all(|g| g.len() == 3 && is_digits(g))
+}
+
+fn is_digits(s: &str) -> bool {
+    !s.is_empty() && s.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Whether `s` is an ISO 8601 date, `YYYY-MM-DD`, optionally followed by
+/// `T` or a space and a time.
+fn is_iso_date(s: &str) -> bool {
+    let (date, time) = match s.find(['T', ' ']) {
+        Some(i) => (&s[..i], Some(&s[i + 1..])),
+        None => (s, None),
+    };
+
+    let parts: Vec<&str> = date.split('-').collect();
+    let [year, month, day] = parts.as_slice() else {
+        return false;
+    };
+    let valid_date = year.len() == 4
+        && is_digits(year)
+        && in_range(month, 2, 1..=12)
+        && in_range(day, 2, 1..=31);
+    valid_date && time.is_none_or(is_iso_time)
+}
+
+/// `HH:MM`, optionally with seconds, a fraction and a `Z` or `±HH:MM` zone.
+fn is_iso_time(s: &str) -> bool {
+    let s = s.strip_suffix('Z').unwrap_or(s);
+    let s = match s.rfind(['+', '-']) {
+        Some(i) if is_hh_mm(&s[i + 1..]) => &s[..i],
+        Some(_) => return false,
+        None => s,
+    };
+    let (s, fraction) = match s.split_once('.') {
+        Some((s, fraction)) => (s, Some(fraction)),
+        None => (s, None),
+    };
+    if fraction.is_some_and(|f| !is_digits(f)) {
+        return false;
+    }
+
+    let parts: Vec<&str> = s.split(':').collect();
+    match parts.as_slice() {
+        [hour, minute] => in_range(hour, 2, 0..=23) && in_range(minute, 2, 0..=59),
+        [hour, minute, second] => {
+            in_range(hour, 2, 0..=23) && in_range(minute, 2, 0..=59) && in_range(second, 2, 0..=60)
+        }
+        _ => false,
+    }
+}
+
+fn is_hh_mm(s: &str) -> bool {
+    matches!(s.split_once(':'), Some((h, m)) if in_range(h, 2, 0..=23) && in_range(m, 2, 0..=59))
+}
+
+/// Whether `s` is exactly `len` digits with a value in `range`.
+fn in_range(s: &str, len: usize, range: std::ops::RangeInclusive<u32>) -> bool {
+    s.len() == len && is_digits(s) && s.parse().is_ok_and(|n| range.contains(&n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_numbers() {
+        for cell in [
+            "0",
+            "42",
+            "-7",
+            "+3.25",
+            "1,234",
+            "12,345,678.9",
+            ".5",
+            "1e6",
+            "-2.5E-3",
+            "12.5%",
+            "-3%",
+            "\x1b[31m99\x1b[0m",
+            " 7 ",
+        ] {
+            assert_eq!(classify(cell), CellKind::Number, "{:?}", cell);
+        }
+    }
+
+    #[test]
+    fn test_classify_rejects_malformed_numbers() {
+        for cell in [
+            "1,23", "1234,567", ",123", "1,,234", "1.2.3", "1.", "-", "%", "e5", "1e", "inf",
+            "NaN", "0x1F", "12 345", "$5",
+        ] {
+            assert_eq!(classify(cell), CellKind::Text, "{:?}", cell);
+        }
+    }
+
+    #[test]
+    fn test_classify_dates() {
+        for cell in [
+            "2024-03-01",
+            "1999-12-31",
+            "2024-03-01T12:30",
+            "2024-03-01T12:30:59Z",
+            "2024-03-01 08:00:00",
+            "2024-03-01T12:30:00.125+02:00",
+            "2024-03-01T23:59:60-05:30",
+        ] {
+            assert_eq!(classify(cell), CellKind::Date, "{:?}", cell);
+        }
+        for cell in [
+            "2024-13-01",
+            "2024-00-10",
+            "2024-03-32",
+            "24-03-01",
+            "2024-3-1",
+            "2024/03/01",
+            "2024-03-01T25:00",
+            "2024-03-01T12",
+            "2024-03-01T12:30+2",
+            "2024-03-01 later",
+        ] {
+            assert_eq!(classify(cell), CellKind::Text, "{:?}", cell);
+        }
+    }
+
+    #[test]
+    fn test_classify_empty() {
+        assert_eq!(classify(""), CellKind::Empty);
+        assert_eq!(classify(" \t"), CellKind::Empty);
+        assert_eq!(classify("\x1b[1m\x1b[0m"), CellKind::Empty);
+    }
+
+    #[test]
+    fn test_infer_alignment() {
+        assert_eq!(infer_alignment(["1", "22", "-3.5"]), RIGHT);
+        assert_eq!(infer_alignment(["", "1,000", " ", "50%"]), RIGHT);
+        assert_eq!(infer_alignment(["2024-01-01", "", "2024-02-01"]), RIGHT);
+        // Mixed kinds fall back to left.
+        assert_eq!(infer_alignment(["1", "2024-01-01"]), LEFT);
+        assert_eq!(infer_alignment(["1", "2", "three"]), LEFT);
+        assert_eq!(infer_alignment(["alpha", "beta"]), LEFT);
+        // Nothing to go on.
+        assert_eq!(infer_alignment(["", " "]), LEFT);
+        assert_eq!(infer_alignment(std::iter::empty()), LEFT);
+    }
+}
@@ -0,0 +1,103 @@
+//! Markdown export for tables.
+
+use crate::Table;
+use lipgloss::Position;
+
+impl Table {
+    /// Renders the table as a GitHub-flavored markdown table.
+    ///
+    /// The headers make up the header row (left blank when there are none),
+    /// followed by every data row and then the footer, if any. Columns set
+    /// with [`Table::column_alignment`] or inferred with
+    /// [`Table::infer_alignment`] get a matching delimiter (`:---`, `:---:`
+    /// or `---:`).
+    ///
+    /// Cell content is stripped of ANSI sequences, pipes are escaped and line
+    /// breaks become `<br>`. Like [`Table`]'s other exports, this does not
+    /// depend on [`Table::render`]: styles, borders, height, offsets and
+    /// width limits only affect terminal rendering.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::RIGHT;
+    /// use lipgloss_table::Table;
+    ///
+    /// let table = Table::new()
+    ///     .headers(vec!["Name", "Score"])
+    ///     .row(vec!["a|b", "10"])
+    ///     .column_alignment(1, RIGHT);
+    ///
+    /// assert_eq!(
+    ///     table.to_markdown(),
+    ///     "| Name | Score |\n| --- | ---: |\n| a\\|b | 10 |"
+    /// );
+    /// ```
+    pub fn to_markdown(&self) -> String {
+        let columns = self
+            .headers
+            .len()
+            .max(self.footers.len())
+            .max(self.data.columns());
+        if columns == 0 {
+            return String::new();
+        }
+
+        let inferred = self.infer_alignments();
+        let delimiters: Vec<String> = (0..columns)
+            .map(|c| {
+                let align = self
+                    .column_alignments
+                    .get(&c)
+                    .or_else(|| inferred.get(&c))
+                    .copied();
+                markdown_delimiter(align).to_string()
+            })
+            .collect();
+
+        let mut lines = vec![
+            markdown_row((0..columns).map(|c| self.headers.get(c).cloned().unwrap_or_default())),
+            markdown_row(delimiters.into_iter()),
+        ];
+        for r in 0..self.data.rows() {
+            lines.push(markdown_row((0..columns).map(|c| {
+                if c < self.data.columns() {
+                    self.data.at(r, c)
+                } else {
+                    String::new()
+                }
+            })));
+        }
+        if !self.footers.is_empty() {
+            lines.push(markdown_row(
+                (0..columns).map(|c| self.footers.get(c).cloned().unwrap_or_default()),
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Formats one markdown table row from raw cell content.
+fn markdown_row(cells: impl Iterator<Item = String>) -> String {
+    let cells: Vec<String> = cells.map(|cell| escape_cell(&cell)).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Strips styling from a cell and escapes what would break the table.
+fn escape_cell(cell: &str) -> String {
+    lipgloss::strip_ansi(cell)
+        .replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace('\n', "<br>")
+}
+
+/// The delimiter cell for a column alignment: positions left of center
+/// align left, right of center align right.
+fn markdown_delimiter(align: Option<Position>) -> &'static str {
+    match align {
+        None => "---",
+        Some(Position(p)) if p < 0.5 => ":---",
+        Some(Position(p)) if p > 0.5 => "---:",
+        Some(_) => ":---:",
+    }
+}
@@ -1,3 +1,7 @@
+/// The narrowest a column is drawn, so that its separators never run
+/// together, however little room the table has.
+const MIN_COLUMN_WIDTH: usize = 1;
+
 /// A column representation in the table resizer with width analysis and content.
 ///
 /// `ResizerColumn` stores statistical information about a table column including
@@ -24,8 +28,18 @@ pub struct ResizerColumn {
     /// Horizontal padding requirement (margins + padding from styles).
     pub x_padding: usize,
 
-    /// Fixed width constraint from style specifications, if any.
+    /// Fixed width constraint from style specifications or
+    /// `Table::column_width`, if any (0 means none).
     pub fixed_width: usize,
+
+    /// Upper bound on the column's width from `Table::column_max_width`, if
+    /// any (0 means none). Ignored when `fixed_width` is set.
+    pub max_width: usize,
+
+    /// Content width the column is laid out at from
+    /// `Table::wrap_threshold`, if any (0 means none). Unlike `max_width`,
+    /// the column grows back past it when the table has room to spare.
+    pub wrap_threshold: usize,
 }
 
 impl ResizerColumn {
@@ -43,7 +57,8 @@ impl ResizerColumn {
     ///
     /// Returns a new `ResizerColumn` instance with:
     /// - `index` set to the provided value
-    /// - `min`, `max`, `median`, `x_padding`, `fixed_width` set to 0
+    /// - `min`, `max`, `median`, `x_padding`, `fixed_width`, `max_width`,
+    ///   `wrap_threshold` set to 0
     /// - `rows` initialized as an empty vector
     ///
     /// # Examples
@@ -66,10 +81,55 @@ impl ResizerColumn {
             rows: Vec::new(),
             x_padding: 0,
             fixed_width: 0,
+            max_width: 0,
+            wrap_threshold: 0,
         }
     }
 }
 
+/// Widths of the vertical border seams in a table row.
+///
+/// A row is laid out as `left`, then each column separated by `column`, then
+/// `right`. A seam whose border is disabled, or whose glyph is empty, has
+/// width 0 and takes no space. The table measures its columns and draws its
+/// borders from the same `Seams`, so every line has the same width.
+///
+/// # Examples
+///
+/// ```
+/// use lipgloss_table::resizing::Seams;
+///
+/// let seams = Seams { left: 1, column: 1, right: 1 };
+/// assert_eq!(seams.total(3), 4);
+/// assert_eq!(Seams::NONE.total(3), 0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Seams {
+    /// Width of the left edge.
+    pub left: usize,
+    /// Width of each separator between two columns.
+    pub column: usize,
+    /// Width of the right edge.
+    pub right: usize,
+}
+
+impl Seams {
+    /// Seams that take no width at all.
+    pub const NONE: Seams = Seams {
+        left: 0,
+        column: 0,
+        right: 0,
+    };
+
+    /// Total seam width in a row of `columns` columns.
+    pub fn total(&self, columns: usize) -> usize {
+        if columns == 0 {
+            return 0;
+        }
+        self.left + self.right + self.column * (columns - 1)
+    }
+}
+
 /// A comprehensive table resizing engine that calculates optimal layouts.
 ///
 /// The `Resizer` analyzes table content, applies constraints, and computes
@@ -99,8 +159,8 @@ pub struct Resizer {
     /// Whether text wrapping is enabled for content that exceeds column width.
     pub wrap: bool,
 
-    /// Whether column separators/borders are enabled between columns.
-    pub border_column: bool,
+    /// Widths of the vertical borders, counted in the total table width.
+    pub seams: Seams,
 
     /// Vertical padding requirements for each cell, organized by [row][column].
     pub y_paddings: Vec<Vec<usize>>,
@@ -156,7 +216,7 @@ impl Resizer {
     /// - If `headers` is empty, only data rows will be processed
     /// - The number of columns is determined by the row with the most cells
     /// - Column width calculations use Unicode-aware width detection
-    /// - The resizer defaults to enabling text wrapping and column borders
+    /// - The resizer defaults to enabling text wrapping and one-column seams
     pub fn new(
         table_width: i32,
         table_height: i32,
@@ -171,7 +231,11 @@ impl Resizer {
             row_heights: Vec::new(),
             columns: Vec::new(),
             wrap: true,
-            border_column: true,
+            seams: Seams {
+                left: 1,
+                column: 1,
+                right: 1,
+            },
             y_paddings: Vec::new(),
         };
 
@@ -216,28 +280,48 @@ impl Resizer {
         vec![1; self.all_rows.len()]
     }
 
-    /// Detects the table width automatically based on content.
+    /// Detects the table width automatically based on content and column
+    /// width constraints.
     pub fn detect_table_width(&self) -> i32 {
-        let content_width: usize = self.columns.iter().map(|col| col.max + col.x_padding).sum();
-        let border_width = self.total_horizontal_border();
-        (content_width + border_width) as i32
+        self.max_total() as i32
     }
 
     /// Returns the total width with maximum column widths.
     pub fn max_total(&self) -> usize {
-        let content_width: usize = self.columns.iter().map(|col| col.max + col.x_padding).sum();
-        content_width + self.total_horizontal_border()
+        self.max_column_widths().iter().sum::<usize>() + self.total_horizontal_border()
     }
 
-    /// Returns the maximum column widths.
+    /// Returns the maximum column widths: the fixed width where one is set,
+    /// otherwise the widest content plus padding, capped by `max_width`.
+    /// A column with no content is one column wide, so its separators do
+    /// not run together.
     pub fn max_column_widths(&self) -> Vec<usize> {
         self.columns
             .iter()
             .map(|col| {
+                let natural = (col.max + col.x_padding).max(MIN_COLUMN_WIDTH);
                 if col.fixed_width > 0 {
                     col.fixed_width
+                } else if col.max_width > 0 {
+                    natural.min(col.max_width)
+                } else {
+                    natural
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the column widths allocation starts from: the maximum column
+    /// widths, with content past a column's `wrap_threshold` left out.
+    pub fn layout_column_widths(&self) -> Vec<usize> {
+        self.max_column_widths()
+            .into_iter()
+            .zip(&self.columns)
+            .map(|(width, col)| {
+                if col.fixed_width == 0 && col.wrap_threshold > 0 {
+                    width.min((col.wrap_threshold + col.x_padding).max(1))
                 } else {
-                    col.max + col.x_padding
+                    width
                 }
             })
             .collect()
@@ -245,25 +329,42 @@ impl Resizer {
 
     /// Calculates total horizontal border width.
     pub fn total_horizontal_border(&self) -> usize {
-        if self.border_column && !self.columns.is_empty() {
-            self.columns.len() + 1 // One border between each column, plus edges
-        } else {
-            0
-        }
+        self.seams.total(self.columns.len())
     }
 
     /// Main method to get optimized column widths and row heights.
+    ///
+    /// Columns start at their [`layout_column_widths`](Self::layout_column_widths).
+    /// If those fit, any room left over first goes back to columns held
+    /// under a wrap threshold, then is spread evenly.
     pub fn optimized_widths(&mut self) -> (Vec<usize>, Vec<usize>) {
-        if self.max_total() <= self.table_width as usize {
-            self.expand_table_width()
+        let col_widths = self.layout_column_widths();
+        let total = col_widths.iter().sum::<usize>() + self.total_horizontal_border();
+        if total <= self.table_width as usize {
+            self.expand_table_width(col_widths)
         } else {
-            self.shrink_table_width()
+            self.shrink_table_width(col_widths)
         }
     }
 
-    /// Expands table width to fit the target by growing columns evenly.
-    fn expand_table_width(&mut self) -> (Vec<usize>, Vec<usize>) {
-        let mut col_widths = self.max_column_widths();
+    /// Expands table width to fit the target, first growing columns held
+    /// under a wrap threshold back toward their maximum width, then growing
+    /// columns evenly.
+    fn expand_table_width(&mut self, mut col_widths: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
+        let max_widths = self.max_column_widths();
+        loop {
+            let total_width = col_widths.iter().sum::<usize>() + self.total_horizontal_border();
+            if total_width >= self.table_width as usize {
+                break;
+            }
+            let narrowest = (0..col_widths.len())
+                .filter(|&j| col_widths[j] < max_widths[j])
+                .min_by_key(|&j| col_widths[j]);
+            match narrowest {
+                Some(idx) => col_widths[idx] += 1,
+                None => break,
+            }
+        }
 
         // Iteratively expand the shortest columns until we reach target width
         loop {
@@ -272,22 +373,29 @@ impl Resizer {
                 break;
             }
 
-            // Find the shortest non-fixed column
-            let mut shortest_idx = 0;
+            // Find the shortest column that is neither fixed nor at its cap
+            let mut shortest_idx = None;
             let mut shortest_width = usize::MAX;
 
             for (j, &width) in col_widths.iter().enumerate() {
-                if self.columns[j].fixed_width > 0 {
+                let col = &self.columns[j];
+                if col.fixed_width > 0 {
                     continue; // Skip fixed-width columns
                 }
+                if col.max_width > 0 && width >= col.max_width {
+                    continue; // Skip columns at their maximum width
+                }
                 if width < shortest_width {
                     shortest_width = width;
-                    shortest_idx = j;
+                    shortest_idx = Some(j);
                 }
             }
 
             // Expand the shortest column by 1
-            col_widths[shortest_idx] += 1;
+            match shortest_idx {
+                Some(idx) => col_widths[idx] += 1,
+                None => break, // Every column is constrained
+            }
         }
 
         let row_heights = self.expand_row_heights(&col_widths);
@@ -295,9 +403,7 @@ impl Resizer {
     }
 
     /// Shrinks table width using intelligent median-based algorithm.
-    fn shrink_table_width(&mut self) -> (Vec<usize>, Vec<usize>) {
-        let mut col_widths = self.max_column_widths();
-
+    fn shrink_table_width(&mut self, mut col_widths: Vec<usize>) -> (Vec<usize>, Vec<usize>) {
         // Phase 1: Shrink very big columns (>= tableWidth/2)
         self.shrink_biggest_columns(&mut col_widths, true);
 
@@ -307,6 +413,9 @@ impl Resizer {
         // Phase 3: Shrink any remaining big columns
         self.shrink_biggest_columns(&mut col_widths, false);
 
+        // Phase 4: Flexible columns are down to one cell; shrink fixed columns
+        self.shrink_fixed_columns(&mut col_widths);
+
         let row_heights = self.expand_row_heights(&col_widths);
         (col_widths, row_heights)
     }
@@ -331,20 +440,34 @@ impl Resizer {
                     continue; // Only consider very big columns in phase 1
                 }
 
-                if width > biggest_width {
+                if width > MIN_COLUMN_WIDTH && width > biggest_width {
                     biggest_width = width;
                     biggest_idx = Some(j);
                 }
             }
 
-            if let Some(idx) = biggest_idx {
-                if col_widths[idx] > 0 {
-                    col_widths[idx] -= 1;
-                } else {
-                    break; // Can't shrink further
-                }
-            } else {
-                break; // No suitable columns to shrink
+            match biggest_idx {
+                Some(idx) => col_widths[idx] -= 1,
+                None => break, // No suitable columns to shrink
+            }
+        }
+    }
+
+    /// Shrinks fixed-width columns, widest first, once flexible columns can
+    /// give up no more space.
+    fn shrink_fixed_columns(&mut self, col_widths: &mut [usize]) {
+        loop {
+            let total_width = col_widths.iter().sum::<usize>() + self.total_horizontal_border();
+            if total_width <= self.table_width as usize {
+                break;
+            }
+
+            let widest = (0..col_widths.len())
+                .filter(|&j| self.columns[j].fixed_width > 0 && col_widths[j] > MIN_COLUMN_WIDTH)
+                .max_by_key(|&j| (col_widths[j], std::cmp::Reverse(j)));
+            match widest {
+                Some(idx) => col_widths[idx] -= 1,
+                None => break,
             }
         }
     }
@@ -366,7 +489,7 @@ impl Resizer {
                 }
 
                 let median_width = self.columns[j].median + self.columns[j].x_padding;
-                if width > median_width {
+                if width > median_width.max(MIN_COLUMN_WIDTH) {
                     let diff = width - median_width;
                     if diff > max_diff {
                         max_diff = diff;
@@ -376,11 +499,7 @@ impl Resizer {
             }
 
             if let Some(idx) = target_idx {
-                if col_widths[idx] > 0 {
-                    col_widths[idx] -= 1;
-                } else {
-                    break;
-                }
+                col_widths[idx] -= 1;
             } else {
                 // No columns are wider than their median, fall back to shrinking biggest
                 let mut biggest_idx = None;
@@ -390,20 +509,15 @@ impl Resizer {
                     if self.columns[j].fixed_width > 0 {
                         continue;
                     }
-                    if width > biggest_width {
+                    if width > MIN_COLUMN_WIDTH && width > biggest_width {
                         biggest_width = width;
                         biggest_idx = Some(j);
                     }
                 }
 
-                if let Some(idx) = biggest_idx {
-                    if col_widths[idx] > 0 {
-                        col_widths[idx] -= 1;
-                    } else {
-                        break;
-                    }
-                } else {
-                    break;
+                match biggest_idx {
+                    Some(idx) => col_widths[idx] -= 1,
+                    None => break,
                 }
             }
         }
@@ -437,71 +551,18 @@ impl Resizer {
         row_heights
     }
 
-    /// Detects the height of content considering text wrapping.
+    /// Detects the height of content considering text wrapping. Without
+    /// wrapping, lines are truncated and each takes one row.
     fn detect_content_height(&self, content: &str, width: usize) -> usize {
         if width == 0 {
             return 1;
         }
 
         let content = content.replace("\r\n", "\n");
-        let mut height = 0;
-
-        for line in content.lines() {
-            if line.is_empty() {
-                height += 1;
-            } else {
-                height += self.calculate_wrapped_line_height(line, width);
-            }
-        }
-
-        height.max(1)
-    }
-
-    /// Calculates how many lines a single line of text will take when wrapped.
-    fn calculate_wrapped_line_height(&self, line: &str, width: usize) -> usize {
-        let line_width = display_width(line);
-        if line_width <= width {
-            return 1;
-        }
-
-        // Word-based wrapping calculation
-        let words: Vec<&str> = line.split_whitespace().collect();
-        if words.is_empty() {
-            return 1;
+        if !self.wrap {
+            return content.split('\n').count();
         }
-
-        let mut lines = 1;
-        let mut current_width = 0;
-
-        for word in words {
-            let word_width = display_width(word);
-
-            // If this word alone exceeds width, it will need its own line(s)
-            if word_width > width {
-                if current_width > 0 {
-                    lines += 1; // Wrap before this word
-                }
-                // Add lines for this long word (character wrapping)
-                lines += word_width.div_ceil(width);
-                current_width = word_width % width;
-            } else {
-                // Check if adding this word would exceed width
-                let needed_width = if current_width > 0 {
-                    current_width + 1 + word_width // +1 for space
-                } else {
-                    word_width
-                };
-
-                if needed_width > width {
-                    lines += 1; // Start new line
-                    current_width = word_width;
-                } else {
-                    current_width = needed_width;
-                }
-            }
-        }
-
-        lines
+        crate::wrap::wrap(&content, width).len().max(1)
     }
 
     /// Returns horizontal padding for a column.
@@ -610,4 +671,35 @@ mod tests {
         assert_eq!(widths[0], 5); // "Alice" is 5 chars
         assert_eq!(widths[1], 3); // "Age" is 3 chars (header is longest)
     }
+
+    #[test]
+    fn test_total_horizontal_border_follows_seams() {
+        let rows = vec![vec!["a".to_string(), "b".to_string(), "c".to_string()]];
+        let mut resizer = Resizer::new(80, 0, vec![], rows);
+        assert_eq!(resizer.total_horizontal_border(), 4);
+
+        resizer.seams = Seams {
+            left: 0,
+            column: 1,
+            right: 1,
+        };
+        assert_eq!(resizer.total_horizontal_border(), 3);
+
+        resizer.seams = Seams::NONE;
+        assert_eq!(resizer.total_horizontal_border(), 0);
+    }
+
+    #[test]
+    fn test_wrap_threshold_only_affects_allocation() {
+        let rows = vec![vec!["a".repeat(40), "b".repeat(10)]];
+        let mut resizer = Resizer::new(30, 0, vec![], rows);
+        resizer.columns[0].wrap_threshold = 12;
+        assert_eq!(resizer.max_column_widths(), vec![40, 10]);
+        assert_eq!(resizer.layout_column_widths(), vec![12, 10]);
+
+        // Room left over goes back to the thresholded column first.
+        assert_eq!(resizer.optimized_widths().0, vec![17, 10]);
+        resizer.table_width = 60;
+        assert_eq!(resizer.optimized_widths().0, vec![40, 17]);
+    }
 }
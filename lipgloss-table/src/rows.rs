@@ -1,3 +1,6 @@
+use std::collections::HashMap;
+use std::fmt;
+
 /// Data is the interface that wraps the basic methods of a table model.
 pub trait Data {
     /// At returns the contents of the cell at the given index.
@@ -8,8 +11,78 @@ pub trait Data {
 
     /// Columns returns the number of columns in the table.
     fn columns(&self) -> usize;
+
+    /// Returns the underlying StringData when the data is stored as strings.
+    ///
+    /// Used for borrowing cell contents without cloning. Other data sources
+    /// return `None`, which is the default.
+    fn as_string_data(&self) -> Option<&StringData> {
+        None
+    }
+
+    /// Mutable counterpart of [`Data::as_string_data`], used for in-place updates.
+    fn as_string_data_mut(&mut self) -> Option<&mut StringData> {
+        None
+    }
+
+    /// Returns how many leading rows to measure column widths from, for data
+    /// sources that are expensive to read.
+    ///
+    /// When this is `Some`, rendering reads only those rows and the rows that
+    /// are shown, instead of every row. The default, `None`, measures every
+    /// row.
+    fn sample_rows(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// OutOfBounds is returned when a cell position lies outside the table data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// The requested row index.
+    pub row: usize,
+    /// The requested column index.
+    pub col: usize,
+    /// The number of rows in the data.
+    pub rows: usize,
+    /// The number of columns in the data.
+    pub columns: usize,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cell ({}, {}) is out of bounds for {}x{} table data",
+            self.row, self.col, self.rows, self.columns
+        )
+    }
 }
 
+impl std::error::Error for OutOfBounds {}
+
+/// SpanOverlap is returned when a spanning cell would cover a cell that
+/// another span already covers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpanOverlap {
+    /// The data row of the first cell covered twice.
+    pub row: usize,
+    /// The data column of the first cell covered twice.
+    pub col: usize,
+}
+
+impl fmt::Display for SpanOverlap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cell ({}, {}) is already covered by another span",
+            self.row, self.col
+        )
+    }
+}
+
+impl std::error::Error for SpanOverlap {}
+
 /// StringData is a string-based implementation of the Data interface.
 #[derive(Debug, Clone)]
 pub struct StringData {
@@ -43,6 +116,36 @@ impl StringData {
         self.append(row);
         self
     }
+
+    /// Returns the contents of the cell at the given index without cloning.
+    ///
+    /// Cells missing from a short row inside the column count read as empty.
+    pub fn cell(&self, row: usize, col: usize) -> Option<&str> {
+        if row >= self.rows.len() || col >= self.columns {
+            return None;
+        }
+        Some(self.rows[row].get(col).map(String::as_str).unwrap_or(""))
+    }
+
+    /// Replaces the contents of the cell at the given index in place.
+    ///
+    /// Short rows are padded with empty cells up to `col`.
+    pub fn set_cell(&mut self, row: usize, col: usize, value: String) -> Result<(), OutOfBounds> {
+        if row >= self.rows.len() || col >= self.columns {
+            return Err(OutOfBounds {
+                row,
+                col,
+                rows: self.rows.len(),
+                columns: self.columns,
+            });
+        }
+        let cells = &mut self.rows[row];
+        if cells.len() <= col {
+            cells.resize(col + 1, String::new());
+        }
+        cells[col] = value;
+        Ok(())
+    }
 }
 
 impl Data for StringData {
@@ -60,6 +163,14 @@ impl Data for StringData {
     fn columns(&self) -> usize {
         self.columns
     }
+
+    fn as_string_data(&self) -> Option<&StringData> {
+        Some(self)
+    }
+
+    fn as_string_data_mut(&mut self) -> Option<&mut StringData> {
+        Some(self)
+    }
 }
 
 /// Filter applies a filter on some data.
@@ -121,6 +232,124 @@ impl<D: Data> Data for Filter<D> {
     }
 }
 
+/// LazyData reads cells on demand, for data sources too large to copy such
+/// as a database cursor.
+///
+/// The row and column counts are given up front and cells are fetched with
+/// `fetch(row, column)`. With a height limit set on the table, rendering only
+/// fetches the visible rows and the first rows used to measure column widths
+/// (100 by default, see [`LazyData::sample`]). Longer content further down is
+/// wrapped or truncated to those widths; set widths with `column_width` to
+/// avoid depending on the sample.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_table::{LazyData, Table};
+///
+/// let data = LazyData::new(1_000_000, 2, |row, col| match col {
+///     0 => row.to_string(),
+///     _ => format!("item {}", row),
+/// });
+///
+/// let mut table = Table::new().data(data).height(5).offset(500_000);
+/// let out = table.render();
+/// assert!(out.contains("item 500000"));
+/// ```
+pub struct LazyData<F> {
+    rows: usize,
+    columns: usize,
+    sample: usize,
+    fetch: F,
+}
+
+impl<F: Fn(usize, usize) -> String> LazyData<F> {
+    /// Creates a LazyData with the given size, reading cells with `fetch`.
+    pub fn new(rows: usize, columns: usize, fetch: F) -> Self {
+        Self {
+            rows,
+            columns,
+            sample: 100,
+            fetch,
+        }
+    }
+
+    /// Sets how many leading rows are measured for column widths.
+    pub fn sample(mut self, rows: usize) -> Self {
+        self.sample = rows;
+        self
+    }
+}
+
+impl<F: Fn(usize, usize) -> String> Data for LazyData<F> {
+    fn at(&self, row: usize, cell: usize) -> String {
+        if row >= self.rows || cell >= self.columns {
+            return String::new();
+        }
+        (self.fetch)(row, cell)
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn columns(&self) -> usize {
+        self.columns
+    }
+
+    fn sample_rows(&self) -> Option<usize> {
+        Some(self.sample)
+    }
+}
+
+/// A copy of selected rows of another data source, used to render a lazy
+/// source without reading the rows that are not shown.
+pub(crate) struct SparseRows {
+    rows: usize,
+    columns: usize,
+    sample: Option<usize>,
+    cells: HashMap<usize, Vec<String>>,
+}
+
+impl SparseRows {
+    /// Copies the given rows of `data`, keeping its size and sample.
+    pub(crate) fn copy<D: Data + ?Sized>(data: &D, rows: &[usize]) -> Self {
+        let columns = data.columns();
+        let cells = rows
+            .iter()
+            .map(|&r| (r, (0..columns).map(|c| data.at(r, c)).collect()))
+            .collect();
+        Self {
+            rows: data.rows(),
+            columns,
+            sample: data.sample_rows(),
+            cells,
+        }
+    }
+}
+
+impl Data for SparseRows {
+    fn at(&self, row: usize, cell: usize) -> String {
+        self.cells
+            .get(&row)
+            .and_then(|cells| cells.get(cell))
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn rows(&self) -> usize {
+        self.rows
+    }
+
+    fn columns(&self) -> usize {
+        self.columns
+    }
+
+    fn sample_rows(&self) -> Option<usize> {
+        self.sample
+    }
+}
+
 /// Converts an object that implements the Data interface to a matrix.
 pub fn data_to_matrix<D: Data + ?Sized>(data: &D) -> Vec<Vec<String>> {
     let num_rows = data.rows();
@@ -221,4 +450,43 @@ mod tests {
         assert_eq!(matrix[0], vec!["A", "B"]);
         assert_eq!(matrix[1], vec!["C", ""]); // Padded with empty string
     }
+
+    #[test]
+    fn test_string_data_set_cell() {
+        let mut data = StringData::new(vec![
+            vec!["A".to_string(), "B".to_string()],
+            vec!["C".to_string()],
+        ]);
+
+        assert!(data.set_cell(0, 1, "X".to_string()).is_ok());
+        assert_eq!(data.cell(0, 1), Some("X"));
+
+        // Short rows grow up to the requested column
+        assert!(data.set_cell(1, 1, "Y".to_string()).is_ok());
+        assert_eq!(data.at(1, 1), "Y");
+
+        assert_eq!(
+            data.set_cell(2, 0, "Z".to_string()),
+            Err(OutOfBounds {
+                row: 2,
+                col: 0,
+                rows: 2,
+                columns: 2
+            })
+        );
+        assert!(data.set_cell(0, 2, "Z".to_string()).is_err());
+        assert_eq!(data.cell(0, 2), None);
+    }
+
+    #[test]
+    fn test_lazy_data_bounds() {
+        let data = LazyData::new(3, 2, |row, col| format!("{}:{}", row, col)).sample(1);
+        assert_eq!(data.rows(), 3);
+        assert_eq!(data.columns(), 2);
+        assert_eq!(data.at(2, 1), "2:1");
+        assert_eq!(data.at(3, 0), "");
+        assert_eq!(data.at(0, 2), "");
+        assert_eq!(data.sample_rows(), Some(1));
+        assert_eq!(StringData::empty().sample_rows(), None);
+    }
 }
@@ -0,0 +1,141 @@
+//! The drawing of a table before it becomes a string: lines of segments,
+//! each a piece of text with its display width and what part of the table
+//! it is.
+
+use std::fmt;
+
+/// What part of the table a [`Segment`] draws.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SegmentKind {
+    /// The outer frame and the horizontal rules between rows.
+    Border,
+    /// Cell content, including the lines of row-spanning cells that cross a
+    /// row separator.
+    Cell,
+    /// A vertical border between two cells.
+    Separator,
+}
+
+/// A piece of a table line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Segment {
+    pub(crate) text: String,
+    /// Display width of `text`; escape sequences take no columns.
+    pub(crate) width: usize,
+    pub(crate) kind: SegmentKind,
+}
+
+impl Segment {
+    pub(crate) fn new(text: impl Into<String>, kind: SegmentKind) -> Self {
+        let text = text.into();
+        let width = lipgloss::width(&text);
+        Self { text, width, kind }
+    }
+}
+
+/// One terminal line of a table.
+pub(crate) type Line = Vec<Segment>;
+
+/// The display width of a line.
+pub(crate) fn line_width(line: &[Segment]) -> usize {
+    line.iter().map(|s| s.width).sum()
+}
+
+/// Joins the lines into the table's output.
+pub(crate) fn assemble(lines: &[Line]) -> String {
+    let mut out = String::new();
+    for (i, line) in lines.iter().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        for segment in line {
+            out.push_str(&segment.text);
+        }
+    }
+    out
+}
+
+/// Lays out blocks of text side by side, one segment per block on every
+/// line, the way `join_horizontal` aligned to the top does.
+///
+/// A block of a single line stays as it is. When any block spans several
+/// lines, every block is padded to its widest line and to the height of the
+/// tallest, and border blocks are repeated down the full height.
+pub(crate) fn side_by_side(blocks: Vec<(String, SegmentKind)>) -> Vec<Line> {
+    let height = blocks
+        .iter()
+        .map(|(text, _)| lipgloss::height(text))
+        .max()
+        .unwrap_or(1);
+    if height <= 1 {
+        return vec![blocks
+            .into_iter()
+            .map(|(text, kind)| Segment::new(text, kind))
+            .collect()];
+    }
+
+    let mut lines: Vec<Line> = vec![Vec::with_capacity(blocks.len()); height];
+    for (text, kind) in blocks {
+        let mut block: Vec<String> = text
+            .split('\n')
+            .map(|l| lipgloss::utils::expand_tabs(l, lipgloss::DEFAULT_TAB_WIDTH))
+            .collect();
+        if kind != SegmentKind::Cell && block.len() == 1 {
+            block = vec![block[0].clone(); height];
+        }
+        let width = block.iter().map(|l| lipgloss::width(l)).max().unwrap_or(0);
+        for (i, line) in lines.iter_mut().enumerate() {
+            let text = block.get(i).map(String::as_str).unwrap_or_default();
+            let pad = width.saturating_sub(lipgloss::width(text));
+            let text = format!("{}{}", text, " ".repeat(pad));
+            line.push(Segment { text, width, kind });
+        }
+    }
+    lines
+}
+
+/// A table line whose width differs from the table's.
+///
+/// Returned by [`Table::try_render`](crate::Table::try_render) for a strict
+/// table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidthMismatch {
+    /// The line of the output, counting from 0.
+    pub line: usize,
+    /// The display width of the line.
+    pub width: usize,
+    /// The display width of the table.
+    pub expected: usize,
+}
+
+impl fmt::Display for WidthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "table line {} is {} columns wide, expected {}",
+            self.line, self.width, self.expected
+        )
+    }
+}
+
+impl std::error::Error for WidthMismatch {}
+
+/// Checks that every line is `expected` columns wide, offsetting the
+/// reported line numbers by `first_line`.
+pub(crate) fn check_widths(
+    lines: &[Line],
+    expected: usize,
+    first_line: usize,
+) -> Result<(), WidthMismatch> {
+    for (i, line) in lines.iter().enumerate() {
+        let width = line_width(line);
+        if width != expected {
+            return Err(WidthMismatch {
+                line: first_line + i,
+                width,
+                expected,
+            });
+        }
+    }
+    Ok(())
+}
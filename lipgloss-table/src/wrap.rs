@@ -0,0 +1,165 @@
+//! ANSI-aware word wrapping for cell content.
+//!
+//! Both the resizer, which measures row heights, and the table, which draws
+//! the cells, wrap through [`wrap`], so a row is always exactly as tall as
+//! its tallest wrapped cell.
+
+use lipgloss::utils::{escape_len, split_breakable, SgrState};
+use unicode_width::UnicodeWidthChar;
+
+/// Wraps `content` to lines of at most `width` columns.
+///
+/// Lines break at whitespace; runs of ideographs may also break between
+/// characters, and words wider than `width` are hard-broken. Lines that
+/// already fit are kept as they are. Escape sequences take no width and stay
+/// with the text they precede. Styling still active at a line break is
+/// closed at the end of the line and re-opened at the start of the next, so
+/// every line can be drawn on its own without losing or leaking styles.
+pub(crate) fn wrap(content: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut lines = Vec::new();
+    for line in content.split('\n') {
+        if lipgloss::width(line) <= width {
+            lines.push(line.to_string());
+        } else {
+            lines.extend(wrap_line(line, width));
+        }
+    }
+    carry_sgr(lines)
+}
+
+/// Greedily packs the words of a single line into lines of `width` columns.
+fn wrap_line(line: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_width = 0;
+
+    for word in words(line) {
+        for (k, piece) in split_breakable(&word)
+            .into_iter()
+            .flat_map(|piece| hard_break(piece, width))
+            .enumerate()
+        {
+            let piece_width = lipgloss::width(piece);
+            // Pieces after the first continue the same word, without a space.
+            let gap = usize::from(k == 0 && current_width > 0);
+
+            if current_width > 0 && current_width + gap + piece_width > width {
+                lines.push(std::mem::take(&mut current));
+                current_width = 0;
+            } else if gap == 1 {
+                current.push(' ');
+                current_width += 1;
+            }
+            current.push_str(piece);
+            current_width += piece_width;
+        }
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+/// Splits a line into whitespace-separated words.
+///
+/// Escape sequences between words are attached to the word that follows, or
+/// to the last word when nothing follows, so no styling is dropped.
+fn words(line: &str) -> Vec<String> {
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+    // Escapes seen since the last visible character
+    let mut pending = String::new();
+    let mut in_word = false;
+
+    let mut i = 0;
+    while i < line.len() {
+        let rest = &line[i..];
+        if rest.starts_with('\x1b') {
+            let len = escape_len(rest);
+            if in_word {
+                word.push_str(&rest[..len]);
+            } else {
+                pending.push_str(&rest[..len]);
+            }
+            i += len;
+            continue;
+        }
+
+        let ch = rest.chars().next().unwrap_or(' ');
+        i += ch.len_utf8();
+        if ch.is_whitespace() {
+            if in_word {
+                words.push(std::mem::take(&mut word));
+                in_word = false;
+            }
+        } else {
+            if !in_word {
+                word.push_str(&std::mem::take(&mut pending));
+                in_word = true;
+            }
+            word.push(ch);
+        }
+    }
+
+    if in_word {
+        words.push(word);
+    }
+    if !pending.is_empty() {
+        match words.last_mut() {
+            Some(last) => last.push_str(&pending),
+            None => words.push(pending),
+        }
+    }
+    words
+}
+
+/// Cuts `piece` into chunks of at most `width` columns.
+///
+/// A character wider than `width` gets a chunk of its own.
+fn hard_break(piece: &str, width: usize) -> Vec<&str> {
+    if lipgloss::width(piece) <= width {
+        return vec![piece];
+    }
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+    let mut used = 0;
+    let mut i = 0;
+    while i < piece.len() {
+        let rest = &piece[i..];
+        if rest.starts_with('\x1b') {
+            i += escape_len(rest);
+            continue;
+        }
+        let ch = rest.chars().next().unwrap_or(' ');
+        let w = ch.width().unwrap_or(0);
+        if used > 0 && used + w > width {
+            chunks.push(&piece[start..i]);
+            start = i;
+            used = 0;
+        }
+        used += w;
+        i += ch.len_utf8();
+    }
+    chunks.push(&piece[start..]);
+    chunks
+}
+
+/// Closes styling still open at the end of each line and re-opens it at the
+/// start of the next.
+fn carry_sgr(lines: Vec<String>) -> Vec<String> {
+    let mut state = SgrState::new();
+    let mut out = Vec::with_capacity(lines.len());
+    for line in &lines {
+        let mut text = state.reopen();
+        text.push_str(line);
+        state.track(line);
+        if state.is_open() {
+            text.push_str("\x1b[0m");
+        }
+        out.push(text);
+    }
+    out
+}
@@ -4,8 +4,16 @@
 //! and collections of children. It supports styling, filtering, and complex
 //! tree operations with Go lipgloss compatibility.
 
-use lipgloss::Style;
+use crate::rules::{Rule, StyleRules};
+use lipgloss::{Position, Style};
 use std::fmt;
+use std::sync::Arc;
+
+/// Returns `f` when it is a function pointer of type `P`, for the deprecated
+/// getters that hand out `&fn` rather than a trait object.
+fn fn_pointer<F: 'static, P: Copy + 'static>(f: &F) -> Option<P> {
+    (f as &dyn std::any::Any).downcast_ref::<P>().copied()
+}
 
 /// Helper trait to enable cloning boxed trait objects for Node.
 ///
@@ -120,6 +128,17 @@ pub trait Node: fmt::Display + CloneNode {
     /// # Returns
     ///
     /// An optional reference to the node's custom enumerator function
+    fn get_enumerator_impl(&self) -> Option<&Arc<dyn crate::Enumerate>> {
+        None
+    }
+
+    /// Returns the custom enumerator function for this node as a function pointer, if any.
+    ///
+    /// The renderer still reads this for nodes that do not implement
+    /// [`Node::get_enumerator_impl`](crate::Node::get_enumerator_impl).
+    #[deprecated(
+        note = "Use get_enumerator_impl, which also covers closures and trait implementations"
+    )]
     fn get_enumerator(&self) -> Option<&crate::Enumerator> {
         None
     }
@@ -132,6 +151,17 @@ pub trait Node: fmt::Display + CloneNode {
     /// # Returns
     ///
     /// An optional reference to the node's custom indenter function
+    fn get_indenter_impl(&self) -> Option<&Arc<dyn crate::Indent>> {
+        None
+    }
+
+    /// Returns the custom indenter function for this node as a function pointer, if any.
+    ///
+    /// The renderer still reads this for nodes that do not implement
+    /// [`Node::get_indenter_impl`](crate::Node::get_indenter_impl).
+    #[deprecated(
+        note = "Use get_indenter_impl, which also covers closures and trait implementations"
+    )]
     fn get_indenter(&self) -> Option<&crate::Indenter> {
         None
     }
@@ -168,6 +198,17 @@ pub trait Node: fmt::Display + CloneNode {
     /// # Returns
     ///
     /// An optional reference to the node's item style function
+    fn get_item_style_func_impl(&self) -> Option<&Arc<dyn crate::Stylize>> {
+        None
+    }
+
+    /// Returns the item style function for this node as a function pointer, if any.
+    ///
+    /// The renderer still reads this for nodes that do not implement
+    /// [`Node::get_item_style_func_impl`](crate::Node::get_item_style_func_impl).
+    #[deprecated(
+        note = "Use get_item_style_func_impl, which also covers closures and trait implementations"
+    )]
     fn get_item_style_func(&self) -> Option<&crate::StyleFunc> {
         None
     }
@@ -180,9 +221,285 @@ pub trait Node: fmt::Display + CloneNode {
     /// # Returns
     ///
     /// An optional reference to the node's enumerator style function
+    fn get_enumerator_style_func_impl(&self) -> Option<&Arc<dyn crate::Stylize>> {
+        None
+    }
+
+    /// Returns the enumerator style function for this node as a function pointer, if any.
+    ///
+    /// The renderer still reads this for nodes that do not implement
+    /// [`Node::get_enumerator_style_func_impl`](crate::Node::get_enumerator_style_func_impl).
+    #[deprecated(
+        note = "Use get_enumerator_style_func_impl, which also covers closures and trait implementations"
+    )]
     fn get_enumerator_style_func(&self) -> Option<&crate::StyleFunc> {
         None
     }
+
+    /// Returns the item style function of the node and its context, if any.
+    ///
+    /// When set it is used instead of the
+    /// [item style function](Node::get_item_style_func).
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's item style function
+    fn get_item_style_for(&self) -> Option<&crate::NodeStyleFunc> {
+        None
+    }
+
+    /// Returns the enumerator style function of the node and its context,
+    /// if any.
+    ///
+    /// When set it is used instead of the
+    /// [enumerator style function](Node::get_enumerator_style_func).
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's enumerator style function
+    fn get_enumerator_style_for(&self) -> Option<&crate::NodeStyleFunc> {
+        None
+    }
+
+    /// Returns whether this node is marked done, for checklists.
+    ///
+    /// Enumerators and style functions can read it through
+    /// [`Children::is_done`]. Nodes without completion state are never done.
+    fn is_done(&self) -> bool {
+        false
+    }
+
+    /// Marks this node done or not done. Nodes without completion state
+    /// ignore it.
+    ///
+    /// # Arguments
+    ///
+    /// * `done` - `true` to mark the node done
+    fn set_done(&mut self, _done: bool) {}
+
+    /// Returns the style applied to this node's done children, if any.
+    ///
+    /// It is applied to the child's value before the item style.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's done style
+    fn get_done_style(&self) -> Option<&Style> {
+        None
+    }
+
+    /// Returns the secondary line drawn below this node's value, if any.
+    ///
+    /// The description is styled on its own, with the parent's
+    /// [description style](Node::get_description_style) rather than the
+    /// item style, and lines up with the value past the enumerator.
+    ///
+    /// # Returns
+    ///
+    /// The node's description, if set
+    fn description(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns the style attached to this node's own value, if any.
+    ///
+    /// It takes precedence over the parent's item style and item style
+    /// functions when the node is drawn as a child.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's own style
+    fn get_node_style(&self) -> Option<&Style> {
+        None
+    }
+
+    /// Returns the style attached to this node's own enumerator, if any.
+    ///
+    /// It takes precedence over the parent's enumerator style and
+    /// enumerator style functions when the node is drawn as a child.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's own enumerator style
+    fn get_node_enumerator_style(&self) -> Option<&Style> {
+        None
+    }
+
+    /// Returns the style rules of this node, if any.
+    ///
+    /// They style the values of this node's descendants, and replace the
+    /// rules of the trees above it.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's style rules
+    fn get_style_rules(&self) -> Option<&Arc<StyleRules>> {
+        None
+    }
+
+    /// Returns the style applied to the descriptions of this node's
+    /// children, if any.
+    ///
+    /// # Returns
+    ///
+    /// An optional reference to the node's description style
+    fn get_description_style(&self) -> Option<&Style> {
+        None
+    }
+
+    /// Returns how this node aligns enumerators of different widths.
+    ///
+    /// Custom enumerators narrower than the widest one at the same level
+    /// are padded to its width; the position decides where the padding
+    /// goes. `None` keeps the default, right alignment.
+    ///
+    /// # Returns
+    ///
+    /// The node's enumerator alignment, if set
+    fn get_enumerator_alignment(&self) -> Option<Position> {
+        None
+    }
+
+    /// Returns the marker drawn where the offset hides children, if any.
+    ///
+    /// # Returns
+    ///
+    /// The node's offset marker, see [`Tree::offset_marker`]
+    fn get_offset_marker(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns how many visible children the offset hides before and after
+    /// the ones shown.
+    ///
+    /// # Returns
+    ///
+    /// The hidden counts as `(above, below)`
+    fn offset_hidden(&self) -> (usize, usize) {
+        (0, 0)
+    }
+
+    /// Returns the raw text of a block node, if this node is one.
+    ///
+    /// Block nodes are rendered line by line in a single pass, with the
+    /// continuation indent on every line after the first, instead of going
+    /// through the item style and block joining used for other nodes.
+    ///
+    /// # Returns
+    ///
+    /// The block's text, or `None` for regular nodes
+    fn as_block(&self) -> Option<&str> {
+        None
+    }
+
+    /// Returns this node's children with hidden nodes kept in place.
+    ///
+    /// Unlike [`Node::children`], hidden children are not dropped. Any
+    /// offset window still applies to the visible ones. Used when
+    /// serializing a tree with its hidden nodes, see
+    /// [`OutlineOptions`](crate::OutlineOptions).
+    ///
+    /// # Returns
+    ///
+    /// A `Box<dyn Children>` with visible and hidden children in order
+    fn all_children(&self) -> Box<dyn Children> {
+        self.children()
+    }
+
+    /// Returns a copy of this node cut down to what `keep` accepts, or
+    /// `None` if nothing is left. See [`Tree::filter`].
+    ///
+    /// A node without children is kept when `keep` accepts it. A node with
+    /// children is kept when any of its descendants is. Nodes that can hold
+    /// children override this to drop the rejected ones from the copy.
+    ///
+    /// # Returns
+    ///
+    /// The pruned copy, or `None` if the whole node is filtered out
+    fn prune(&self, keep: &dyn Fn(&dyn Node) -> bool) -> Option<Box<dyn Node>> {
+        let node = self.clone_node();
+        let children = node.children();
+        let kept = if children.length() == 0 {
+            keep(node.as_ref())
+        } else {
+            (0..children.length())
+                .filter_map(|i| children.at(i))
+                .any(|child| child.prune(keep).is_some())
+        };
+        kept.then_some(node)
+    }
+
+    /// Returns how many descendants this node has that are not hidden,
+    /// however deep.
+    ///
+    /// A collapsed tree counts the descendants it hides, see
+    /// [`Tree::collapse`].
+    ///
+    /// # Returns
+    ///
+    /// The number of visible descendants
+    fn descendant_count(&self) -> usize {
+        let children = self.children();
+        (0..children.length())
+            .filter_map(|i| children.at(i))
+            .map(|child| 1 + child.descendant_count())
+            .sum()
+    }
+
+    /// Returns a copy of this node that shows `levels` more levels of
+    /// descendants and collapses the nodes below them. See
+    /// [`Tree::max_depth`].
+    ///
+    /// `label` is the label of the tree that limits the depth, used by the
+    /// collapsed nodes that have none of their own. Nodes that can hold
+    /// children override this; others are copied as they are.
+    ///
+    /// # Returns
+    ///
+    /// The limited copy
+    fn limit_depth(&self, levels: usize, label: Option<&CollapsedLabelFn>) -> Box<dyn Node> {
+        let _ = (levels, label);
+        self.clone_node()
+    }
+}
+
+// The renderer reads a node's functions through these, so a node that only
+// implements the deprecated `&fn` getters keeps its overrides.
+
+/// Returns the node's enumerator, if any.
+#[allow(deprecated)]
+pub(crate) fn node_enumerator(node: &dyn Node) -> Option<Arc<dyn crate::Enumerate>> {
+    node.get_enumerator_impl().cloned().or_else(|| {
+        node.get_enumerator()
+            .map(|&f| Arc::new(f) as Arc<dyn crate::Enumerate>)
+    })
+}
+
+/// Returns the node's indenter, if any.
+#[allow(deprecated)]
+pub(crate) fn node_indenter(node: &dyn Node) -> Option<Arc<dyn crate::Indent>> {
+    node.get_indenter_impl().cloned().or_else(|| {
+        node.get_indenter()
+            .map(|&f| Arc::new(f) as Arc<dyn crate::Indent>)
+    })
+}
+
+/// Returns the node's item style function, if any.
+#[allow(deprecated)]
+pub(crate) fn node_item_style_func(node: &dyn Node) -> Option<Arc<dyn crate::Stylize>> {
+    node.get_item_style_func_impl().cloned().or_else(|| {
+        node.get_item_style_func()
+            .map(|&f| Arc::new(f) as Arc<dyn crate::Stylize>)
+    })
+}
+
+/// Returns the node's enumerator style function, if any.
+#[allow(deprecated)]
+pub(crate) fn node_enumerator_style_func(node: &dyn Node) -> Option<Arc<dyn crate::Stylize>> {
+    node.get_enumerator_style_func_impl().cloned().or_else(|| {
+        node.get_enumerator_style_func()
+            .map(|&f| Arc::new(f) as Arc<dyn crate::Stylize>)
+    })
 }
 
 impl Clone for NodeChildren {
@@ -315,6 +632,58 @@ pub trait Children {
     ///
     /// The count of child nodes as a `usize`
     fn length(&self) -> usize;
+
+    /// Returns the number of children that render an item of their own:
+    /// those neither hidden nor without a value.
+    ///
+    /// The children handed to enumerators and style functions during
+    /// rendering are already these, windowed by the parent's offset, so
+    /// there this equals [`length`](Children::length). On a collection
+    /// built by hand, or one returned by [`Node::all_children`], it leaves
+    /// out what would not render.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Children, Leaf, NodeChildren};
+    ///
+    /// let mut children = NodeChildren::new();
+    /// children.append(Box::new(Leaf::new("shown", false)));
+    /// children.append(Box::new(Leaf::new("hidden", true)));
+    /// children.append(Box::new(Leaf::new("", false)));
+    ///
+    /// assert_eq!(children.length(), 3);
+    /// assert_eq!(children.visible_length(), 1);
+    /// ```
+    fn visible_length(&self) -> usize {
+        (0..self.length())
+            .filter_map(|i| self.at(i))
+            .filter(|node| !node.hidden() && !node.value().is_empty())
+            .count()
+    }
+
+    /// Returns whether the node at `index` is marked done, see
+    /// [`Node::is_done`]. Out-of-range indices are not done.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Children, Leaf, Node, NodeChildren};
+    ///
+    /// let mut bought = Leaf::new("Milk", false);
+    /// bought.set_done(true);
+    ///
+    /// let mut children = NodeChildren::new();
+    /// children.append(Box::new(bought));
+    /// children.append(Box::new(Leaf::new("Eggs", false)));
+    ///
+    /// assert!(children.is_done(0));
+    /// assert!(!children.is_done(1));
+    /// assert!(!children.is_done(2));
+    /// ```
+    fn is_done(&self, index: usize) -> bool {
+        self.at(index).is_some_and(|node| node.is_done())
+    }
 }
 
 /// A concrete implementation of the `Children` trait using a vector of boxed nodes.
@@ -452,6 +821,80 @@ impl NodeChildren {
     pub fn at_mut(&mut self, index: usize) -> Option<&mut Box<dyn Node>> {
         self.nodes.get_mut(index)
     }
+
+    /// Inserts a child node at the given index, shifting the nodes after it.
+    ///
+    /// An index equal to the length appends; an index past it does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based position the node takes
+    /// * `child` - The boxed node to insert
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{NodeChildren, Leaf, Children};
+    ///
+    /// let mut children = NodeChildren::new();
+    /// children.append(Box::new(Leaf::new("B", false)));
+    /// children.insert(0, Box::new(Leaf::new("A", false)));
+    /// children.insert(5, Box::new(Leaf::new("Ignored", false)));
+    ///
+    /// assert_eq!(children.length(), 2);
+    /// assert_eq!(children.at(0).unwrap().value(), "A");
+    /// ```
+    pub fn insert(&mut self, index: usize, child: Box<dyn Node>) {
+        if index <= self.nodes.len() {
+            self.nodes.insert(index, child);
+        }
+    }
+
+    /// Replaces the child node at the given index, returning the old one.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The zero-based index of the node to replace
+    /// * `child` - The boxed node to put in its place
+    ///
+    /// # Returns
+    ///
+    /// The replaced node if the index was valid, or `None` if out of bounds,
+    /// in which case the collection is left unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{NodeChildren, Leaf, Children};
+    ///
+    /// let mut children = NodeChildren::new();
+    /// children.append(Box::new(Leaf::new("Old", false)));
+    ///
+    /// let old = children.replace(0, Box::new(Leaf::new("New", false)));
+    /// assert_eq!(old.unwrap().value(), "Old");
+    /// assert_eq!(children.at(0).unwrap().value(), "New");
+    /// ```
+    pub fn replace(&mut self, index: usize, child: Box<dyn Node>) -> Option<Box<dyn Node>> {
+        self.nodes
+            .get_mut(index)
+            .map(|slot| std::mem::replace(slot, child))
+    }
+
+    /// Removes all child nodes from the collection.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{NodeChildren, Leaf, Children};
+    ///
+    /// let mut children = NodeChildren::new();
+    /// children.append(Box::new(Leaf::new("A", false)));
+    /// children.clear();
+    /// assert_eq!(children.length(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
 }
 
 impl Default for NodeChildren {
@@ -498,6 +941,14 @@ pub struct Leaf {
     value: String,
     /// Whether this leaf is hidden from rendering
     hidden: bool,
+    /// Whether this leaf is checked off, for checklists
+    done: bool,
+    /// Secondary line drawn below the value
+    description: Option<String>,
+    /// Style of the value, over the parent's item styles
+    style: Option<Style>,
+    /// Style of the enumerator, over the parent's enumerator styles
+    enumerator_style: Option<Style>,
 }
 
 impl Leaf {
@@ -523,8 +974,61 @@ impl Leaf {
         Self {
             value: value.into(),
             hidden,
+            done: false,
+            description: None,
+            style: None,
+            enumerator_style: None,
         }
     }
+
+    /// Sets a description drawn on the line below the value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Leaf, Node};
+    ///
+    /// let leaf = Leaf::new("Foo Document", false).with_description("1 day ago");
+    /// assert_eq!(leaf.value(), "Foo Document");
+    /// assert_eq!(leaf.description(), Some("1 day ago"));
+    /// ```
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Attaches a style to this leaf's value.
+    ///
+    /// The style takes precedence over the parent tree's
+    /// [`item_style`](Tree::item_style) and item style functions, so one
+    /// node can be highlighted without touching the tree's styling. It goes
+    /// on top of any [style rules](Tree::style_rules) that match the leaf.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_tree::{Leaf, Node, Tree};
+    ///
+    /// let selected = Style::new().transform(|s| format!("[{}]", s));
+    /// let tree = Tree::new().child(vec![
+    ///     Leaf::new("a", false).into(),
+    ///     Leaf::new("b", false).with_style(selected).into(),
+    /// ]);
+    /// assert_eq!(tree.to_string(), "├── a\n└── [b]");
+    /// ```
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Attaches a style to this leaf's enumerator, over the parent tree's
+    /// [`enumerator_style`](Tree::enumerator_style) and enumerator style
+    /// functions.
+    pub fn with_enumerator_style(mut self, style: Style) -> Self {
+        self.enumerator_style = Some(style);
+        self
+    }
 }
 
 impl Node for Leaf {
@@ -548,12 +1052,24 @@ impl Node for Leaf {
         self.value = value;
     }
 
-    fn get_enumerator(&self) -> Option<&crate::Enumerator> {
-        None
+    fn is_done(&self) -> bool {
+        self.done
     }
 
-    fn get_indenter(&self) -> Option<&crate::Indenter> {
-        None
+    fn set_done(&mut self, done: bool) {
+        self.done = done;
+    }
+
+    fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    fn get_node_style(&self) -> Option<&Style> {
+        self.style.as_ref()
+    }
+
+    fn get_node_enumerator_style(&self) -> Option<&Style> {
+        self.enumerator_style.as_ref()
     }
 }
 
@@ -563,19 +1079,100 @@ impl fmt::Display for Leaf {
     }
 }
 
-/// A tree node that can contain both a value and child nodes.
+/// A multi-line text block rendered as a single child.
 ///
-/// `Tree` is the main building block for creating hierarchical tree structures.
-/// Unlike `Leaf` nodes, trees can have children and support advanced features
-/// like custom styling, enumerators, indenters, and offset-based child filtering.
+/// `Block` is meant for large, log-style text such as a command's output. It
+/// renders like a multi-line [`Leaf`], but is split into lines lazily at render
+/// time and written out in one pass, so thousands of lines render in linear
+/// time without a node per line. The text is shared, so cloning a block (as
+/// the renderer does) does not copy it.
 ///
-/// # Key Features
+/// Unlike a `Leaf`, the item style is applied to each line on its own, so the
+/// lines are not padded to a common width.
 ///
-/// - **Hierarchical structure**: Can contain child nodes forming a tree
-/// - **Custom styling**: Support for root, item, and enumerator styling
-/// - **Custom rendering**: Override enumerators and indenters per-node
-/// - **Child filtering**: Use offsets to display only a subset of children
-/// - **Builder pattern**: Fluent API for easy tree construction
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_tree::{Block, Node, Children};
+///
+/// let block = Block::new("line 1\nline 2");
+/// assert_eq!(block.as_block(), Some("line 1\nline 2"));
+/// assert_eq!(block.children().length(), 0);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Block {
+    /// The text of the block, shared between clones
+    text: Arc<str>,
+    /// Whether this block is hidden from rendering
+    hidden: bool,
+}
+
+impl Block {
+    /// Creates a new visible block from the given text.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The multi-line text to render
+    pub fn new(text: impl AsRef<str>) -> Self {
+        Self {
+            text: Arc::from(text.as_ref()),
+            hidden: false,
+        }
+    }
+}
+
+impl Node for Block {
+    fn value(&self) -> String {
+        self.text.to_string()
+    }
+
+    fn children(&self) -> Box<dyn Children> {
+        Box::new(NodeChildren::new())
+    }
+
+    fn hidden(&self) -> bool {
+        self.hidden
+    }
+
+    fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden;
+    }
+
+    fn set_value(&mut self, value: String) {
+        self.text = Arc::from(value);
+    }
+
+    fn as_block(&self) -> Option<&str> {
+        Some(&self.text)
+    }
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+/// A predicate over the nodes of a tree, see [`Tree::filter`].
+type NodeFilter = Arc<dyn Fn(&dyn Node) -> bool + Send + Sync>;
+
+/// The marker shown in place of a collapsed tree's children, from the
+/// number of descendants it hides. See [`Tree::collapsed_label_fn`].
+pub type CollapsedLabelFn = Arc<dyn Fn(usize) -> String + Send + Sync>;
+
+/// A tree node that can contain both a value and child nodes.
+///
+/// `Tree` is the main building block for creating hierarchical tree structures.
+/// Unlike `Leaf` nodes, trees can have children and support advanced features
+/// like custom styling, enumerators, indenters, and offset-based child filtering.
+///
+/// # Key Features
+///
+/// - **Hierarchical structure**: Can contain child nodes forming a tree
+/// - **Custom styling**: Support for root, item, and enumerator styling
+/// - **Custom rendering**: Override enumerators and indenters per-node
+/// - **Child filtering**: Use offsets to display only a subset of children
+/// - **Builder pattern**: Fluent API for easy tree construction
 ///
 /// # Examples
 ///
@@ -610,16 +1207,20 @@ pub struct Tree {
     value: String,
     /// Whether this tree node is hidden from rendering
     hidden: bool,
-    /// Offset bounds [start, end] for filtering children
+    /// Visible children to skip from the front and trim from the back
     offset: [usize; 2],
     /// Collection of child nodes
     children: NodeChildren,
 
     // Style and rendering properties
     /// Custom enumerator function for this tree
-    enumerator: Option<crate::Enumerator>,
+    enumerator: Option<Arc<dyn crate::Enumerate>>,
+    /// The enumerator again, when it was given as a function pointer
+    enumerator_fn: Option<crate::Enumerator>,
     /// Custom indenter function for this tree
-    indenter: Option<crate::Indenter>,
+    indenter: Option<Arc<dyn crate::Indent>>,
+    /// The indenter again, when it was given as a function pointer
+    indenter_fn: Option<crate::Indenter>,
     /// Style applied to the root value
     root_style: Option<Style>,
     /// Base style applied to all child items
@@ -627,9 +1228,44 @@ pub struct Tree {
     /// Base style applied to all enumerators
     enumerator_style: Option<Style>,
     /// Dynamic styling function for items
-    item_style_func: Option<crate::StyleFunc>,
+    item_style_func: Option<Arc<dyn crate::Stylize>>,
+    /// The item style function again, when it was given as a function pointer
+    item_style_func_fn: Option<crate::StyleFunc>,
     /// Dynamic styling function for enumerators
-    enumerator_style_func: Option<crate::StyleFunc>,
+    enumerator_style_func: Option<Arc<dyn crate::Stylize>>,
+    /// The enumerator style function again, when it was given as a function
+    /// pointer
+    enumerator_style_func_fn: Option<crate::StyleFunc>,
+    /// Styling function for items, given the node itself
+    item_style_for: Option<crate::NodeStyleFunc>,
+    /// Styling function for enumerators, given the node itself
+    enumerator_style_for: Option<crate::NodeStyleFunc>,
+    /// Whether this tree's root is checked off, for checklists
+    done: bool,
+    /// Style applied to the values of done children
+    done_style: Option<Style>,
+    /// Style applied to the descriptions of children
+    description_style: Option<Style>,
+    /// Style of this tree's value when drawn as a child
+    node_style: Option<Style>,
+    /// Style of this tree's enumerator when drawn as a child
+    node_enumerator_style: Option<Style>,
+    /// Styles for the values its rules select, see [`Tree::style_rules`]
+    style_rules: Option<Arc<StyleRules>>,
+    /// Where narrower enumerators are placed within the widest one's width
+    enumerator_alignment: Option<Position>,
+    /// Marker drawn where the offset hides children
+    offset_marker: Option<String>,
+    /// Which descendants to show, see [`Tree::filter`]
+    filter: Option<NodeFilter>,
+    /// Levels of descendants shown before they collapse
+    max_depth: Option<usize>,
+    /// Whether the children are hidden behind the collapsed marker
+    collapsed: bool,
+    /// Marker shown in place of collapsed children
+    collapsed_label: Option<CollapsedLabelFn>,
+    /// Columns each rendered line is truncated to
+    width: Option<usize>,
 }
 
 impl Tree {
@@ -652,17 +1288,36 @@ impl Tree {
             value: String::new(),
             hidden: false,
             offset: [0, 0],
+            filter: None,
+            max_depth: None,
+            collapsed: false,
+            collapsed_label: None,
+            width: None,
             children: NodeChildren::new(),
             // CRITICAL FIX: Set default enumerator and indenter so trees don't inherit
             // from parent List's list_indenter. This prevents spacing conflicts when
             // trees are nested in lists with 2-space indentation.
-            enumerator: Some(crate::default_enumerator),
-            indenter: Some(crate::default_indenter),
+            enumerator: Some(Arc::new(crate::default_enumerator)),
+            enumerator_fn: Some(crate::default_enumerator),
+            indenter: Some(Arc::new(crate::default_indenter)),
+            indenter_fn: Some(crate::default_indenter),
             root_style: None,
             item_style: None,
             enumerator_style: None,
             item_style_func: None,
+            item_style_func_fn: None,
             enumerator_style_func: None,
+            enumerator_style_func_fn: None,
+            item_style_for: None,
+            enumerator_style_for: None,
+            done: false,
+            done_style: None,
+            description_style: None,
+            node_style: None,
+            node_enumerator_style: None,
+            style_rules: None,
+            enumerator_alignment: None,
+            offset_marker: None,
         }
     }
 
@@ -713,44 +1368,263 @@ impl Tree {
     /// Sets the offset range for displaying children.
     ///
     /// This allows you to display only a subset of the tree's children,
-    /// which is useful for pagination or filtering large trees.
+    /// which is useful for pagination or filtering large trees. Offsets are
+    /// counted over *visible* children only: hidden children are removed
+    /// first, then `start` children are skipped from the front and `end`
+    /// children are trimmed from the back. Each subtree applies its own
+    /// offset the same way, at any nesting depth.
     ///
     /// # Arguments
     ///
-    /// * `start` - Starting index (inclusive) for child display
-    /// * `end` - Ending index (exclusive) for child display
+    /// * `start` - Number of visible children to skip from the front
+    /// * `end` - Number of visible children to trim from the back
     ///
     /// # Examples
     ///
     /// ```rust
-    /// use lipgloss_tree::Tree;
+    /// use lipgloss_tree::{Children, Leaf, Node, Tree};
     ///
     /// let tree = Tree::new()
-    ///     .child(vec!["A".into(), "B".into(), "C".into(), "D".into()])
-    ///     .offset(1, 3); // Will only show children B and C
+    ///     .child(vec![
+    ///         "A".into(),
+    ///         Leaf::new("hidden", true).into(),
+    ///         "B".into(),
+    ///         "C".into(),
+    ///         "D".into(),
+    ///     ])
+    ///     .offset(1, 1); // Will only show children B and C
+    ///
+    /// let children = tree.children();
+    /// assert_eq!(children.length(), 2);
+    /// assert_eq!(children.at(0).unwrap().value(), "B");
     /// ```
     ///
     /// # Note
     ///
-    /// If `start > end`, the values will be swapped. If `end` exceeds the
-    /// number of children, it will be clamped to the children count.
+    /// Offsets are resolved at render time, so they may be set before or
+    /// after children are added. Offsets larger than the number of visible
+    /// children simply yield no children.
     pub fn offset(mut self, start: usize, end: usize) -> Self {
-        let (start, end) = if start > end {
-            (end, start)
-        } else {
-            (start, end)
-        };
+        self.offset = [start, end];
+        self
+    }
 
-        let end = if end > self.children.length() {
-            self.children.length()
-        } else {
-            end
-        };
+    /// Sets a marker drawn in place of the children the offset hides.
+    ///
+    /// When the offset hides children before the ones shown, the marker is
+    /// drawn on a line of its own above them, and likewise below. It is
+    /// styled like an enumerator and sits in the enumerator column.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec!["A".into(), "B".into(), "C".into(), "D".into()])
+    ///     .offset(1, 1)
+    ///     .offset_marker("…");
+    /// assert_eq!(tree.to_string(), "…\n├── B\n└── C\n…");
+    /// ```
+    pub fn offset_marker(mut self, marker: impl Into<String>) -> Self {
+        self.offset_marker = Some(marker.into());
+        self
+    }
 
-        self.offset = [start, end];
+    /// Shows only the descendants that `keep` accepts, and the branches
+    /// leading to them.
+    ///
+    /// The filter is applied at render time to the nodes without children.
+    /// A branch stays while any of its descendants does, and is pruned once
+    /// they are all filtered out. The last child left gets the closing
+    /// branch, and the [offset](Tree::offset) counts only the children left.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{children, Node, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .root("src")
+    ///     .child(children![
+    ///         "main.rs",
+    ///         Tree::new().root("bin").child(children!["cli.rs", "build.sh"]),
+    ///         Tree::new().root("assets").child(children!["logo.png"]),
+    ///         "notes.txt",
+    ///     ])
+    ///     .filter(|node: &dyn Node| node.value().ends_with(".rs"));
+    ///
+    /// assert_eq!(tree.to_string(), "src\n├── main.rs\n└── bin\n    └── cli.rs");
+    /// ```
+    pub fn filter<F>(mut self, keep: F) -> Self
+    where
+        F: Fn(&dyn Node) -> bool + Send + Sync + 'static,
+    {
+        self.filter = Some(Arc::new(keep));
+        self
+    }
+
+    /// Shows descendants down to `depth` levels below this tree and
+    /// collapses the nodes there that have children of their own.
+    ///
+    /// The tree itself is at depth 0, so `max_depth(1)` shows its children
+    /// and nothing below them. A collapsed node shows a single marker in
+    /// place of its children, `…` unless set with
+    /// [`Tree::collapsed_label_fn`]. Hidden nodes are neither shown nor
+    /// counted, and nothing below the limit affects the layout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{children, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .root("~")
+    ///     .child(children![
+    ///         Tree::new().root("src").child(children![
+    ///             "main.rs",
+    ///             Tree::new().root("ui").child(children!["view.rs"]),
+    ///         ]),
+    ///         "README.md",
+    ///     ])
+    ///     .max_depth(1)
+    ///     .collapsed_label_fn(|hidden| format!("(+{} more)", hidden));
+    ///
+    /// assert_eq!(
+    ///     tree.to_string(),
+    ///     "~\n├── src\n│   └── (+3 more)\n└── README.md"
+    /// );
+    /// ```
+    pub fn max_depth(mut self, depth: usize) -> Self {
+        self.max_depth = Some(depth);
+        self
+    }
+
+    /// Collapses this tree, showing a single marker in place of its
+    /// children.
+    ///
+    /// The marker is only shown if some child is not hidden. See
+    /// [`Tree::max_depth`] to collapse every tree below a depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{children, Tree};
+    ///
+    /// let tree = Tree::new().child(children![
+    ///     Tree::new().root("open").child(children!["a"]),
+    ///     Tree::new().root("closed").child(children!["b", "c"]).collapse(true),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     tree.to_string(),
+    ///     "├── open\n│   └── a\n└── closed\n    └── …"
+    /// );
+    /// ```
+    pub fn collapse(mut self, collapsed: bool) -> Self {
+        self.collapsed = collapsed;
         self
     }
 
+    /// Sets the marker shown in place of collapsed children, given how many
+    /// descendants are hidden.
+    ///
+    /// Trees collapsed by this tree's [`Tree::max_depth`] use it too, unless
+    /// they set one of their own.
+    pub fn collapsed_label_fn<F>(mut self, label: F) -> Self
+    where
+        F: Fn(usize) -> String + Send + Sync + 'static,
+    {
+        self.collapsed_label = Some(Arc::new(label));
+        self
+    }
+
+    /// Limits every rendered line to `width` columns.
+    ///
+    /// Values that do not fit are cut with an ellipsis, escape sequences
+    /// aside, so that the indentation, branch glyphs and value of a line
+    /// together are at most `width` columns wide. Each line of a multi-line
+    /// value is cut on its own, and the branches keep their alignment. With
+    /// [`Tree::max_depth`] this bounds both dimensions of the tree, for
+    /// drawing it in a fixed-size panel.
+    ///
+    /// Only the width of the tree being rendered applies, not the ones of
+    /// trees nested in it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{children, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .root("src")
+    ///     .child(children!["main.rs", "a_rather_long_module_name.rs"])
+    ///     .width(16);
+    ///
+    /// assert_eq!(
+    ///     tree.to_string(),
+    ///     "src\n├── main.rs\n└── a_rather_lo…"
+    /// );
+    /// ```
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Cuts the lines of rendered output to the tree's width, if it has one.
+    fn fit_width(&self, rendered: String) -> String {
+        let Some(width) = self.width else {
+            return rendered;
+        };
+        rendered
+            .split('\n')
+            .map(|line| {
+                // The short lines of a multi-line value are padded to its
+                // widest one; only cutting the padding needs no ellipsis.
+                if lipgloss::width(line) <= width {
+                    line.to_string()
+                } else if lipgloss::width(lipgloss::strip_ansi(line).trim_end()) <= width {
+                    lipgloss::slice_ansi(line, 0, width)
+                } else {
+                    lipgloss::truncate_ellipsis(line, width, lipgloss::utils::ELLIPSIS)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    /// Whether the children are replaced by the collapsed marker.
+    fn is_collapsed(&self) -> bool {
+        self.collapsed || self.max_depth == Some(0)
+    }
+
+    /// The collapsed marker, or nothing if no descendant is hidden by it.
+    fn collapsed_children(&self) -> NodeChildren {
+        let mut children = NodeChildren::new();
+        let hidden = self.descendant_count();
+        if hidden > 0 {
+            let label = match &self.collapsed_label {
+                Some(label) => label(hidden),
+                None => "…".to_string(),
+            };
+            children.append(Box::new(Leaf::new(label, false)));
+        }
+        children
+    }
+
+    /// The children that are not hidden and pass the filter, before the
+    /// offset is applied. Those the filter prunes are cut down to match.
+    fn shown_children(&self) -> Vec<Box<dyn Node>> {
+        (0..self.children.length())
+            .filter_map(|i| self.children.at(i))
+            .filter(|node| !node.hidden())
+            .filter_map(|node| match &self.filter {
+                Some(keep) => node.prune(keep.as_ref()),
+                None => Some(node.clone_node()),
+            })
+            .collect()
+    }
+
     /// Adds multiple children to this tree.
     ///
     /// This method accepts a vector of boxed nodes and appends them all
@@ -783,6 +1657,34 @@ impl Tree {
         self
     }
 
+    /// Adds every value of an iterator as a child.
+    ///
+    /// Unlike [`Tree::child`] the values need not be boxed, only convertible
+    /// into a node, such as strings. Use the [`children!`](crate::children)
+    /// macro to mix values of different types.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .root("Fruits")
+    ///     .child_nodes(["Apple", "Pear"]);
+    ///
+    /// assert_eq!(tree.to_string(), "Fruits\n├── Apple\n└── Pear");
+    /// ```
+    pub fn child_nodes<I, N>(mut self, children: I) -> Self
+    where
+        I: IntoIterator<Item = N>,
+        N: Into<Box<dyn Node>>,
+    {
+        for child in children {
+            self.children.append(child.into());
+        }
+        self
+    }
+
     /// Adds a single child to this tree.
     ///
     /// This is a convenience method for adding one child at a time,
@@ -810,6 +1712,112 @@ impl Tree {
         self
     }
 
+    /// Inserts a child at `index` of an already built tree, shifting the
+    /// children after it. An index equal to the number of children appends;
+    /// an index past it is ignored.
+    ///
+    /// The index counts every child as added, hidden ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let mut tree = Tree::new().child(vec!["a".into(), "c".into()]);
+    /// tree.insert_child(1, "b");
+    /// assert_eq!(tree.to_string(), "├── a\n├── b\n└── c");
+    /// ```
+    pub fn insert_child(&mut self, index: usize, child: impl Into<Box<dyn Node>>) {
+        self.children.insert(index, child.into());
+    }
+
+    /// Removes and returns the child at `index`, or `None` if out of range.
+    ///
+    /// The index counts every child as added, hidden ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Node, Tree};
+    ///
+    /// let mut tree = Tree::new().child(vec!["a".into(), "b".into()]);
+    /// assert_eq!(tree.remove_child(0).unwrap().value(), "a");
+    /// assert!(tree.remove_child(5).is_none());
+    /// assert_eq!(tree.to_string(), "└── b");
+    /// ```
+    pub fn remove_child(&mut self, index: usize) -> Option<Box<dyn Node>> {
+        self.children.remove(index)
+    }
+
+    /// Replaces the child at `index`, returning the old one, or `None` if
+    /// out of range, in which case the tree is left unchanged.
+    ///
+    /// The index counts every child as added, hidden ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Node, Tree};
+    ///
+    /// let mut tree = Tree::new().child(vec!["a".into(), "b".into()]);
+    /// let old = tree.replace_child(1, Tree::new().root("B").child(vec!["c".into()]));
+    /// assert_eq!(old.unwrap().value(), "b");
+    /// assert_eq!(tree.to_string(), "├── a\n└── B\n    └── c");
+    /// ```
+    pub fn replace_child(
+        &mut self,
+        index: usize,
+        child: impl Into<Box<dyn Node>>,
+    ) -> Option<Box<dyn Node>> {
+        self.children.replace(index, child.into())
+    }
+
+    /// Removes every child, keeping the root and all settings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let mut tree = Tree::new().root("empty").child(vec!["a".into()]);
+    /// tree.clear_children();
+    /// assert_eq!(tree.to_string(), "empty");
+    /// ```
+    pub fn clear_children(&mut self) {
+        self.children.clear();
+    }
+
+    /// Adds a multi-line text block as a single child.
+    ///
+    /// Every line after the first is drawn with the continuation indent, and no
+    /// per-line enumerators or nodes are created. This is much cheaper than a
+    /// leaf per line when rendering large outputs such as command logs. See
+    /// [`Block`] for how it differs from a multi-line leaf.
+    ///
+    /// # Arguments
+    ///
+    /// * `text` - The text to attach; it is split into lines at render time
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .root("$ make")
+    ///     .block_child("compiling...\nlinking...\ndone")
+    ///     .child(vec!["exit 0".into()]);
+    ///
+    /// assert_eq!(
+    ///     tree.to_string(),
+    ///     "$ make\n├── compiling...\n│   linking...\n│   done\n└── exit 0"
+    /// );
+    /// ```
+    pub fn block_child(mut self, text: &str) -> Self {
+        self.children.append(Box::new(Block::new(text)));
+        self
+    }
+
     /// Sets a custom enumerator function for this tree.
     ///
     /// The enumerator generates branch characters (like ├──, └──) based on
@@ -835,8 +1843,42 @@ impl Tree {
     ///     })
     ///     .child(vec!["A".into(), "B".into()]);
     /// ```
-    pub fn enumerator(mut self, enumerator: crate::Enumerator) -> Self {
-        self.enumerator = Some(enumerator);
+    pub fn enumerator<F>(mut self, enumerator: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> String + Send + Sync + 'static,
+    {
+        self.enumerator_fn = fn_pointer(&enumerator);
+        self.enumerator = Some(Arc::new(enumerator));
+        self
+    }
+
+    /// Sets the enumerator from any [`Enumerate`](crate::Enumerate) implementation.
+    ///
+    /// Use this instead of [`Tree::enumerator`] for struct-based implementations
+    /// that carry their own state.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Children, Enumerate, Tree};
+    ///
+    /// struct Hebrew;
+    ///
+    /// impl Enumerate for Hebrew {
+    ///     fn enumerate(&self, _children: &dyn Children, i: usize) -> String {
+    ///         let letters = ['א', 'ב', 'ג'];
+    ///         format!("{}.", letters[i % letters.len()])
+    ///     }
+    /// }
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec!["A".into(), "B".into()])
+    ///     .enumerator_impl(Hebrew);
+    /// assert_eq!(tree.to_string(), "א. A\nב. B");
+    /// ```
+    pub fn enumerator_impl(mut self, enumerator: impl crate::Enumerate + 'static) -> Self {
+        self.enumerator_fn = None;
+        self.enumerator = Some(Arc::new(enumerator));
         self
     }
 
@@ -863,11 +1905,56 @@ impl Tree {
     ///         }
     ///     });
     /// ```
-    pub fn indenter(mut self, indenter: crate::Indenter) -> Self {
-        self.indenter = Some(indenter);
+    pub fn indenter<F>(mut self, indenter: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> String + Send + Sync + 'static,
+    {
+        self.indenter_fn = fn_pointer(&indenter);
+        self.indenter = Some(Arc::new(indenter));
         self
     }
 
+    /// Sets the indenter from any [`Indent`](crate::Indent) implementation.
+    ///
+    /// Use this instead of [`Tree::indenter`] for struct-based implementations
+    /// that carry their own state.
+    pub fn indenter_impl(mut self, indenter: impl crate::Indent + 'static) -> Self {
+        self.indenter_fn = None;
+        self.indenter = Some(Arc::new(indenter));
+        self
+    }
+
+    /// Sets an indenter that sees where each child sits in the whole tree.
+    ///
+    /// The function receives an [`IndentContext`](crate::IndentContext) with
+    /// the depth of the indentation column, whether each ancestor has a
+    /// later sibling, and the child's index and whether it is last. This is
+    /// enough to draw guides of your own, such as dotted or ASCII ones.
+    /// Like [`Tree::indenter`], it applies to the children of this tree;
+    /// nested trees keep their own indenter.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec![
+    ///         Tree::new().root("a").child(vec!["x".into()]).into(),
+    ///         "b".into(),
+    ///     ])
+    ///     .indenter_with_context(|ctx| {
+    ///         if ctx.is_last { "    " } else { "┊   " }.to_string()
+    ///     });
+    /// assert_eq!(tree.to_string(), "├── a\n┊   └── x\n└── b");
+    /// ```
+    pub fn indenter_with_context<F>(self, indenter: F) -> Self
+    where
+        F: Fn(&crate::IndentContext) -> String + Send + Sync + 'static,
+    {
+        self.indenter_impl(crate::ContextIndenter(indenter))
+    }
+
     /// Sets the styling for the root value of this tree.
     ///
     /// # Arguments
@@ -938,7 +2025,9 @@ impl Tree {
     /// Sets a dynamic styling function for child items.
     ///
     /// The function receives the children collection and the current index,
-    /// allowing for context-aware styling decisions.
+    /// allowing for context-aware styling decisions. It is called with the
+    /// same children and index as [`Tree::enumerator_style_func`] for the
+    /// same line, which spells out what those are.
     ///
     /// # Arguments
     ///
@@ -960,15 +2049,40 @@ impl Tree {
     ///         }
     ///     });
     /// ```
-    pub fn item_style_func(mut self, func: crate::StyleFunc) -> Self {
-        self.item_style_func = Some(func);
+    pub fn item_style_func<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> Style + Send + Sync + 'static,
+    {
+        self.item_style_func_fn = fn_pointer(&func);
+        self.item_style_func = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets the item style function from any [`Stylize`](crate::Stylize) implementation.
+    ///
+    /// Use this instead of [`Tree::item_style_func`] for struct-based implementations
+    /// that carry their own state.
+    pub fn item_style_func_impl(mut self, func: impl crate::Stylize + 'static) -> Self {
+        self.item_style_func_fn = None;
+        self.item_style_func = Some(Arc::new(func));
         self
     }
 
     /// Sets a dynamic styling function for enumerators (branch characters).
     ///
     /// The function receives the children collection and the current index,
-    /// allowing for context-aware styling of branch characters.
+    /// allowing for context-aware styling of branch characters. As in Go, it
+    /// is called for the direct children of this tree only:
+    ///
+    /// - For a leaf, with this tree's visible children and the leaf's index
+    ///   among them.
+    /// - For a subtree, its own line (the subtree's root value) is a child of
+    ///   this tree like any leaf, so it gets the same arguments. The
+    ///   subtree's children are styled by the subtree's function, or by this
+    ///   one when the subtree sets no styling of its own, in which case the
+    ///   function receives the subtree's children.
+    /// - The root value of the outermost tree is not enumerated; it is styled
+    ///   with [`Tree::root_style`] and the function is never called for it.
     ///
     /// # Arguments
     ///
@@ -990,8 +2104,234 @@ impl Tree {
     ///         }
     ///     });
     /// ```
-    pub fn enumerator_style_func(mut self, func: crate::StyleFunc) -> Self {
-        self.enumerator_style_func = Some(func);
+    pub fn enumerator_style_func<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> Style + Send + Sync + 'static,
+    {
+        self.enumerator_style_func_fn = fn_pointer(&func);
+        self.enumerator_style_func = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets the enumerator style function from any [`Stylize`](crate::Stylize) implementation.
+    ///
+    /// Use this instead of [`Tree::enumerator_style_func`] for struct-based implementations
+    /// that carry their own state.
+    pub fn enumerator_style_func_impl(mut self, func: impl crate::Stylize + 'static) -> Self {
+        self.enumerator_style_func_fn = None;
+        self.enumerator_style_func = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function that styles each item from the node itself and where
+    /// it sits.
+    ///
+    /// Unlike [`Tree::item_style_func`], which gets the siblings and an
+    /// index into them, the function is handed the node being drawn, so it
+    /// cannot look up the wrong one when an offset or filter renumbers the
+    /// children. When both are set this one is used. An
+    /// [`item_style`](Tree::item_style) still takes precedence over either.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_tree::{children, Node, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .child(children!["main.rs", "README.md", "lib.rs"])
+    ///     .offset(1, 0)
+    ///     .item_style_for(|node: &dyn Node, ctx| {
+    ///         if node.value().ends_with(".rs") && ctx.is_last {
+    ///             Style::new().transform(|s| s.to_uppercase())
+    ///         } else {
+    ///             Style::new()
+    ///         }
+    ///     });
+    ///
+    /// assert_eq!(tree.to_string(), "├── README.md\n└── LIB.RS");
+    /// ```
+    pub fn item_style_for<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&dyn Node, crate::NodeContext) -> Style + Send + Sync + 'static,
+    {
+        self.item_style_for = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets a function that styles each enumerator from the node it marks
+    /// and where it sits. See [`Tree::item_style_for`].
+    ///
+    /// When both are set this one is used over
+    /// [`Tree::enumerator_style_func`].
+    pub fn enumerator_style_for<F>(mut self, func: F) -> Self
+    where
+        F: Fn(&dyn Node, crate::NodeContext) -> Style + Send + Sync + 'static,
+    {
+        self.enumerator_style_for = Some(Arc::new(func));
+        self
+    }
+
+    /// Sets the style applied to the values of children marked done.
+    ///
+    /// The style is applied to a done child's value before the item style,
+    /// so it only affects the text. Children are marked done with
+    /// [`Tree::child_done`] or [`Node::set_done`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec!["Milk".into(), "Eggs".into()])
+    ///     .child_done(0, true)
+    ///     .done_style(Style::new().strikethrough(true));
+    /// ```
+    pub fn done_style(mut self, style: Style) -> Self {
+        self.done_style = Some(style);
+        self
+    }
+
+    /// Sets the style applied to the descriptions of children, see
+    /// [`Leaf::with_description`]. Descriptions are unstyled by default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_tree::{Leaf, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec![Box::new(Leaf::new("main.rs", false).with_description("2 KB"))])
+    ///     .description_style(Style::new().faint(true));
+    /// ```
+    pub fn description_style(mut self, style: Style) -> Self {
+        self.description_style = Some(style);
+        self
+    }
+
+    /// Attaches a style to this tree's own value, used when the tree is
+    /// drawn as a child of another.
+    ///
+    /// Like [`Leaf::with_style`], it takes precedence over the parent's
+    /// item style and item style functions. The root of a top-level tree is
+    /// styled with [`Tree::root_style`] instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_tree::{children, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .child(children![
+    ///         Tree::new()
+    ///             .root("src")
+    ///             .node_style(Style::new().transform(|s| s.to_uppercase()))
+    ///             .child(children!["main.rs"]),
+    ///         "README.md",
+    ///     ])
+    ///     .item_style_func(|_, _| Style::new().padding_left(2));
+    /// assert_eq!(tree.to_string(), "├── SRC\n│   └──   main.rs\n└──   README.md");
+    /// ```
+    pub fn node_style(mut self, style: Style) -> Self {
+        self.node_style = Some(style);
+        self
+    }
+
+    /// Attaches a style to this tree's own enumerator, used when the tree
+    /// is drawn as a child of another. See [`Tree::node_style`].
+    pub fn node_enumerator_style(mut self, style: Style) -> Self {
+        self.node_enumerator_style = Some(style);
+        self
+    }
+
+    /// Styles the values of the nodes that `rules` select.
+    ///
+    /// Every rule whose [`Matcher`] selects a node applies, in order, each
+    /// overriding the properties the ones before it set; the result goes on
+    /// top of the tree's root or item style. A style attached to the node
+    /// itself, see [`Tree::node_style`], goes on top of the rules, and the
+    /// properties it sets win. The rules reach every descendant unless a
+    /// nested tree sets rules of its own. Depths always count from the
+    /// outermost tree being rendered, at 0, including in the rules of a
+    /// nested tree. Enumerators are not styled by rules.
+    ///
+    /// [`Matcher`]: crate::Matcher
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    /// use lipgloss_tree::{children, Matcher, Rule, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .root("logs")
+    ///     .child(children![
+    ///         Tree::new().root("api").child(children!["ok", "error: timeout"]),
+    ///     ])
+    ///     .style_rules(vec![
+    ///         Rule::new(Matcher::IsLeaf, Style::new().padding_left(1)),
+    ///         Rule::new(Matcher::ValueContains("error".into()), Style::new().padding_left(2)),
+    ///     ]);
+    ///
+    /// assert_eq!(
+    ///     tree.to_string(),
+    ///     "logs\n└── api\n    ├──  ok\n    └──   error: timeout"
+    /// );
+    /// ```
+    pub fn style_rules(mut self, rules: Vec<Rule>) -> Self {
+        self.style_rules = Some(Arc::new(StyleRules::from(rules)));
+        self
+    }
+
+    /// Sets how enumerators of different widths line up.
+    ///
+    /// Custom enumerators are padded to the width of the widest one among
+    /// this tree's children, measured after styling. By default they are
+    /// right-aligned, like Go's lipgloss; [`LEFT`](lipgloss::LEFT) keeps
+    /// them flush with the indentation and pads after them instead. Each
+    /// nested tree aligns its own children and does not inherit this.
+    /// Branch glyphs are all the same width and are not affected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec!["a".into(), "b".into()])
+    ///     .enumerator(|_, i| if i == 0 { "-" } else { "--" }.to_string())
+    ///     .enumerator_alignment(lipgloss::LEFT);
+    /// assert_eq!(tree.to_string(), "-  a\n-- b");
+    /// ```
+    pub fn enumerator_alignment(mut self, position: Position) -> Self {
+        self.enumerator_alignment = Some(position);
+        self
+    }
+
+    /// Marks the child at `index` done or not done. An out-of-range index
+    /// is ignored.
+    ///
+    /// The index counts every child as added, hidden ones included.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Children, Node, Tree};
+    ///
+    /// let tree = Tree::new()
+    ///     .child(vec!["Milk".into(), "Eggs".into()])
+    ///     .child_done(1, true);
+    /// assert!(!tree.children().is_done(0));
+    /// assert!(tree.children().is_done(1));
+    /// ```
+    pub fn child_done(mut self, index: usize, done: bool) -> Self {
+        if let Some(child) = self.children.at_mut(index) {
+            child.set_done(done);
+        }
         self
     }
 
@@ -1021,12 +2361,24 @@ impl Tree {
     ///         }
     ///     });
     ///
-    /// assert!(tree.get_enumerator().is_some());
+    /// assert!(tree.get_enumerator_impl().is_some());
     /// ```
-    pub fn get_enumerator(&self) -> Option<&crate::Enumerator> {
+    pub fn get_enumerator_impl(&self) -> Option<&Arc<dyn crate::Enumerate>> {
         self.enumerator.as_ref()
     }
 
+    /// Returns this tree's enumerator function as a function pointer.
+    ///
+    /// Only a function pointer can be returned this way, so this is `None`
+    /// when the enumerator function is a closure, a function item or a trait
+    /// implementation. Use [`Tree::get_enumerator_impl`] instead.
+    #[deprecated(
+        note = "Use get_enumerator_impl, which also covers closures and trait implementations"
+    )]
+    pub fn get_enumerator(&self) -> Option<&crate::Enumerator> {
+        self.enumerator_fn.as_ref()
+    }
+
     /// Returns the custom indenter function for this tree.
     ///
     /// The indenter function generates indentation strings for nested content
@@ -1053,12 +2405,24 @@ impl Tree {
     ///         }
     ///     });
     ///
-    /// assert!(tree.get_indenter().is_some());
+    /// assert!(tree.get_indenter_impl().is_some());
     /// ```
-    pub fn get_indenter(&self) -> Option<&crate::Indenter> {
+    pub fn get_indenter_impl(&self) -> Option<&Arc<dyn crate::Indent>> {
         self.indenter.as_ref()
     }
 
+    /// Returns this tree's indenter function as a function pointer.
+    ///
+    /// Only a function pointer can be returned this way, so this is `None`
+    /// when the indenter function is a closure, a function item or a trait
+    /// implementation. Use [`Tree::get_indenter_impl`] instead.
+    #[deprecated(
+        note = "Use get_indenter_impl, which also covers closures and trait implementations"
+    )]
+    pub fn get_indenter(&self) -> Option<&crate::Indenter> {
+        self.indenter_fn.as_ref()
+    }
+
     /// Returns the styling configuration for this tree's root value.
     ///
     /// The root style is applied to the tree's main value when rendered,
@@ -1115,12 +2479,24 @@ impl Tree {
     ///         }
     ///     });
     ///
-    /// assert!(tree.get_item_style_func().is_some());
+    /// assert!(tree.get_item_style_func_impl().is_some());
     /// ```
-    pub fn get_item_style_func(&self) -> Option<&crate::StyleFunc> {
+    pub fn get_item_style_func_impl(&self) -> Option<&Arc<dyn crate::Stylize>> {
         self.item_style_func.as_ref()
     }
 
+    /// Returns this tree's item style function as a function pointer.
+    ///
+    /// Only a function pointer can be returned this way, so this is `None`
+    /// when the item style function is a closure, a function item or a trait
+    /// implementation. Use [`Tree::get_item_style_func_impl`] instead.
+    #[deprecated(
+        note = "Use get_item_style_func_impl, which also covers closures and trait implementations"
+    )]
+    pub fn get_item_style_func(&self) -> Option<&crate::StyleFunc> {
+        self.item_style_func_fn.as_ref()
+    }
+
     /// Returns the dynamic styling function for this tree's branch characters.
     ///
     /// The enumerator style function provides context-aware styling for the
@@ -1149,12 +2525,24 @@ impl Tree {
     ///         }
     ///     });
     ///
-    /// assert!(tree.get_enumerator_style_func().is_some());
+    /// assert!(tree.get_enumerator_style_func_impl().is_some());
     /// ```
-    pub fn get_enumerator_style_func(&self) -> Option<&crate::StyleFunc> {
+    pub fn get_enumerator_style_func_impl(&self) -> Option<&Arc<dyn crate::Stylize>> {
         self.enumerator_style_func.as_ref()
     }
 
+    /// Returns this tree's enumerator style function as a function pointer.
+    ///
+    /// Only a function pointer can be returned this way, so this is `None`
+    /// when the enumerator style function is a closure, a function item or a trait
+    /// implementation. Use [`Tree::get_enumerator_style_func_impl`] instead.
+    #[deprecated(
+        note = "Use get_enumerator_style_func_impl, which also covers closures and trait implementations"
+    )]
+    pub fn get_enumerator_style_func(&self) -> Option<&crate::StyleFunc> {
+        self.enumerator_style_func_fn.as_ref()
+    }
+
     /// Returns the base styling configuration for this tree's child items.
     ///
     /// The item style is applied to all child item content before any
@@ -1228,26 +2616,101 @@ impl Node for Tree {
     }
 
     fn children(&self) -> Box<dyn Children> {
-        // Return a subset of children based on offset, cloning nodes to preserve structure
-        let start = self.offset[0];
-        let end = if self.offset[1] == 0 {
-            self.children.length()
-        } else {
-            self.children.length().saturating_sub(self.offset[1])
-        };
+        if self.is_collapsed() {
+            return Box::new(self.collapsed_children());
+        }
+        // Offsets apply to visible children only, so hidden and filtered
+        // nodes are dropped before the window is taken.
+        let visible = self.shown_children();
+        let end = visible.len().saturating_sub(self.offset[1]);
 
         let mut filtered_children = NodeChildren::new();
-        for i in start..end.min(self.children.length()) {
-            if let Some(node) = self.children.at(i) {
-                if node.hidden() {
-                    continue;
-                }
-                filtered_children.append(node.clone_node());
-            }
+        for node in visible.into_iter().take(end).skip(self.offset[0]) {
+            filtered_children.append(match self.max_depth {
+                Some(depth) => node.limit_depth(depth - 1, self.collapsed_label.as_ref()),
+                None => node,
+            });
         }
         Box::new(filtered_children)
     }
 
+    fn all_children(&self) -> Box<dyn Children> {
+        if self.is_collapsed() {
+            return Box::new(self.collapsed_children());
+        }
+        let visible = self.shown_children().len();
+        let end = visible.saturating_sub(self.offset[1]);
+
+        let mut all = NodeChildren::new();
+        let mut index = 0;
+        for node in (0..self.children.length()).filter_map(|i| self.children.at(i)) {
+            if node.hidden() {
+                all.append(node.clone_node());
+                continue;
+            }
+            let node = match &self.filter {
+                Some(keep) => match node.prune(keep.as_ref()) {
+                    Some(node) => node,
+                    None => continue,
+                },
+                None => node.clone_node(),
+            };
+            if index >= self.offset[0] && index < end {
+                all.append(match self.max_depth {
+                    Some(depth) => node.limit_depth(depth - 1, self.collapsed_label.as_ref()),
+                    None => node,
+                });
+            }
+            index += 1;
+        }
+        Box::new(all)
+    }
+
+    fn prune(&self, keep: &dyn Fn(&dyn Node) -> bool) -> Option<Box<dyn Node>> {
+        if self.children.length() == 0 {
+            return keep(self).then(|| self.clone_node());
+        }
+        // Hidden children stay as they are; they are not drawn either way.
+        let mut kept = false;
+        let mut children = NodeChildren::new();
+        for node in (0..self.children.length()).filter_map(|i| self.children.at(i)) {
+            if node.hidden() {
+                children.append(node.clone_node());
+            } else if let Some(node) = node.prune(keep) {
+                kept = true;
+                children.append(node);
+            }
+        }
+        kept.then(|| {
+            let mut tree = self.clone();
+            tree.children = children;
+            Box::new(tree) as Box<dyn Node>
+        })
+    }
+
+    fn descendant_count(&self) -> usize {
+        // Counted through the children before any offset or collapse.
+        self.shown_children()
+            .iter()
+            .map(|child| 1 + child.descendant_count())
+            .sum()
+    }
+
+    fn limit_depth(&self, levels: usize, label: Option<&CollapsedLabelFn>) -> Box<dyn Node> {
+        let mut tree = self.clone();
+        if tree.collapsed_label.is_none() {
+            tree.collapsed_label = label.cloned();
+        }
+        if levels == 0 {
+            tree.collapsed = true;
+        } else {
+            // A limit of the tree's own applies when it is tighter.
+            let levels = tree.max_depth.map_or(levels, |depth| depth.min(levels));
+            tree.max_depth = Some(levels);
+        }
+        Box::new(tree)
+    }
+
     fn hidden(&self) -> bool {
         self.hidden
     }
@@ -1260,14 +2723,24 @@ impl Node for Tree {
         self.value = value;
     }
 
-    fn get_enumerator(&self) -> Option<&crate::Enumerator> {
+    fn get_enumerator_impl(&self) -> Option<&Arc<dyn crate::Enumerate>> {
         self.enumerator.as_ref()
     }
 
-    fn get_indenter(&self) -> Option<&crate::Indenter> {
+    #[allow(deprecated)]
+    fn get_enumerator(&self) -> Option<&crate::Enumerator> {
+        self.enumerator_fn.as_ref()
+    }
+
+    fn get_indenter_impl(&self) -> Option<&Arc<dyn crate::Indent>> {
         self.indenter.as_ref()
     }
 
+    #[allow(deprecated)]
+    fn get_indenter(&self) -> Option<&crate::Indenter> {
+        self.indenter_fn.as_ref()
+    }
+
     fn get_item_style(&self) -> Option<&Style> {
         self.item_style.as_ref()
     }
@@ -1276,34 +2749,163 @@ impl Node for Tree {
         self.enumerator_style.as_ref()
     }
 
-    fn get_item_style_func(&self) -> Option<&crate::StyleFunc> {
+    fn get_item_style_func_impl(&self) -> Option<&Arc<dyn crate::Stylize>> {
         self.item_style_func.as_ref()
     }
 
-    fn get_enumerator_style_func(&self) -> Option<&crate::StyleFunc> {
+    #[allow(deprecated)]
+    fn get_item_style_func(&self) -> Option<&crate::StyleFunc> {
+        self.item_style_func_fn.as_ref()
+    }
+
+    fn get_enumerator_style_func_impl(&self) -> Option<&Arc<dyn crate::Stylize>> {
         self.enumerator_style_func.as_ref()
     }
+
+    #[allow(deprecated)]
+    fn get_enumerator_style_func(&self) -> Option<&crate::StyleFunc> {
+        self.enumerator_style_func_fn.as_ref()
+    }
+
+    fn get_item_style_for(&self) -> Option<&crate::NodeStyleFunc> {
+        self.item_style_for.as_ref()
+    }
+
+    fn get_enumerator_style_for(&self) -> Option<&crate::NodeStyleFunc> {
+        self.enumerator_style_for.as_ref()
+    }
+
+    fn is_done(&self) -> bool {
+        self.done
+    }
+
+    fn set_done(&mut self, done: bool) {
+        self.done = done;
+    }
+
+    fn get_done_style(&self) -> Option<&Style> {
+        self.done_style.as_ref()
+    }
+
+    fn get_description_style(&self) -> Option<&Style> {
+        self.description_style.as_ref()
+    }
+
+    fn get_node_style(&self) -> Option<&Style> {
+        self.node_style.as_ref()
+    }
+
+    fn get_node_enumerator_style(&self) -> Option<&Style> {
+        self.node_enumerator_style.as_ref()
+    }
+
+    fn get_style_rules(&self) -> Option<&Arc<StyleRules>> {
+        self.style_rules.as_ref()
+    }
+
+    fn get_enumerator_alignment(&self) -> Option<Position> {
+        self.enumerator_alignment
+    }
+
+    fn get_offset_marker(&self) -> Option<&str> {
+        self.offset_marker.as_deref()
+    }
+
+    fn offset_hidden(&self) -> (usize, usize) {
+        if self.is_collapsed() {
+            return (0, 0);
+        }
+        let visible = match self.filter {
+            Some(_) => self.shown_children().len(),
+            None => (0..self.children.length())
+                .filter_map(|i| self.children.at(i))
+                .filter(|node| !node.hidden())
+                .count(),
+        };
+        let end = visible.saturating_sub(self.offset[1]);
+        let above = self.offset[0].min(visible);
+        let shown = end.saturating_sub(above);
+        (above, visible - above - shown)
+    }
 }
 
-impl fmt::Display for Tree {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let mut renderer = crate::renderer::Renderer::new()
-            .enumerator(self.enumerator.unwrap_or(crate::default_enumerator))
-            .indenter(self.indenter.unwrap_or(crate::default_indenter));
+impl Tree {
+    /// Renders this tree in segments, see [`Renderer::render_parts`].
+    ///
+    /// The tree's own enumerator, indenter and styles are used, exactly as
+    /// when it is displayed. Joining the segments with `"\n"` gives the same
+    /// output as `to_string()`.
+    ///
+    /// [`Renderer::render_parts`]: crate::Renderer::render_parts
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new().root("Root").child(vec!["A".into(), "B".into()]);
+    /// let parts = tree.render_parts();
+    /// assert_eq!(parts, vec!["Root", "├── A", "└── B"]);
+    /// assert_eq!(parts.join("\n"), tree.to_string());
+    /// ```
+    pub fn render_parts(&self) -> Vec<String> {
+        self.renderer()
+            .render_parts(self, true, "")
+            .into_iter()
+            .map(|part| self.fit_width(part))
+            .collect()
+    }
+
+    /// Renders this tree with `prefix` in front of every line, for embedding
+    /// it in code comments or indented logs.
+    ///
+    /// The prefix is not part of the tree's layout, and it stays outside the
+    /// tree's styling, see [`lipgloss::utils::write_prefixed`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new().root("Root").child(vec!["A".into(), "B".into()]);
+    /// assert_eq!(tree.render_prefixed("// "), "// Root\n// ├── A\n// └── B");
+    /// ```
+    pub fn render_prefixed(&self, prefix: &str) -> String {
+        lipgloss::utils::prefix_lines(&self.to_string(), prefix)
+    }
+
+    /// Writes this tree to `w` with `prefix` in front of every line. See
+    /// [`Tree::render_prefixed`].
+    pub fn render_prefixed_to<W: fmt::Write>(&self, w: &mut W, prefix: &str) -> fmt::Result {
+        lipgloss::utils::write_prefixed(w, &self.to_string(), prefix)
+    }
+
+    /// Builds a renderer configured from this tree's settings.
+    pub(crate) fn renderer(&self) -> crate::renderer::Renderer {
+        let mut renderer = crate::renderer::Renderer::new();
+        if let Some(enumerator) = &self.enumerator {
+            renderer = renderer.shared_enumerator(Arc::clone(enumerator));
+        }
+        if let Some(indenter) = &self.indenter {
+            renderer = renderer.shared_indenter(Arc::clone(indenter));
+        }
 
-        // Build a TreeStyle snapshot from this Tree and apply to renderer
+        // Build a TreeStyle snapshot from this Tree and apply to renderer.
+        // Style functions are read from the tree itself while rendering, so
+        // the snapshot keeps the defaults.
         let style = crate::renderer::TreeStyle {
-            enumerator_func: self
-                .enumerator_style_func
-                .unwrap_or(|_, _| Style::new().padding_right(1)),
-            item_func: self.item_style_func.unwrap_or(|_, _| Style::new()),
             root: self.root_style.clone().unwrap_or_default(),
             enumerator_base: self.enumerator_style.clone(),
             item_base: self.item_style.clone(),
+            ..Default::default()
         };
-        renderer = renderer.style(style);
+        renderer.style(style)
+    }
+}
 
-        let output = renderer.render(self, true, "");
+impl fmt::Display for Tree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let output = self.fit_width(self.renderer().render(self, true, ""));
         write!(f, "{}", output)
     }
 }
@@ -1333,6 +2935,12 @@ impl From<Leaf> for Box<dyn Node> {
     }
 }
 
+impl From<Block> for Box<dyn Node> {
+    fn from(block: Block) -> Self {
+        Box::new(block)
+    }
+}
+
 /// Creates a new tree with the specified root value.
 ///
 /// This is a convenience function equivalent to `Tree::new().root(root)`.
@@ -5,8 +5,9 @@
 //! It includes both built-in enumerators with box-drawing characters and
 //! type definitions for custom enumerator functions.
 
-use crate::Children;
+use crate::{Children, Node};
 use lipgloss::Style;
+use std::sync::Arc;
 
 /// Function type for generating tree branch characters.
 ///
@@ -113,6 +114,175 @@ pub type Indenter = fn(&dyn Children, usize) -> String;
 /// ```
 pub type StyleFunc = fn(&dyn Children, usize) -> Style;
 
+/// Produces branch markers for the children of a tree or list.
+///
+/// This is the trait behind [`Tree::enumerator`](crate::Tree::enumerator).
+/// Any `Fn(&dyn Children, usize) -> String` implements it, including plain
+/// [`Enumerator`] functions and capturing closures. Implement it on a struct
+/// when the markers depend on state such as a lookup table or a numbering
+/// scheme that is configured at runtime.
+///
+/// Implementations must be `Send + Sync` because trees are shared across
+/// threads and cloned cheaply.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_tree::{Children, Enumerate, Tree};
+///
+/// struct Labels(Vec<&'static str>);
+///
+/// impl Enumerate for Labels {
+///     fn enumerate(&self, _children: &dyn Children, index: usize) -> String {
+///         self.0.get(index).copied().unwrap_or("-").to_string()
+///     }
+/// }
+///
+/// let tree = Tree::new()
+///     .child(vec!["Foo".into(), "Bar".into()])
+///     .enumerator_impl(Labels(vec!["a)", "b)"]));
+/// assert_eq!(tree.to_string(), "a) Foo\nb) Bar");
+/// ```
+pub trait Enumerate: Send + Sync {
+    /// Returns the marker for the child at `index` within `children`.
+    fn enumerate(&self, children: &dyn Children, index: usize) -> String;
+}
+
+impl<F> Enumerate for F
+where
+    F: Fn(&dyn Children, usize) -> String + Send + Sync,
+{
+    fn enumerate(&self, children: &dyn Children, index: usize) -> String {
+        self(children, index)
+    }
+}
+
+/// Produces the indentation for content nested below a child.
+///
+/// This is the trait behind [`Tree::indenter`](crate::Tree::indenter). Any
+/// `Fn(&dyn Children, usize) -> String` implements it, including plain
+/// [`Indenter`] functions and capturing closures.
+pub trait Indent: Send + Sync {
+    /// Returns the indentation for nested content under the child at `index`.
+    fn indent(&self, children: &dyn Children, index: usize) -> String;
+
+    /// Returns the indentation for nested content under a child, given where
+    /// it sits in the whole tree.
+    ///
+    /// The renderer calls this for every indented child. The default ignores
+    /// the context and defers to [`Indent::indent`].
+    fn indent_with_context(&self, children: &dyn Children, context: &IndentContext) -> String {
+        self.indent(children, context.index)
+    }
+
+    /// Whether this indenter lays out a list rather than tree branches.
+    ///
+    /// Trees nested under a list indenter start at the list's indentation
+    /// but do not take on the list's enumerator style, so the padding that
+    /// follows a list marker is not added after their branch glyphs.
+    /// Defaults to `false`.
+    fn is_list(&self) -> bool {
+        false
+    }
+}
+
+/// Where a child sits in the tree, as seen by
+/// [`Tree::indenter_with_context`](crate::Tree::indenter_with_context).
+///
+/// The indentation a child produces is one column of the prefix drawn in
+/// front of everything nested below it, so the context describes that
+/// column and the ones to its left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndentContext<'a> {
+    /// How many indentation columns are to the left of this one; `0` for
+    /// the children of the top-level node.
+    pub depth: usize,
+    /// For each of those columns, outermost first, whether the ancestor it
+    /// belongs to has a later visible sibling. Its length is `depth`.
+    pub ancestor_has_next: &'a [bool],
+    /// The position of the child among its visible siblings.
+    pub index: usize,
+    /// Whether no later sibling renders a visible line.
+    pub is_last: bool,
+}
+
+/// Where a child sits among its siblings, as seen by
+/// [`Tree::item_style_for`](crate::Tree::item_style_for) and
+/// [`Tree::enumerator_style_for`](crate::Tree::enumerator_style_for).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeContext {
+    /// The position of the child among its parent's children that are not
+    /// hidden, counting those the parent's offset leaves out.
+    pub index: usize,
+    /// The position of the child among the siblings that render a line;
+    /// the index the `(&dyn Children, usize)` style functions receive.
+    pub visible_index: usize,
+    /// Nesting depth of the child: `1` for the children of the top-level
+    /// node, `2` for theirs and so on.
+    pub depth: usize,
+    /// Whether no later sibling renders a visible line.
+    pub is_last: bool,
+}
+
+/// A style function of the node being styled and its [`NodeContext`].
+///
+/// Set with [`Tree::item_style_for`](crate::Tree::item_style_for) and
+/// [`Tree::enumerator_style_for`](crate::Tree::enumerator_style_for).
+pub type NodeStyleFunc = Arc<dyn Fn(&dyn Node, NodeContext) -> Style + Send + Sync>;
+
+/// An [`Indent`] built from a function of the [`IndentContext`].
+///
+/// Created by [`Tree::indenter_with_context`](crate::Tree::indenter_with_context).
+/// Called without a context, as when sampling an indenter, it sees the
+/// child as a top-level one.
+pub struct ContextIndenter<F>(pub F);
+
+impl<F> Indent for ContextIndenter<F>
+where
+    F: Fn(&IndentContext) -> String + Send + Sync,
+{
+    fn indent(&self, children: &dyn Children, index: usize) -> String {
+        (self.0)(&IndentContext {
+            depth: 0,
+            ancestor_has_next: &[],
+            index,
+            is_last: index + 1 >= children.length(),
+        })
+    }
+
+    fn indent_with_context(&self, _children: &dyn Children, context: &IndentContext) -> String {
+        (self.0)(context)
+    }
+}
+
+impl<F> Indent for F
+where
+    F: Fn(&dyn Children, usize) -> String + Send + Sync,
+{
+    fn indent(&self, children: &dyn Children, index: usize) -> String {
+        self(children, index)
+    }
+}
+
+/// Produces a style for a child based on its position.
+///
+/// This is the trait behind the item and enumerator style functions. Any
+/// `Fn(&dyn Children, usize) -> Style` implements it, including plain
+/// [`StyleFunc`] functions and capturing closures.
+pub trait Stylize: Send + Sync {
+    /// Returns the style for the child at `index` within `children`.
+    fn style(&self, children: &dyn Children, index: usize) -> Style;
+}
+
+impl<F> Stylize for F
+where
+    F: Fn(&dyn Children, usize) -> Style + Send + Sync,
+{
+    fn style(&self, children: &dyn Children, index: usize) -> Style {
+        self(children, index)
+    }
+}
+
 /// Default tree enumerator using standard box-drawing characters.
 ///
 /// This enumerator generates the classic tree structure using Unicode
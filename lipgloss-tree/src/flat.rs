@@ -0,0 +1,199 @@
+//! Flattened, line-oriented rendering for trees.
+//!
+//! Instead of indentation and branch glyphs, every line carries the path of
+//! ancestor values leading to it, so the output survives `grep`, `sort` and
+//! other line-oriented tools:
+//!
+//! ```text
+//! logs/api/started
+//! logs/api/stopped
+//! logs/db/connected
+//! ```
+
+use lipgloss::Style;
+
+use crate::children::{Node, NodeChildren, Tree};
+use crate::Stylize;
+
+/// Options for [`Tree::render_flat_with`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_tree::{FlatOptions, Tree};
+///
+/// let tree = Tree::new()
+///     .root("logs")
+///     .child(vec![Tree::new().root("api").child(vec!["started".into()]).into()]);
+///
+/// let options = FlatOptions::new().separator(" > ").branches(true);
+/// assert_eq!(
+///     tree.render_flat_with(&options),
+///     "logs\nlogs > api\nlogs > api > started"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlatOptions {
+    separator: String,
+    branches: bool,
+}
+
+impl Default for FlatOptions {
+    fn default() -> Self {
+        Self {
+            separator: "/".to_string(),
+            branches: false,
+        }
+    }
+}
+
+impl FlatOptions {
+    /// Creates options that join paths with `/` and write leaves only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the string placed between path segments.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Also writes a line for every branch, before the lines of its children.
+    pub fn branches(mut self, branches: bool) -> Self {
+        self.branches = branches;
+        self
+    }
+}
+
+impl Tree {
+    /// Renders one line per visible leaf, prefixed with its ancestor path.
+    ///
+    /// Each line is the values of the leaf's ancestors, starting with the
+    /// root, joined by `separator` and followed by the leaf's own value.
+    /// Hidden nodes and everything below them are skipped and offset windows
+    /// apply, so the lines match the leaves the tree renders. Nodes with an
+    /// empty value add no segment to the path.
+    ///
+    /// Item styles are applied to the leaf value only; the path is written
+    /// as-is and no widths or padding are involved. A multi-line value gets
+    /// the path in front of each of its lines. Use
+    /// [`Tree::render_flat_with`] to include branch lines too.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new().root("logs").child(vec![
+    ///     Tree::new().root("api").child(vec!["started".into(), "stopped".into()]).into(),
+    ///     "idle".into(),
+    /// ]);
+    ///
+    /// assert_eq!(
+    ///     tree.render_flat("/"),
+    ///     "logs/api/started\nlogs/api/stopped\nlogs/idle"
+    /// );
+    /// ```
+    pub fn render_flat(&self, separator: &str) -> String {
+        self.render_flat_with(&FlatOptions::new().separator(separator))
+    }
+
+    /// Renders the flattened tree using `options`.
+    ///
+    /// See [`Tree::render_flat`] for the format. With
+    /// [`FlatOptions::branches`] set, the root and every visible subtree with
+    /// a value get a line of their own, styled like a leaf, ahead of their
+    /// children.
+    pub fn render_flat_with(&self, options: &FlatOptions) -> String {
+        if self.hidden() {
+            return String::new();
+        }
+
+        let mut lines = Vec::new();
+        let mut path = Vec::new();
+        if !self.value().is_empty() {
+            if options.branches {
+                let root = self
+                    .get_root_style()
+                    .map(|style| style.render(&self.value()))
+                    .unwrap_or_else(|| self.value());
+                push_lines(&mut lines, &path, &root, options);
+            }
+            path.push(self.value());
+        }
+        write_children(&mut lines, self, &mut path, None, options);
+        lines.join("\n")
+    }
+}
+
+/// Appends a line for each visible descendant of `node`.
+///
+/// `item_base` is inherited from the parent level when the node does not
+/// override it.
+fn write_children(
+    lines: &mut Vec<String>,
+    node: &dyn Node,
+    path: &mut Vec<String>,
+    item_base: Option<&Style>,
+    options: &FlatOptions,
+) {
+    let item_base = node.get_item_style().or(item_base);
+    let own_item_func = crate::children::node_item_style_func(node);
+    let item_func: &dyn Stylize = match &own_item_func {
+        Some(func) => func.as_ref(),
+        None => &|_: &dyn crate::Children, _: usize| Style::new(),
+    };
+
+    let children = node.children();
+    let shown: Vec<&dyn Node> = (0..children.length())
+        .filter_map(|i| children.at(i))
+        .filter(|child| !child.hidden())
+        .collect();
+
+    // Style functions see the same children as the terminal renderer: the
+    // ones that print a line of their own.
+    let visible = NodeChildren::from_nodes(
+        shown
+            .iter()
+            .filter(|child| !child.value().is_empty())
+            .map(|child| child.clone_node())
+            .collect(),
+    );
+
+    let mut index = 0;
+    for child in shown {
+        let value = child.value();
+        if value.is_empty() {
+            write_children(lines, child, path, item_base, options);
+            continue;
+        }
+
+        let style = item_base
+            .cloned()
+            .unwrap_or_else(|| item_func.style(&visible, index));
+        index += 1;
+
+        let is_branch = child.children().length() > 0;
+        if !is_branch || options.branches {
+            push_lines(lines, path, &style.render(&value), options);
+        }
+        if is_branch {
+            path.push(value);
+            write_children(lines, child, path, item_base, options);
+            path.pop();
+        }
+    }
+}
+
+/// Appends `value` behind the joined `path`, one line per line of `value`.
+fn push_lines(lines: &mut Vec<String>, path: &[String], value: &str, options: &FlatOptions) {
+    let prefix = if path.is_empty() {
+        String::new()
+    } else {
+        format!("{}{}", path.join(&options.separator), options.separator)
+    };
+    for line in value.split('\n') {
+        lines.push(format!("{}{}", prefix, line));
+    }
+}
@@ -0,0 +1,165 @@
+//! HTML export for trees, enabled with the `html` feature.
+
+use lipgloss::html::{escape_text, style_attr, style_css, CONTAINER_CSS};
+use lipgloss::Style;
+
+use crate::children::{Leaf, Node, NodeChildren, Tree};
+use crate::{default_enumerator, Enumerate, Stylize};
+
+impl Tree {
+    /// Renders the tree as nested HTML lists.
+    ///
+    /// The root value, if any, comes first in a `<span>`, followed by a
+    /// `<ul>` of its children. Subtrees become lists nested inside their
+    /// parent's `<li>`. A level whose enumerator counts (`1.`, `A.`, `I.`
+    /// and so on) becomes an `<ol>` with the matching `type`; glyph
+    /// enumerators such as branches and bullets give a `<ul>`.
+    ///
+    /// Root and item styles are mapped to inline CSS on a `<span>` around
+    /// each value. Hidden nodes are skipped, offsets apply, and content is
+    /// escaped and stripped of ANSI sequences. The markup has no whitespace
+    /// between tags because the outer `<div>` preserves whitespace.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new()
+    ///     .root("src")
+    ///     .child(vec![Tree::new().root("bin").child(vec!["main.rs".into()]).into()]);
+    ///
+    /// assert_eq!(
+    ///     tree.to_html(),
+    ///     "<div style=\"font-family:monospace;white-space:pre-wrap\">\
+    ///      <span>src</span>\
+    ///      <ul><li>bin<ul><li>main.rs</li></ul></li></ul>\
+    ///      </div>"
+    /// );
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut out = format!("<div{}>", style_attr(CONTAINER_CSS));
+        if self.hidden() {
+            out.push_str("</div>");
+            return out;
+        }
+        if !self.value().is_empty() {
+            let css = self.get_root_style().map(style_css).unwrap_or_default();
+            out.push_str(&format!(
+                "<span{}>{}</span>",
+                style_attr(&css),
+                escape_text(&self.value())
+            ));
+        }
+        write_list(&mut out, self, &default_enumerator, None);
+        out.push_str("</div>");
+        out
+    }
+}
+
+/// Writes the children of `node` as a list, or nothing when none are shown.
+///
+/// `enumerator` and `item_base` are inherited from the parent level when the
+/// node does not override them.
+fn write_list(
+    out: &mut String,
+    node: &dyn Node,
+    enumerator: &dyn Enumerate,
+    item_base: Option<&Style>,
+) {
+    let own_enumerator = crate::children::node_enumerator(node);
+    let enumerator = own_enumerator.as_deref().unwrap_or(enumerator);
+    let item_base = node.get_item_style().or(item_base);
+    let own_item_func = crate::children::node_item_style_func(node);
+    let item_func: &dyn Stylize = match &own_item_func {
+        Some(func) => func.as_ref(),
+        None => &|_: &dyn crate::Children, _: usize| Style::new(),
+    };
+
+    let children = node.children();
+    let shown: Vec<&dyn Node> = (0..children.length())
+        .filter_map(|i| children.at(i))
+        .filter(|child| !child.hidden())
+        .collect();
+
+    // Style functions see the same children as the terminal renderer: the
+    // ones that print a line of their own.
+    let visible = NodeChildren::from_nodes(
+        shown
+            .iter()
+            .filter(|child| !child.value().is_empty())
+            .map(|child| child.clone_node())
+            .collect(),
+    );
+
+    // Each entry is an item's content and the lists nested under it. Nodes
+    // with an empty value are containers whose children belong to the
+    // previous item.
+    let mut items: Vec<(String, String)> = Vec::new();
+    for child in shown {
+        let mut nested = String::new();
+        write_list(&mut nested, child, enumerator, item_base);
+
+        if child.value().is_empty() {
+            match items.last_mut() {
+                Some((_, under)) => under.push_str(&nested),
+                None if !nested.is_empty() => items.push((String::new(), nested)),
+                None => {}
+            }
+            continue;
+        }
+
+        let index = items
+            .iter()
+            .filter(|(content, _)| !content.is_empty())
+            .count();
+        let style = item_base
+            .cloned()
+            .unwrap_or_else(|| item_func.style(&visible, index));
+        let css = style_css(&style);
+        let text = escape_text(&child.value());
+        let content = if css.is_empty() {
+            text
+        } else {
+            format!("<span{}>{}</span>", style_attr(&css), text)
+        };
+        items.push((content, nested));
+    }
+
+    if items.is_empty() {
+        return;
+    }
+
+    let (open, close) = list_tags(enumerator);
+    out.push_str(&open);
+    for (content, nested) in items {
+        out.push_str(&format!("<li>{}{}</li>", content, nested));
+    }
+    out.push_str(close);
+}
+
+/// Chooses `<ol>` or `<ul>` by sampling the enumerator: markers that differ
+/// between the first two items count, markers that repeat are bullets.
+fn list_tags(enumerator: &dyn Enumerate) -> (String, &'static str) {
+    let sample = NodeChildren::from_nodes(
+        (0..3)
+            .map(|_| Box::new(Leaf::new("x", false)) as Box<dyn Node>)
+            .collect(),
+    );
+    let marker = |i| {
+        enumerator
+            .enumerate(&sample, i)
+            .trim()
+            .trim_end_matches(['.', ')'])
+            .to_string()
+    };
+    let first = marker(0);
+    if first == marker(1) {
+        return ("<ul>".to_string(), "</ul>");
+    }
+    let open = match first.as_str() {
+        "a" | "A" | "i" | "I" => format!("<ol type=\"{}\">", first),
+        _ => "<ol>".to_string(),
+    };
+    (open, "</ol>")
+}
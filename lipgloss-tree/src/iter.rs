@@ -0,0 +1,125 @@
+//! Walking the nodes of a tree in the order they are rendered.
+//!
+//! [`Tree::iter`] visits the nodes that print a line of their own, each
+//! once however many lines its value takes, so the position of a node in
+//! the walk is its position on screen. Interactive trees can use it to map
+//! a cursor to the node under it:
+//!
+//! ```text
+//! 0  home
+//! 1  ├── notes
+//! 2  │   line two     <- still node 1
+//! 3  └── todo.txt     <- node 2
+//! ```
+
+use crate::children::{Node, Tree};
+
+/// Depth-first iterator over the nodes of a [`Tree`] as rendered, see
+/// [`Tree::iter`].
+///
+/// Unlike most `iter` methods it yields owned nodes rather than references.
+/// The children a tree shows are worked out when asked for, with offsets,
+/// filters and collapsing applied, so there is no stored node to borrow.
+pub struct Iter {
+    /// Nodes still to visit with their depth, the next one last.
+    stack: Vec<(usize, Box<dyn Node>)>,
+}
+
+impl Iterator for Iter {
+    type Item = (usize, Box<dyn Node>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((depth, node)) = self.stack.pop() {
+            let children = node.children();
+            for i in (0..children.length()).rev() {
+                if let Some(child) = children.at(i).filter(|child| !child.hidden()) {
+                    self.stack.push((depth + 1, child.clone_node()));
+                }
+            }
+            // Unnamed containers print no line; their children hang under
+            // the item before them.
+            if !node.value().is_empty() {
+                return Some((depth, node));
+            }
+        }
+        None
+    }
+}
+
+impl Tree {
+    /// Returns a depth-first iterator over the nodes this tree renders,
+    /// with their depth: 0 for the root, 1 for its children and so on.
+    ///
+    /// Nodes come in the order their lines are rendered. Hidden nodes and
+    /// everything below them are skipped, offsets, filters and collapsing
+    /// apply, and a collapsed marker is visited like the leaf it is drawn
+    /// as. Unnamed nodes are not visited themselves, only their children.
+    ///
+    /// The nodes are copies of what is shown, not the nodes the tree was
+    /// built from, see [`Iter`]. Each level copies the subtrees below it, so
+    /// a full walk costs O(n × depth) node copies for a tree of n nodes.
+    /// Walk it once and keep the result, rather than calling
+    /// [`Tree::node_at_visible_index`] in a loop.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{children, Tree};
+    ///
+    /// let tree = Tree::new().root("home").child(children![
+    ///     Tree::new().root("docs").child(children!["notes.txt"]),
+    ///     "todo.txt",
+    /// ]);
+    ///
+    /// let walked: Vec<(usize, String)> = tree
+    ///     .iter()
+    ///     .map(|(depth, node)| (depth, node.value()))
+    ///     .collect();
+    /// assert_eq!(
+    ///     walked,
+    ///     [
+    ///         (0, "home".to_string()),
+    ///         (1, "docs".to_string()),
+    ///         (2, "notes.txt".to_string()),
+    ///         (1, "todo.txt".to_string()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn iter(&self) -> Iter {
+        let stack = if self.hidden() {
+            Vec::new()
+        } else {
+            vec![(0, Box::new(self.clone()) as Box<dyn Node>)]
+        };
+        Iter { stack }
+    }
+
+    /// Number of nodes rendered, each counted once however many lines it
+    /// takes. See [`Tree::iter`].
+    pub fn visible_len(&self) -> usize {
+        self.iter().count()
+    }
+
+    /// Returns the `index`th rendered node, counting from 0 in the order of
+    /// [`Tree::iter`], or `None` past the end.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{children, Leaf, Tree};
+    ///
+    /// let tree = Tree::new().root("home").child(children![
+    ///     "notes\nline two",
+    ///     Leaf::new("secret", true),
+    ///     "todo.txt",
+    /// ]);
+    ///
+    /// assert_eq!(tree.visible_len(), 3);
+    /// assert_eq!(tree.node_at_visible_index(1).unwrap().value(), "notes\nline two");
+    /// assert_eq!(tree.node_at_visible_index(2).unwrap().value(), "todo.txt");
+    /// assert!(tree.node_at_visible_index(3).is_none());
+    /// ```
+    pub fn node_at_visible_index(&self, index: usize) -> Option<Box<dyn Node>> {
+        self.iter().nth(index).map(|(_, node)| node)
+    }
+}
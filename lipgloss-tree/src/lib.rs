@@ -88,11 +88,15 @@
 //!
 //! ## Architecture
 //!
-//! The crate is organized into three main modules:
+//! The crate is organized into five main modules:
 //!
 //! - [`children`] - Node and tree data structures
 //! - [`enumerator`] - Functions for generating branch characters and indentation  
+//! - [`flat`] - Flattened rendering with ancestor paths
+//! - [`iter`] - Walking the rendered nodes for selection handling
+//! - [`outline`] - Plain-text outline serialization
 //! - [`renderer`] - Core rendering engine with styling support
+//! - [`rules`] - Styling nodes by selectors on depth, value and shape
 
 #![warn(missing_docs)]
 
@@ -100,15 +104,37 @@
 pub mod children;
 /// Functions for generating branch characters and indentation strings.
 pub mod enumerator;
+/// Flattened, line-oriented rendering with ancestor paths.
+pub mod flat;
+/// HTML export, enabled with the `html` feature.
+#[cfg(feature = "html")]
+mod html;
+/// Walking the rendered nodes of a tree.
+pub mod iter;
+/// Plain-text outline serialization for trees.
+pub mod outline;
 /// Core rendering engine with styling and formatting support.
 pub mod renderer;
+/// Styling nodes by selectors on their depth, value and shape.
+pub mod rules;
+/// Rendering a range of lines for height-limited viewports.
+pub mod window;
 
 // Re-export the main types and functions
-pub use children::{new_string_data, root, Children, Filter, Leaf, Node, NodeChildren, Tree};
+pub use children::{
+    new_string_data, root, Block, Children, CollapsedLabelFn, Filter, Leaf, Node, NodeChildren,
+    Tree,
+};
 pub use enumerator::{
-    default_enumerator, default_indenter, rounded_enumerator, Enumerator, Indenter, StyleFunc,
+    default_enumerator, default_indenter, rounded_enumerator, ContextIndenter, Enumerate,
+    Enumerator, Indent, IndentContext, Indenter, NodeContext, NodeStyleFunc, StyleFunc, Stylize,
 };
+pub use flat::FlatOptions;
+pub use iter::Iter;
+pub use outline::{OutlineError, OutlineErrorKind, OutlineOptions};
 pub use renderer::Renderer;
+pub use rules::{Matcher, MatcherParseError, Rule, StyleRules};
+pub use window::WindowOpts;
 
 // Go API compatibility aliases
 /// Trait alias for `Children` - provides compatibility with Go naming conventions
@@ -120,6 +146,46 @@ pub use NodeChildren as NodeChildrenType;
 /// Type alias for `Tree` - provides compatibility with Go naming conventions
 pub use Tree as TreeType;
 
+/// Returns the version of this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(!lipgloss_tree::version().is_empty());
+/// ```
+pub const fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
+/// Builds the children for [`Tree::child`] from values of mixed types.
+///
+/// Each value is converted with `Into<Box<dyn Node>>`, so strings, [`Leaf`]s,
+/// [`Block`]s and [`Tree`]s can be listed side by side without boxing or
+/// casting them.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_tree::{children, Leaf, Tree};
+///
+/// let tree = Tree::new().root(".").child(children![
+///     "macOS",
+///     Tree::new().root("Linux").child(children!["NixOS", "Void Linux"]),
+///     Leaf::new("Plan 9", false),
+/// ]);
+///
+/// assert_eq!(
+///     tree.to_string(),
+///     ".\n├── macOS\n├── Linux\n│   ├── NixOS\n│   └── Void Linux\n└── Plan 9"
+/// );
+/// ```
+#[macro_export]
+macro_rules! children {
+    ($($child:expr),* $(,)?) => {
+        ::std::vec![$(::std::convert::Into::<::std::boxed::Box<dyn $crate::Node>>::into($child)),*]
+    };
+}
+
 /// Creates a new empty tree.
 ///
 /// This is a convenience function equivalent to `Tree::new()`.
@@ -0,0 +1,341 @@
+//! Plain-text outline serialization for trees.
+//!
+//! An outline stores one node value per line, indented by depth, with no
+//! branch glyphs or styles:
+//!
+//! ```text
+//! Notes
+//!   Groceries
+//!     Milk
+//!   Ideas
+//! ```
+//!
+//! [`Tree::to_outline`] writes this format with two spaces per level and
+//! [`Tree::from_outline`] reads it back, detecting whether the file is
+//! indented with tabs or with runs of spaces.
+//!
+//! A single top-level line reads back as the root. A tree without a root
+//! whose only top-level node would be mistaken for one is written a level
+//! in instead, and an outline whose first line is indented reads back
+//! without a root.
+
+use std::fmt;
+
+use crate::children::{Children, Leaf, Node, Tree};
+
+/// Options for writing and reading outlines.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_tree::{Leaf, OutlineOptions, Tree};
+///
+/// let tree = Tree::new()
+///     .root("Notes")
+///     .child(vec!["Shown".into(), Leaf::new("Draft", true).into()]);
+///
+/// let options = OutlineOptions::new().hidden_marker("~ ");
+/// let outline = tree.to_outline_with(&options);
+/// assert_eq!(outline, "Notes\n  Shown\n  ~ Draft");
+///
+/// let restored = Tree::from_outline_with(&outline, &options).unwrap();
+/// assert_eq!(restored.to_outline_with(&options), outline);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OutlineOptions {
+    hidden_marker: Option<String>,
+}
+
+impl OutlineOptions {
+    /// Creates options that skip hidden nodes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Writes hidden nodes with `marker` in front of their value, and reads
+    /// lines starting with `marker` back as hidden nodes.
+    ///
+    /// Without a marker hidden nodes, and everything below them, are left out
+    /// of the outline.
+    pub fn hidden_marker(mut self, marker: impl Into<String>) -> Self {
+        let marker = marker.into();
+        self.hidden_marker = (!marker.is_empty()).then_some(marker);
+        self
+    }
+}
+
+/// The reason an outline could not be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutlineErrorKind {
+    /// The line mixes tabs and spaces, or uses a different indent character
+    /// than earlier lines.
+    MixedIndentation,
+    /// The line's leading spaces are not a multiple of the detected indent
+    /// width.
+    UnevenIndentation,
+    /// The line is indented more than one level deeper than the line above.
+    UnexpectedIndent,
+    /// The line is less indented than the first line of an outline that
+    /// starts indented.
+    UnexpectedDedent,
+}
+
+/// OutlineError is returned when [`Tree::from_outline`] cannot parse its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutlineError {
+    /// The 1-based line number the error was found on.
+    pub line: usize,
+    /// What was wrong with the line.
+    pub kind: OutlineErrorKind,
+}
+
+impl fmt::Display for OutlineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self.kind {
+            OutlineErrorKind::MixedIndentation => "mixed tabs and spaces in indentation",
+            OutlineErrorKind::UnevenIndentation => "indentation is not a whole number of levels",
+            OutlineErrorKind::UnexpectedIndent => "indented more than one level past its parent",
+            OutlineErrorKind::UnexpectedDedent => "indented less than the first line",
+        };
+        write!(f, "line {}: {}", self.line, reason)
+    }
+}
+
+impl std::error::Error for OutlineError {}
+
+impl Tree {
+    /// Serializes this tree to an indented plain-text outline.
+    ///
+    /// Each node's value goes on its own line, indented two spaces per
+    /// level. The root, if set, is the first line; a tree without a root
+    /// writes its children at the top level, or one level in when there is
+    /// only one of them. Hidden nodes are skipped and offset windows are
+    /// applied, so the outline holds exactly what the tree renders. Use
+    /// [`Tree::to_outline_with`] to keep hidden nodes.
+    ///
+    /// Values are written as-is, so values containing newlines or leading
+    /// whitespace do not survive a round trip.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Tree;
+    ///
+    /// let tree = Tree::new().root("Notes").child(vec![
+    ///     Tree::new().root("Groceries").child(vec!["Milk".into()]).into(),
+    ///     "Ideas".into(),
+    /// ]);
+    ///
+    /// assert_eq!(tree.to_outline(), "Notes\n  Groceries\n    Milk\n  Ideas");
+    /// ```
+    pub fn to_outline(&self) -> String {
+        self.to_outline_with(&OutlineOptions::default())
+    }
+
+    /// Serializes this tree to an outline using `options`.
+    ///
+    /// See [`Tree::to_outline`] for the format.
+    pub fn to_outline_with(&self, options: &OutlineOptions) -> String {
+        let mut lines = Vec::new();
+        let depth = if self.value().is_empty() {
+            // A lone top-level line would read back as the root.
+            usize::from(outline_children(self, options).length() == 1)
+        } else {
+            write_line(&mut lines, self, 0, options);
+            1
+        };
+        write_children(&mut lines, self, depth, options);
+        lines.join("\n")
+    }
+
+    /// Parses an indented plain-text outline into a tree.
+    ///
+    /// Indentation may use tabs or spaces; the indent width is taken from the
+    /// first indented line. Blank lines are ignored. A single top-level line
+    /// becomes the root; several top-level lines, or an outline whose first
+    /// line is indented one level, produce a tree without a root. Nodes with
+    /// children become subtrees and the rest become leaves, all with default
+    /// styles.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`OutlineError`] with the offending line number when
+    /// indentation mixes tabs and spaces, is not a whole number of levels,
+    /// skips a level, or leaves the level an indented first line set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{OutlineErrorKind, Tree};
+    ///
+    /// let tree = Tree::from_outline("Notes\n\tGroceries\n\t\tMilk\n\tIdeas").unwrap();
+    /// assert_eq!(tree.to_outline(), "Notes\n  Groceries\n    Milk\n  Ideas");
+    ///
+    /// let err = Tree::from_outline("Notes\n  Groceries\n\tMilk").err().unwrap();
+    /// assert_eq!(err.line, 3);
+    /// assert_eq!(err.kind, OutlineErrorKind::MixedIndentation);
+    /// ```
+    pub fn from_outline(s: &str) -> Result<Tree, OutlineError> {
+        Self::from_outline_with(s, &OutlineOptions::default())
+    }
+
+    /// Parses an outline using `options`.
+    ///
+    /// See [`Tree::from_outline`] for the format and errors.
+    pub fn from_outline_with(s: &str, options: &OutlineOptions) -> Result<Tree, OutlineError> {
+        let ParsedLines { entries, rootless } = parse_lines(s)?;
+
+        // The stack holds the open subtree at each depth, with a synthetic
+        // root at the bottom that collects the top-level nodes.
+        let mut stack: Vec<Vec<Entry>> = vec![Vec::new()];
+        for (depth, value) in entries {
+            while stack.len() > depth + 1 {
+                close_level(&mut stack);
+            }
+            stack.last_mut().unwrap().push(Entry {
+                value: value.to_string(),
+                children: Vec::new(),
+            });
+            stack.push(Vec::new());
+        }
+        while stack.len() > 1 {
+            close_level(&mut stack);
+        }
+        let mut top = stack.pop().unwrap();
+
+        if top.len() == 1 && !rootless {
+            let root = top.pop().unwrap();
+            let (value, hidden) = split_marker(&root.value, options);
+            Ok(build_children(Tree::new().root(value), root.children, options).hide(hidden))
+        } else {
+            Ok(build_children(Tree::new(), top, options))
+        }
+    }
+}
+
+/// A parsed outline line and the lines nested under it.
+struct Entry {
+    value: String,
+    children: Vec<Entry>,
+}
+
+fn write_line(lines: &mut Vec<String>, node: &dyn Node, depth: usize, options: &OutlineOptions) {
+    let marker = match (&options.hidden_marker, node.hidden()) {
+        (Some(marker), true) => marker.as_str(),
+        _ => "",
+    };
+    lines.push(format!("{}{}{}", "  ".repeat(depth), marker, node.value()));
+}
+
+/// The children written to the outline: all of them when hidden nodes are
+/// marked, otherwise only the visible ones.
+fn outline_children(node: &dyn Node, options: &OutlineOptions) -> Box<dyn Children> {
+    if options.hidden_marker.is_some() {
+        node.all_children()
+    } else {
+        node.children()
+    }
+}
+
+fn write_children(
+    lines: &mut Vec<String>,
+    node: &dyn Node,
+    depth: usize,
+    options: &OutlineOptions,
+) {
+    let children = outline_children(node, options);
+    for child in (0..children.length()).filter_map(|i| children.at(i)) {
+        write_line(lines, child, depth, options);
+        write_children(lines, child, depth + 1, options);
+    }
+}
+
+/// The lines of an outline as `(depth, value)` pairs.
+struct ParsedLines<'a> {
+    entries: Vec<(usize, &'a str)>,
+    /// The first line was indented, marking an outline without a root.
+    /// Depths are then counted from that line.
+    rootless: bool,
+}
+
+/// Splits an outline into `(depth, value)` pairs, skipping blank lines.
+fn parse_lines(s: &str) -> Result<ParsedLines<'_>, OutlineError> {
+    // The indent unit is fixed by the first indented line.
+    let mut unit: Option<(char, usize)> = None;
+    let mut entries = Vec::new();
+    let mut prev_depth: Option<usize> = None;
+    // The depth of the first line: 1 when the outline has no root.
+    let mut base: Option<usize> = None;
+
+    for (i, line) in s.lines().enumerate() {
+        let number = i + 1;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value = line.trim_start_matches([' ', '\t']);
+        let indent = &line[..line.len() - value.len()];
+        let error = |kind| OutlineError { line: number, kind };
+
+        let depth = if indent.is_empty() {
+            0
+        } else {
+            let first = indent.chars().next().unwrap();
+            if indent.chars().any(|c| c != first) {
+                return Err(error(OutlineErrorKind::MixedIndentation));
+            }
+            let (ch, width) = *unit.get_or_insert((first, indent.len()));
+            if ch != first {
+                return Err(error(OutlineErrorKind::MixedIndentation));
+            }
+            if indent.len() % width != 0 {
+                return Err(error(OutlineErrorKind::UnevenIndentation));
+            }
+            indent.len() / width
+        };
+
+        if depth > prev_depth.map_or(1, |d| d + 1) {
+            return Err(error(OutlineErrorKind::UnexpectedIndent));
+        }
+        let base = *base.get_or_insert(depth);
+        if depth < base {
+            return Err(error(OutlineErrorKind::UnexpectedDedent));
+        }
+        prev_depth = Some(depth);
+        entries.push((depth - base, value));
+    }
+    Ok(ParsedLines {
+        entries,
+        rootless: base == Some(1),
+    })
+}
+
+/// Pops the innermost open level and attaches it to its parent entry.
+fn close_level(stack: &mut Vec<Vec<Entry>>) {
+    let children = stack.pop().unwrap();
+    let parent = stack.last_mut().unwrap().last_mut().unwrap();
+    parent.children = children;
+}
+
+fn split_marker<'a>(value: &'a str, options: &OutlineOptions) -> (&'a str, bool) {
+    match options
+        .hidden_marker
+        .as_deref()
+        .and_then(|marker| value.strip_prefix(marker))
+    {
+        Some(rest) => (rest, true),
+        None => (value, false),
+    }
+}
+
+fn build_children(mut tree: Tree, entries: Vec<Entry>, options: &OutlineOptions) -> Tree {
+    for entry in entries {
+        let (value, hidden) = split_marker(&entry.value, options);
+        if entry.children.is_empty() {
+            tree = tree.add_child(Leaf::new(value, hidden));
+        } else {
+            let subtree = build_children(Tree::new().root(value), entry.children, options);
+            tree = tree.add_child(subtree.hide(hidden));
+        }
+    }
+    tree
+}
@@ -5,10 +5,16 @@
 //! It supports both built-in tree glyphs (├──, └──, etc.) and custom
 //! enumerators with proper alignment and styling inheritance.
 
-use crate::children::Children;
-use crate::{default_enumerator, default_indenter, Enumerator, Indenter, Node, StyleFunc};
-use lipgloss::{height, join_horizontal, join_vertical, Style, LEFT, TOP};
-use unicode_width::UnicodeWidthStr;
+use crate::children::{
+    node_enumerator, node_enumerator_style_func, node_indenter, node_item_style_func, Children,
+};
+use crate::rules::{layer, StyleRules};
+use crate::{
+    default_enumerator, default_indenter, Enumerate, Indent, IndentContext, Node, NodeContext,
+    NodeStyleFunc, StyleFunc, Stylize,
+};
+use lipgloss::{height, join_horizontal, join_vertical, width, Style, LEFT, RIGHT, TOP};
+use std::sync::Arc;
 
 /// Minimal Children implementation used to synthesize enumerator glyphs with controlled length/index.
 ///
@@ -46,6 +52,58 @@ impl<'a> Children for VisibleChildren<'a> {
     }
 }
 
+/// A [`NodeStyleFunc`] seen as a [`Stylize`] over the visible children,
+/// handing it the node at each index and where that node sits.
+struct NodeStyler<'a> {
+    func: &'a NodeStyleFunc,
+    /// Index of each visible child among the node's children
+    map: &'a [usize],
+    /// Whether each visible child is the last to render a line
+    is_last: &'a [bool],
+    /// Children skipped by the node's offset
+    skipped: usize,
+    depth: usize,
+}
+
+impl Stylize for NodeStyler<'_> {
+    fn style(&self, children: &dyn Children, index: usize) -> Style {
+        let Some(node) = children.at(index) else {
+            return Style::new();
+        };
+        let context = NodeContext {
+            index: self.skipped + self.map.get(index).copied().unwrap_or(index),
+            visible_index: index,
+            depth: self.depth,
+            is_last: self.is_last.get(index).copied().unwrap_or(true),
+        };
+        (self.func)(node, context)
+    }
+}
+
+/// Which node a rendered line belongs to.
+///
+/// Collected alongside the output by [`Renderer::render_mapped`], one entry
+/// per output line, so lines can be traced back to the tree structure.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LineInfo {
+    /// Nesting depth of the node: 0 for the root, 1 for its children and so
+    /// on. Children of an unnamed container hang under the previous item and
+    /// count one level below it.
+    pub(crate) depth: usize,
+    /// Whether this is the first line of the node.
+    pub(crate) first: bool,
+}
+
+/// Records a [`LineInfo`] for each line of `text`, a node at `depth`.
+fn map_lines(map: Option<&mut Vec<LineInfo>>, text: &str, depth: usize) {
+    if let Some(map) = map {
+        map.extend(text.split('\n').enumerate().map(|(i, _)| LineInfo {
+            depth,
+            first: i == 0,
+        }));
+    }
+}
+
 /// Styling configuration for tree rendering.
 ///
 /// `TreeStyle` controls how different parts of the tree are styled, including
@@ -130,9 +188,15 @@ pub struct Renderer {
     /// Styling configuration for this renderer
     style: TreeStyle,
     /// Function to generate enumerator strings (branch characters)
-    enumerator: Enumerator,
+    enumerator: Arc<dyn Enumerate>,
     /// Function to generate indentation strings for nested content
-    indenter: Indenter,
+    indenter: Arc<dyn Indent>,
+    /// Style rules of the trees above, for nodes without their own
+    rules: Option<Arc<StyleRules>>,
+    /// Enumerator and item style functions of the tree above, for subtrees
+    /// that set no styling of their own (Go shares the parent's renderer)
+    inherited_enum_func: Option<Arc<dyn Stylize>>,
+    inherited_item_func: Option<Arc<dyn Stylize>>,
 }
 
 impl Renderer {
@@ -153,8 +217,11 @@ impl Renderer {
     pub fn new() -> Self {
         Self {
             style: TreeStyle::default(),
-            enumerator: default_enumerator,
-            indenter: default_indenter,
+            enumerator: Arc::new(default_enumerator),
+            indenter: Arc::new(default_indenter),
+            rules: None,
+            inherited_enum_func: None,
+            inherited_item_func: None,
         }
     }
 
@@ -209,7 +276,21 @@ impl Renderer {
     ///         }
     ///     });
     /// ```
-    pub fn enumerator(mut self, enumerator: Enumerator) -> Self {
+    pub fn enumerator<F>(mut self, enumerator: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> String + Send + Sync + 'static,
+    {
+        self.enumerator = Arc::new(enumerator);
+        self
+    }
+
+    /// Sets the enumerator from any [`Enumerate`] implementation.
+    pub fn enumerator_impl(self, enumerator: impl Enumerate + 'static) -> Self {
+        self.shared_enumerator(Arc::new(enumerator))
+    }
+
+    /// Sets an enumerator that is already shared with a tree node.
+    pub(crate) fn shared_enumerator(mut self, enumerator: Arc<dyn Enumerate>) -> Self {
         self.enumerator = enumerator;
         self
     }
@@ -238,11 +319,43 @@ impl Renderer {
     ///         }
     ///     });
     /// ```
-    pub fn indenter(mut self, indenter: Indenter) -> Self {
+    pub fn indenter<F>(mut self, indenter: F) -> Self
+    where
+        F: Fn(&dyn Children, usize) -> String + Send + Sync + 'static,
+    {
+        self.indenter = Arc::new(indenter);
+        self
+    }
+
+    /// Sets the indenter from any [`Indent`] implementation.
+    pub fn indenter_impl(self, indenter: impl Indent + 'static) -> Self {
+        self.shared_indenter(Arc::new(indenter))
+    }
+
+    /// Sets an indenter that is already shared with a tree node.
+    pub(crate) fn shared_indenter(mut self, indenter: Arc<dyn Indent>) -> Self {
         self.indenter = indenter;
         self
     }
 
+    /// Applies style rules to the nodes rendered, unless they have their own.
+    pub(crate) fn shared_rules(mut self, rules: Option<Arc<StyleRules>>) -> Self {
+        self.rules = rules;
+        self
+    }
+
+    /// Uses the enumerator and item style functions of the tree above for
+    /// the nodes rendered, unless they have their own.
+    pub(crate) fn shared_style_funcs(
+        mut self,
+        enum_func: Option<Arc<dyn Stylize>>,
+        item_func: Option<Arc<dyn Stylize>>,
+    ) -> Self {
+        self.inherited_enum_func = enum_func;
+        self.inherited_item_func = item_func;
+        self
+    }
+
     /// Renders a tree node and its children to a formatted string.
     ///
     /// This is the main rendering method that converts a tree structure into
@@ -273,9 +386,61 @@ impl Renderer {
     /// println!("{}", output);
     /// ```
     pub fn render(&self, node: &dyn Node, root: bool, prefix: &str) -> String {
+        self.render_parts(node, root, prefix).join("\n")
+    }
+
+    /// Renders a tree node like [`Renderer::render`], but returns the output
+    /// in segments instead of joining them.
+    ///
+    /// The segments are, in order: the root line (if rendered), then for each
+    /// child its own (possibly multi-line) block followed by the rendered
+    /// subtree below it, if any. For a flat tree without a root there is
+    /// exactly one segment per visible child, which makes this useful for
+    /// laying children out in other arrangements, such as columns.
+    ///
+    /// Joining the segments with `"\n"` gives the output of `render`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Renderer, Tree};
+    ///
+    /// let tree = Tree::new().child(vec!["A".into(), "B\nb".into()]);
+    /// let parts = Renderer::new().render_parts(&tree, true, "");
+    /// assert_eq!(parts, vec!["├── A", "└── B\n    b"]);
+    /// ```
+    pub fn render_parts(&self, node: &dyn Node, root: bool, prefix: &str) -> Vec<String> {
+        self.render_parts_mapped(node, root, prefix, &[], None)
+    }
+
+    /// Renders like [`Renderer::render`] and appends a [`LineInfo`] for every
+    /// output line to `map`. `node` is taken to be at depth 0.
+    pub(crate) fn render_mapped(
+        &self,
+        node: &dyn Node,
+        root: bool,
+        prefix: &str,
+        map: &mut Vec<LineInfo>,
+    ) -> String {
+        self.render_parts_mapped(node, root, prefix, &[], Some(map))
+            .join("\n")
+    }
+
+    /// Renders the segments of `node`, recording the lines in `map` when
+    /// given. `ancestors` holds, outermost first, whether each node above
+    /// it has a later visible sibling; its length is the node's depth.
+    fn render_parts_mapped(
+        &self,
+        node: &dyn Node,
+        root: bool,
+        prefix: &str,
+        ancestors: &[bool],
+        mut map: Option<&mut Vec<LineInfo>>,
+    ) -> Vec<String> {
         if node.hidden() {
-            return String::new();
+            return Vec::new();
         }
+        let depth = ancestors.len();
 
         // Debug: uncomment for debugging
         // eprintln!("RENDER_START: root={}, prefix='{}', prefix_len={}", root, prefix.replace('\n', "\\n"), prefix.len());
@@ -283,12 +448,22 @@ impl Renderer {
         let mut strs = Vec::new();
         let children = node.children();
         // Prefer per-node overrides for enumerator/indenter when present, otherwise use renderer config
-        let enumerator = node.get_enumerator().copied().unwrap_or(self.enumerator);
-        let indenter = node.get_indenter().copied().unwrap_or(self.indenter);
+        let enumerator = node_enumerator(node).unwrap_or_else(|| Arc::clone(&self.enumerator));
+        let indenter = node_indenter(node).unwrap_or_else(|| Arc::clone(&self.indenter));
+
+        // A node's own rules replace the ones of the trees above it.
+        let rules = node.get_style_rules().or(self.rules.as_ref());
+        let rule_style =
+            |depth: usize, node: &dyn Node| rules.and_then(|rules| rules.style_for(depth, node));
 
         // Print the root node name if it's not empty
         if !node.value().is_empty() && root {
-            strs.push(self.style.root.render(&node.value()));
+            let line = match rule_style(depth, node) {
+                Some(rule) => layer(&self.style.root, &rule).render(&node.value()),
+                None => self.style.root.render(&node.value()),
+            };
+            map_lines(map.as_deref_mut(), &line, depth);
+            strs.push(line);
         }
 
         // Build a filtered view of direct children that will render a line (non-hidden, non-empty)
@@ -373,21 +548,97 @@ impl Renderer {
         // Helper to detect built-in branch glyphs
         let is_branch = |s: &str| s == "├──" || s == "└──" || s == "╰──";
 
-        // Calculate alignment padding for custom enumerators (not built-in branch glyphs)
+        // Effective styles for this level, respecting node overrides first;
+        // functions of the node itself win over those of the siblings.
+        let (hidden_above, hidden_below) = node.offset_hidden();
+        let node_styler = |func| NodeStyler {
+            func,
+            map: &vis_children.map,
+            is_last: &is_last_vec,
+            skipped: hidden_above,
+            depth: depth + 1,
+        };
+        let enum_styler = node.get_enumerator_style_for().map(node_styler);
+        let item_styler = node.get_item_style_for().map(node_styler);
+        let own_enum_func =
+            node_enumerator_style_func(node).or_else(|| self.inherited_enum_func.clone());
+        let own_item_func = node_item_style_func(node).or_else(|| self.inherited_item_func.clone());
+        let enum_style_func: &dyn Stylize = match (&enum_styler, &own_enum_func) {
+            (Some(styler), _) => styler,
+            (None, Some(func)) => func.as_ref(),
+            (None, None) => &self.style.enumerator_func,
+        };
+        let item_style_func: &dyn Stylize = match (&item_styler, &own_item_func) {
+            (Some(styler), _) => styler,
+            (None, Some(func)) => func.as_ref(),
+            (None, None) => &self.style.item_func,
+        };
+        let enum_base = node
+            .get_enumerator_style()
+            .cloned()
+            .or_else(|| self.style.enumerator_base.clone());
+        let item_base = node
+            .get_item_style()
+            .cloned()
+            .or_else(|| self.style.item_base.clone());
+
+        // Calculate alignment padding for custom enumerators (not built-in branch glyphs).
+        // Like Go, widths are measured on the styled prefix so that padding and margins
+        // from the enumerator style are included, and multi-line prefixes count their
+        // widest line rather than their byte length.
         let mut max_enum_width = 0;
+        let alignment = node.get_enumerator_alignment().unwrap_or(RIGHT);
         for i in 0..filtered_children.length() {
-            let user_pref = enumerator(&vis_children, i);
+            let user_pref = enumerator.enumerate(&vis_children, i);
             if !is_branch(&user_pref) {
-                // Only consider custom enumerators for alignment
-                let width = user_pref.width();
-                if width > max_enum_width {
-                    max_enum_width = width;
-                }
+                let own = vis_children
+                    .at(i)
+                    .and_then(|c| c.get_node_enumerator_style());
+                let styled = Self::style_prefix(
+                    &user_pref,
+                    own.or(enum_base.as_ref()),
+                    enum_style_func,
+                    &vis_children,
+                    i,
+                );
+                max_enum_width = max_enum_width.max(width(&styled));
             }
         }
 
+        let dummy_children = crate::children::NodeChildren::new();
+        let indent_sample = indenter.indent(&dummy_children, 0);
+        // The built-in indenter is already as wide as a branch glyph plus the space after
+        // it, so a node that styles its own enumerators but keeps that indenter (a tree
+        // nested in a list, say) does not get the enumerator's right padding twice.
+        let skip_indent_padding = node.get_enumerator_style().is_some()
+            && indent_sample == default_indenter(&dummy_children, 0);
+
+        // A marker stands in for the children the offset hides, drawn in the
+        // enumerator column with the enumerator style.
+        let offset_marker = |marker: &str| {
+            let styled = Self::style_prefix(
+                marker,
+                enum_base.as_ref(),
+                enum_style_func,
+                &vis_children,
+                0,
+            );
+            let pad = max_enum_width.saturating_sub(width(&styled));
+            let left = (pad as f64 * alignment.value()).round() as usize;
+            let line_prefix =
+                Self::style_line_prefix(prefix, enum_base.as_ref(), item_base.as_ref());
+            let line = format!("{}{}{}", line_prefix, " ".repeat(left), styled);
+            line.trim_end_matches(' ').to_string()
+        };
+        if let Some(marker) = node.get_offset_marker().filter(|_| hidden_above > 0) {
+            let line = offset_marker(marker);
+            map_lines(map.as_deref_mut(), &line, depth + 1);
+            strs.push(line);
+        }
+
         // Render children
         let mut last_display_indent = String::new();
+        let mut last_has_next = false;
         for i in 0..children.length() {
             if let Some(child) = children.at(i) {
                 if child.hidden() {
@@ -408,125 +659,151 @@ impl Renderer {
                     display_idx_opt = Some(count);
                 }
                 let idx = display_idx_opt.unwrap_or(0);
-                // Build effective styles for this node, respecting node overrides first
-                let enum_style_func = node
-                    .get_enumerator_style_func()
-                    .copied()
-                    .unwrap_or(self.style.enumerator_func);
-                let item_style_func = node
-                    .get_item_style_func()
-                    .copied()
-                    .unwrap_or(self.style.item_func);
-
-                let enum_base = node
-                    .get_enumerator_style()
-                    .cloned()
-                    .or_else(|| self.style.enumerator_base.clone());
-                let item_base = node
-                    .get_item_style()
-                    .cloned()
-                    .or_else(|| self.style.item_base.clone());
-
                 // Compute indent: for visible children use indenter(filtered, display_idx);
                 // for container (empty value) nodes, reuse the last visible indent so nested
                 // content attaches under the previous item.
                 let raw_indent = if let Some(di) = display_idx_opt {
-                    indenter(&filtered_children, di)
+                    last_has_next = !is_last_vec[di];
+                    let context = IndentContext {
+                        depth,
+                        ancestor_has_next: ancestors,
+                        index: di,
+                        is_last: is_last_vec[di],
+                    };
+                    indenter.indent_with_context(&filtered_children, &context)
                 } else {
                     last_display_indent.clone()
                 };
                 // Apply styling to indent based on the type of indenter
-                let indent = if raw_indent.trim().is_empty() {
+                let raw_width = width(&raw_indent);
+                let indent = if indenter.is_list() && raw_width >= 2 {
+                    // List indenter - its first two columns stand in for Go's
+                    // `enumStyle.Render(" ")`, styling a single column with the enumerator
+                    // style so enumerator padding and margins carry over to continuation
+                    // lines and sublists, whatever the item style, and nested items line
+                    // up with their parent's text. A wider indent adds plain spaces.
+                    let enum_style = enum_base
+                        .clone()
+                        .unwrap_or_else(|| enum_style_func.style(&vis_children, idx));
+                    if enum_style.render("").trim().is_empty() {
+                        enum_style.render(" ") + &" ".repeat(raw_width - 2)
+                    } else {
+                        raw_indent.clone()
+                    }
+                } else if raw_indent.trim().is_empty() {
                     // Standard whitespace indenter - apply item_base style if present (for padding)
                     if let Some(base) = &item_base {
                         base.render(&raw_indent)
                     } else {
                         raw_indent.clone()
                     }
-                } else {
-                    // Custom indenter (like "->") - apply enum_base style if present (for colors/styling)
-                    if let Some(base) = &enum_base {
-                        base.render(&raw_indent)
+                } else if let Some(base) = &enum_base {
+                    // Custom indenter (like "->") or guide line - apply enum_base style (for colors/styling)
+                    if skip_indent_padding {
+                        base.clone().unset_padding_right().render(&raw_indent)
                     } else {
-                        raw_indent.clone()
+                        base.render(&raw_indent)
                     }
+                } else {
+                    // Without a base style the guide takes the colors the style function
+                    // gives this child's enumerator, so it stays connected to the branch
+                    // above it; the indenter already has the width, so spacing is dropped.
+                    enum_style_func
+                        .style(&vis_children, idx)
+                        .unset_padding()
+                        .unset_margins()
+                        .render(&raw_indent)
                 };
 
                 // Compute enumerator only for visible children
                 // Base branch according to position in overall visual sequence
-                let user_pref = enumerator(&vis_children, idx);
+                let user_pref = enumerator.enumerate(&vis_children, idx);
                 let is_custom_enum = !is_branch(&user_pref);
                 let mut node_prefix = if !is_custom_enum {
                     let dc = DummyChildren { len: 2 };
                     if is_last_vec[idx] {
-                        enumerator(&dc, 1)
+                        enumerator.enumerate(&dc, 1)
                     } else {
-                        enumerator(&dc, 0)
+                        enumerator.enumerate(&dc, 0)
                     }
                 } else {
                     user_pref.clone()
                 };
 
-                // Apply alignment padding for custom enumerators
-                if !is_custom_enum {
-                    // Built-in branch glyph - no alignment needed
-                } else if max_enum_width > 0 {
-                    // Custom enumerator - apply alignment padding
-                    let current_width = node_prefix.width();
-                    let padding_needed = max_enum_width.saturating_sub(current_width);
+                // A style attached to the child wins over the tree's.
+                node_prefix = Self::style_prefix(
+                    &node_prefix,
+                    child.get_node_enumerator_style().or(enum_base.as_ref()),
+                    enum_style_func,
+                    &vis_children,
+                    idx,
+                );
+
+                // Apply alignment padding for custom enumerators after styling, so the
+                // enumerator style's own padding and margins are kept intact (Go parity)
+                if is_custom_enum {
+                    let padding_needed = max_enum_width.saturating_sub(width(&node_prefix));
                     if padding_needed > 0 {
-                        node_prefix = format!("{}{}", " ".repeat(padding_needed), node_prefix);
+                        let left = (padding_needed as f64 * alignment.value()).round() as usize;
+                        let (left, right) = (" ".repeat(left), " ".repeat(padding_needed - left));
+                        node_prefix = node_prefix
+                            .split('\n')
+                            .map(|line| format!("{}{}{}", left, line, right))
+                            .collect::<Vec<_>>()
+                            .join("\n");
                     }
                 }
-
-                // CRITICAL: Apply either base style OR function style, NEVER both
-                // This prevents double-padding issues where both base and function styles add spacing
-                //
-                // PADDING_RIGHT BEHAVIOR:
-                // - TreeStyle::default() sets enumerator_func to add padding_right(1)
-                // - If enumerator_style (base) is set, it REPLACES the function entirely
-                // - The base style can include its own padding_right(1)
-                // - Go behavior: EnumeratorStyle() method replaces the default function
-                //
-                // SPACING CALCULATION:
-                // - Tree symbols: "├──", "└──" are 3 chars wide
-                // - padding_right(1) adds 1 space → "├── " (4 chars total)
-                // - This aligns with default_indenter: "│   " (4 chars) and "    " (4 spaces)
-                if let Some(base) = &enum_base {
-                    // Base style set via .enumerator_style() - use ONLY this style
-                    // Example: Style::new().foreground(color).padding_right(1)
-                    node_prefix = base.render(&node_prefix);
-                } else {
-                    // No base style - use the function style (default or custom)
-                    let enum_style_result = enum_style_func(&vis_children, idx);
-                    let enum_lead = enum_style_result.render("");
-
-                    // Check if this is a set_string style vs padding-only style
-                    if !enum_lead.is_empty() && !enum_lead.trim().is_empty() {
-                        // Set_string style with actual content (e.g., "+" prefix)
-                        // Apply default padding to tree structure, then prepend the content
-                        let default_styled = Style::new().padding_right(1).render(&node_prefix);
-                        if !enum_lead.ends_with(' ') {
-                            node_prefix = format!("{} {}", enum_lead, default_styled);
-                        } else {
-                            node_prefix = format!("{}{}", enum_lead, default_styled);
-                        }
-                    } else {
-                        // Padding-only style or no additional content
-                        // Apply style function directly to tree structure
-                        node_prefix = enum_style_result.render(&node_prefix);
-                    }
+                // Block children skip block joining and are written out line by line
+                // in a single pass, each line taking the item style a leaf would
+                if let Some(text) = child.as_block() {
+                    let base = || {
+                        item_base
+                            .clone()
+                            .unwrap_or_else(|| item_style_func.style(&vis_children, idx))
+                    };
+                    let style = match (child.get_node_style(), rule_style(depth + 1, child)) {
+                        (Some(own), Some(rule)) => layer(&rule, own),
+                        (Some(own), None) => own.clone(),
+                        (None, Some(rule)) => layer(&base(), &rule),
+                        (None, None) => base(),
+                    };
+                    let line_prefix =
+                        Self::style_line_prefix(prefix, enum_base.as_ref(), item_base.as_ref());
+                    let block =
+                        Self::render_block(text, &style, &line_prefix, &node_prefix, &indent);
+                    map_lines(map.as_deref_mut(), &block, depth + 1);
+                    strs.push(block);
+                    last_display_indent = raw_indent.clone();
+                    continue;
                 }
-                // Note: Alignment padding disabled - Go doesn't align prefixes to same width
+
                 // Debug: uncomment for debugging
                 // eprintln!("RENDER: idx={}, prefix='{}', width={}, max_len={}, final='{}'", idx, node_prefix.replace('\n', "\\n"), prefix_width, max_len, node_prefix.replace('\n', "\\n"));
                 // Apply item styling: base style OR function style, not both
                 let mut item = child.value();
-                if let Some(base) = &item_base {
+                if child.is_done() {
+                    if let Some(done) = node.get_done_style() {
+                        item = done.render(&item);
+                    }
+                }
+                if let Some(own) = child.get_node_style() {
+                    // A style attached to the child replaces the tree's and
+                    // goes on top of the rules, its own properties winning
+                    item = match rule_style(depth + 1, child) {
+                        Some(rule) => layer(&rule, own).render(&item),
+                        None => own.render(&item),
+                    };
+                } else if let Some(rule) = rule_style(depth + 1, child) {
+                    // Rules go on top of the tree's item style
+                    let base = item_base
+                        .clone()
+                        .unwrap_or_else(|| item_style_func.style(&vis_children, idx));
+                    item = layer(&base, &rule).render(&item);
+                } else if let Some(base) = &item_base {
                     item = base.render(&item);
                 } else {
                     // Only apply function style if no base style is set
-                    let item_style_result = item_style_func(&vis_children, idx);
+                    let item_style_result = item_style_func.style(&vis_children, idx);
                     let item_lead = item_style_result.render("");
 
                     // Check if this is a true set_string style vs padding-only style
@@ -546,16 +823,32 @@ impl Renderer {
                         }
                     }
                 }
+                // A description goes below the styled value, styled on its own.
+                if let Some(description) = child.description() {
+                    let description = match node.get_description_style() {
+                        Some(style) => style.render(description),
+                        None => description.to_string(),
+                    };
+                    item = format!("{}\n{}", item, description);
+                }
                 let mut multiline_prefix = prefix.to_string();
 
                 // Handle multiline prefixes and items
                 let item_height = height(&item);
                 let mut node_prefix_height = height(&node_prefix);
 
-                // Extend node prefix if item is taller
+                // Extend node prefix if item is taller. An unstyled blank indent wider
+                // than the enumerator (a wide list indent) would push the item's text
+                // right, so continuation lines are only as wide as the enumerator.
+                let extension_indent = if item_base.is_none()
+                    && raw_indent.trim().is_empty()
+                    && width(&indent) > width(&node_prefix)
+                {
+                    " ".repeat(width(&node_prefix))
+                } else {
+                    indent.clone()
+                };
                 while item_height > node_prefix_height {
-                    // Use raw indent for multiline extension - no styling needed
-                    let extension_indent = indent.clone();
                     node_prefix = join_vertical(LEFT, &[&node_prefix, &extension_indent]);
                     node_prefix_height = height(&node_prefix);
                 }
@@ -578,29 +871,11 @@ impl Renderer {
                     // When tree components have background colors, we need to ensure the multiline_prefix
                     // also gets the same background color to avoid black rectangular gaps.
 
-                    let styled_multiline_prefix = if !multiline_prefix.trim().is_empty() {
-                        // If there is indentation content, check if we should apply background styling
-                        // Priority: enum_base > item_base (prefer the enumerator background)
-                        if let Some(base) = &enum_base {
-                            // If enumerator has background, apply it to prefix indentation
-                            if base.get_background().is_some() {
-                                base.render(&multiline_prefix)
-                            } else {
-                                multiline_prefix.clone()
-                            }
-                        } else if let Some(base) = &item_base {
-                            // If item has background, apply it to prefix indentation
-                            if base.get_background().is_some() {
-                                base.render(&multiline_prefix)
-                            } else {
-                                multiline_prefix.clone()
-                            }
-                        } else {
-                            multiline_prefix.clone()
-                        }
-                    } else {
-                        multiline_prefix.clone()
-                    };
+                    let styled_multiline_prefix = Self::style_line_prefix(
+                        &multiline_prefix,
+                        enum_base.as_ref(),
+                        item_base.as_ref(),
+                    );
 
                     // DEBUG: Uncomment to debug spacing issues
                     // eprintln!("DEBUG: multiline_prefix='{}', node_prefix='{}', item='{}'",
@@ -608,8 +883,21 @@ impl Renderer {
                     //           node_prefix.replace(' ', "·"),
                     //           item.replace(' ', "·"));
 
-                    let line =
-                        join_horizontal(TOP, &[&styled_multiline_prefix, &node_prefix, &item]);
+                    // A title and its description are set line by line, so
+                    // neither is padded out to the width of the other.
+                    let line = if child.description().is_some() {
+                        let prefix =
+                            join_horizontal(TOP, &[&styled_multiline_prefix, &node_prefix]);
+                        prefix
+                            .split('\n')
+                            .zip(item.split('\n').chain(std::iter::repeat("")))
+                            .map(|(prefix, item)| format!("{}{}", prefix, item))
+                            .collect::<Vec<_>>()
+                            .join("\n")
+                    } else {
+                        join_horizontal(TOP, &[&styled_multiline_prefix, &node_prefix, &item])
+                    };
+                    map_lines(map.as_deref_mut(), &line, depth + 1);
                     strs.push(line);
                     // Remember raw indent for subsequent container nodes (before styling)
                     last_display_indent = raw_indent.clone();
@@ -623,49 +911,29 @@ impl Renderer {
                     // This ensures custom indenters (like "->") get the same styling as enumerators
                     let styled_indent = indent.clone();
 
-                    // SMART INDENTATION LOGIC:
-                    // Trees nested in lists should not inherit the list's indenter to avoid double indentation.
-                    let dummy_children = crate::children::NodeChildren::new();
-                    let parent_indent_sample = indenter(&dummy_children, 0);
-                    #[allow(unused_variables)]
-                    let is_parent_list_indenter =
-                        parent_indent_sample.trim() == "" && parent_indent_sample.len() == 2;
-
-                    let child_prefix = if let Some(child_indenter) = child.get_indenter() {
-                        let child_indent_sample = child_indenter(&dummy_children, 0);
-                        #[allow(unused_variables)]
-                        let is_child_tree_indenter =
-                            child_indent_sample.contains('│') || child_indent_sample.len() == 4;
-
-                        // DEBUG: uncomment for debugging tree indentation
-                        // if child.get_enumerator_style().is_some() {
-                        //     eprintln!("DEBUG TREE: parent_indent='{}', child_indent='{}', is_parent_list={}, is_child_tree={}, prefix_len={}",
-                        //         parent_indent_sample.replace(' ', "·"), child_indent_sample.replace(' ', "·"), is_parent_list_indenter, is_child_tree_indenter, prefix.len());
-                        // }
-
-                        // Always provide proper nesting prefix - the issue is tree's internal indentation, not prefix
-                        format!("{}{}", prefix, styled_indent)
-                    } else {
-                        // Child has no specific indenter - inherit parent's indentation
-                        format!("{}{}", prefix, styled_indent)
-                    };
+                    // Trees nested in lists start at the list's indentation and keep
+                    // their own branch layout.
+                    let child_prefix = format!("{}{}", prefix, styled_indent);
 
                     // Detect if child has style overrides (indicating it's a styled tree vs plain container)
                     let has_style_overrides = child.get_enumerator_style().is_some()
                         || child.get_item_style().is_some()
-                        || child.get_enumerator_style_func().is_some()
-                        || child.get_item_style_func().is_some();
+                        || node_enumerator_style_func(child).is_some()
+                        || node_item_style_func(child).is_some()
+                        || child.get_enumerator_style_for().is_some()
+                        || child.get_item_style_for().is_some();
 
                     // Special case: if this child is a tree with its own indenter, use fresh renderer
                     // to prevent list indenter from affecting tree's internal rendering
-                    let child_uses_tree_indenter =
-                        if let Some(child_indenter) = child.get_indenter() {
-                            let dummy = crate::children::NodeChildren::new();
-                            let sample = child_indenter(&dummy, 0);
-                            sample.contains('│') || sample.len() == 4
-                        } else {
-                            false
-                        };
+                    let child_indenter = node_indenter(child);
+                    let child_uses_tree_indenter = child_indenter.as_ref().is_some_and(|i| {
+                        let sample = i.indent(&dummy_children, 0);
+                        !i.is_list() && (sample.contains('│') || sample.len() == 4)
+                    });
+                    // A tree under a list keeps its own branch spacing rather than
+                    // inheriting the padding that follows the list's markers.
+                    let tree_in_list =
+                        indenter.is_list() && child_indenter.as_ref().is_some_and(|i| !i.is_list());
 
                     let mut child_renderer = if child_uses_tree_indenter {
                         // Tree child: use fresh renderer to avoid inheriting list behavior
@@ -676,43 +944,65 @@ impl Renderer {
                     } else {
                         // Child has no overrides: inherit parent's behavior
                         Renderer::new()
-                            .enumerator(self.enumerator)
-                            .indenter(self.indenter)
+                            .shared_enumerator(Arc::clone(&self.enumerator))
+                            .shared_indenter(Arc::clone(&self.indenter))
                     };
 
                     // Apply any explicit functional overrides from the child node itself
-                    if let Some(e) = child.get_enumerator() {
-                        child_renderer = child_renderer.enumerator(*e);
+                    if let Some(e) = node_enumerator(child) {
+                        child_renderer = child_renderer.shared_enumerator(e);
                     }
-                    if let Some(i) = child.get_indenter() {
-                        child_renderer = child_renderer.indenter(*i);
+                    if let Some(i) = child_indenter {
+                        child_renderer = child_renderer.shared_indenter(i);
                     }
 
-                    // For style functions, use tree defaults unless child has specific overrides
-                    // Children should inherit parent styles when they don't have their own
+                    // Style functions fall back to the tree defaults; the child's own
+                    // functions are read from the node when it renders.
                     let style = TreeStyle {
-                        enumerator_func: child
-                            .get_enumerator_style_func()
-                            .copied()
-                            .unwrap_or(|_, _| Style::new().padding_right(1)),
-                        item_func: child
-                            .get_item_style_func()
-                            .copied()
-                            .unwrap_or(|_, _| Style::new()),
+                        enumerator_func: |_, _| Style::new().padding_right(1),
+                        item_func: |_, _| Style::new(),
                         root: Style::default(),
-                        // Inherit parent's base styles if child doesn't have overrides
-                        enumerator_base: child
-                            .get_enumerator_style()
-                            .cloned()
-                            .or_else(|| self.style.enumerator_base.clone()),
-                        item_base: child
-                            .get_item_style()
-                            .cloned()
-                            .or_else(|| self.style.item_base.clone()),
+                        // Inherit parent's base styles if child doesn't have overrides;
+                        // a child's own style function counts as an override
+                        enumerator_base: child.get_enumerator_style().cloned().or_else(|| {
+                            if tree_in_list
+                                || node_enumerator_style_func(child).is_some()
+                                || child.get_enumerator_style_for().is_some()
+                            {
+                                None
+                            } else {
+                                self.style.enumerator_base.clone()
+                            }
+                        }),
+                        item_base: child.get_item_style().cloned().or_else(|| {
+                            if node_item_style_func(child).is_some()
+                                || child.get_item_style_for().is_some()
+                            {
+                                None
+                            } else {
+                                self.style.item_base.clone()
+                            }
+                        }),
                     };
-                    child_renderer = child_renderer.style(style);
+                    child_renderer = child_renderer.style(style).shared_rules(rules.cloned());
+                    // Like Go, a subtree that sets nothing of its own is drawn by
+                    // the parent's renderer, style functions included.
+                    if !has_style_overrides {
+                        child_renderer = child_renderer
+                            .shared_style_funcs(own_enum_func.clone(), own_item_func.clone());
+                    }
 
-                    let mut child_output = child_renderer.render(child, false, &child_prefix);
+                    let child_ancestors: Vec<bool> =
+                        ancestors.iter().copied().chain([last_has_next]).collect();
+                    let mut child_output = child_renderer
+                        .render_parts_mapped(
+                            child,
+                            false,
+                            &child_prefix,
+                            &child_ancestors,
+                            map.as_deref_mut(),
+                        )
+                        .join("\n");
                     // If this child is an unnamed container and there are later siblings that
                     // will render visible lines, ensure the container's last visible branch uses
                     // the mid-branch glyph to visually continue the vertical line across
@@ -732,8 +1022,8 @@ impl Renderer {
                         if future_exists {
                             // Replace the deepest last-branch at this level with a mid-branch.
                             let dc = DummyChildren { len: 2 };
-                            let last_branch = enumerator(&dc, 1);
-                            let mid_branch = enumerator(&dc, 0);
+                            let last_branch = enumerator.enumerate(&dc, 1);
+                            let mid_branch = enumerator.enumerate(&dc, 0);
                             let look_for = format!("{}{}", child_prefix, last_branch);
                             if let Some(pos) = child_output.rfind(&look_for) {
                                 // Ensure we are at line start; find preceding newline or start
@@ -755,7 +1045,119 @@ impl Renderer {
             }
         }
 
-        strs.join("\n")
+        if let Some(marker) = node.get_offset_marker().filter(|_| hidden_below > 0) {
+            let line = offset_marker(marker);
+            map_lines(map, &line, depth + 1);
+            strs.push(line);
+        }
+
+        strs
+    }
+
+    /// Applies the background of the enumerator (or item) base style to the
+    /// parent indentation in front of a line, so styled trees have no gaps.
+    fn style_line_prefix(
+        prefix: &str,
+        enum_base: Option<&Style>,
+        item_base: Option<&Style>,
+    ) -> String {
+        if prefix.trim().is_empty() {
+            return prefix.to_string();
+        }
+        // Priority: enum_base > item_base (prefer the enumerator background)
+        match enum_base.or(item_base) {
+            Some(base) if base.get_background().is_some() => base.render(prefix),
+            _ => prefix.to_string(),
+        }
+    }
+
+    /// Renders a block child: the first line after the node prefix, every
+    /// further line after the continuation indent, each line in `style`.
+    ///
+    /// The output is sized up front and built in a single allocation, so the
+    /// cost is linear in the size of the block.
+    fn render_block(
+        text: &str,
+        style: &Style,
+        line_prefix: &str,
+        node_prefix: &str,
+        indent: &str,
+    ) -> String {
+        let prefix_lines: Vec<&str> = node_prefix.split('\n').collect();
+        let prefix_width = width(node_prefix);
+        let indent_pad = " ".repeat(prefix_width.saturating_sub(width(indent)));
+        let line_count = text.split('\n').count();
+        let per_line = line_prefix.len() + node_prefix.len().max(indent.len() + indent_pad.len());
+
+        let mut out = String::with_capacity(text.len() + line_count * (per_line + 1));
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                out.push('\n');
+            }
+            out.push_str(line_prefix);
+            match prefix_lines.get(i) {
+                Some(p) => {
+                    out.push_str(p);
+                    let pad = prefix_width.saturating_sub(width(p));
+                    out.extend(std::iter::repeat_n(' ', pad));
+                }
+                None => {
+                    out.push_str(indent);
+                    out.push_str(&indent_pad);
+                }
+            }
+            out.push_str(&style.render(line));
+        }
+        out
+    }
+
+    /// Applies the enumerator style to a raw enumerator prefix.
+    ///
+    /// Either the base style or the style function is applied, never both, which
+    /// prevents double padding when both would add spacing.
+    ///
+    /// PADDING_RIGHT BEHAVIOR:
+    /// - TreeStyle::default() sets enumerator_func to add padding_right(1)
+    /// - If enumerator_style (base) is set, it REPLACES the function entirely
+    /// - The base style can include its own padding_right(1)
+    /// - Go behavior: EnumeratorStyle() method replaces the default function
+    ///
+    /// SPACING CALCULATION:
+    /// - Tree symbols: "├──", "└──" are 3 chars wide
+    /// - padding_right(1) adds 1 space → "├── " (4 chars total)
+    /// - This aligns with default_indenter: "│   " (4 chars) and "    " (4 spaces)
+    fn style_prefix(
+        prefix: &str,
+        enum_base: Option<&Style>,
+        enum_style_func: &dyn Stylize,
+        children: &dyn Children,
+        idx: usize,
+    ) -> String {
+        if let Some(base) = enum_base {
+            // Base style set via .enumerator_style() - use ONLY this style
+            // Example: Style::new().foreground(color).padding_right(1)
+            return base.render(prefix);
+        }
+
+        // No base style - use the function style (default or custom)
+        let enum_style_result = enum_style_func.style(children, idx);
+        let enum_lead = enum_style_result.render("");
+
+        // Check if this is a set_string style vs padding-only style
+        if !enum_lead.is_empty() && !enum_lead.trim().is_empty() {
+            // Set_string style with actual content (e.g., "+" prefix)
+            // Apply default padding to tree structure, then prepend the content
+            let default_styled = Style::new().padding_right(1).render(prefix);
+            if !enum_lead.ends_with(' ') {
+                format!("{} {}", enum_lead, default_styled)
+            } else {
+                format!("{}{}", enum_lead, default_styled)
+            }
+        } else {
+            // Padding-only style or no additional content
+            // Apply style function directly to tree structure
+            enum_style_result.render(prefix)
+        }
     }
 }
 
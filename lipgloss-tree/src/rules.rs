@@ -0,0 +1,237 @@
+//! Styling nodes by selectors on their depth, value and shape.
+//!
+//! [`Tree::style_rules`] takes a list of [`Rule`]s, each pairing a
+//! [`Matcher`] with a style. Every node value that a rule matches gets its
+//! style, later rules overriding earlier ones, which makes trees easy to
+//! theme from configuration:
+//!
+//! ```rust
+//! use lipgloss::Style;
+//! use lipgloss_tree::{children, Matcher, Rule, Tree};
+//!
+//! let tree = Tree::new()
+//!     .root("build")
+//!     .child(children!["compile", "error: missing file"])
+//!     .style_rules(vec![
+//!         Rule::new(Matcher::Depth(0), Style::new().bold(true)),
+//!         Rule::new(Matcher::parse("leaf contains:error").unwrap(), Style::new().foreground("9")),
+//!     ]);
+//! # let _ = tree.to_string();
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use lipgloss::Style;
+
+use crate::children::Node;
+
+/// Selects the nodes a [`Rule`] styles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Matcher {
+    /// Nodes at this depth: 0 for the root, 1 for its children and so on.
+    Depth(usize),
+    /// Nodes whose value contains this text.
+    ValueContains(String),
+    /// Nodes without children to show.
+    IsLeaf,
+    /// Nodes with children to show.
+    IsBranch,
+    /// Nodes matched by every one of these matchers.
+    All(Vec<Matcher>),
+}
+
+impl Matcher {
+    /// Parses a selector of space-separated terms, all of which must match.
+    ///
+    /// The terms are `depth:N`, `contains:TEXT`, `leaf` and `branch`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MatcherParseError`] holding the first term that is not
+    /// one of these, or an empty one if the selector has no terms.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::Matcher;
+    ///
+    /// assert_eq!(
+    ///     Matcher::parse("depth:2 leaf").unwrap(),
+    ///     Matcher::All(vec![Matcher::Depth(2), Matcher::IsLeaf])
+    /// );
+    /// assert_eq!(Matcher::parse("branch").unwrap(), Matcher::IsBranch);
+    /// assert_eq!(Matcher::parse("depth:two").unwrap_err().term, "depth:two");
+    /// ```
+    pub fn parse(selector: &str) -> Result<Matcher, MatcherParseError> {
+        let mut matchers = selector
+            .split_whitespace()
+            .map(|term| {
+                let matcher = match term.split_once(':') {
+                    None if term == "leaf" => Some(Matcher::IsLeaf),
+                    None if term == "branch" => Some(Matcher::IsBranch),
+                    Some(("depth", depth)) => depth.parse().ok().map(Matcher::Depth),
+                    Some(("contains", text)) if !text.is_empty() => {
+                        Some(Matcher::ValueContains(text.to_string()))
+                    }
+                    _ => None,
+                };
+                matcher.ok_or_else(|| MatcherParseError {
+                    term: term.to_string(),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        match matchers.len() {
+            0 => Err(MatcherParseError {
+                term: String::new(),
+            }),
+            1 => Ok(matchers.remove(0)),
+            _ => Ok(Matcher::All(matchers)),
+        }
+    }
+
+    /// Whether this matcher selects `node`, drawn at `depth`.
+    pub fn matches(&self, depth: usize, node: &dyn Node) -> bool {
+        match self {
+            Matcher::Depth(d) => *d == depth,
+            Matcher::ValueContains(text) => node.value().contains(text.as_str()),
+            Matcher::IsLeaf => node.children().length() == 0,
+            Matcher::IsBranch => node.children().length() > 0,
+            Matcher::All(matchers) => matchers.iter().all(|m| m.matches(depth, node)),
+        }
+    }
+
+    /// The only depth this matcher can select, if it is limited to one.
+    fn depth(&self) -> Option<usize> {
+        match self {
+            Matcher::Depth(depth) => Some(*depth),
+            Matcher::All(matchers) => matchers.iter().find_map(Matcher::depth),
+            _ => None,
+        }
+    }
+}
+
+/// MatcherParseError is returned when [`Matcher::parse`] meets a term it
+/// does not know.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MatcherParseError {
+    /// The offending term, empty for a selector without terms.
+    pub term: String,
+}
+
+impl fmt::Display for MatcherParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.term.is_empty() {
+            write!(f, "empty selector")
+        } else {
+            write!(f, "unknown selector term {:?}", self.term)
+        }
+    }
+}
+
+impl std::error::Error for MatcherParseError {}
+
+/// A style for the nodes a [`Matcher`] selects, see [`Tree::style_rules`].
+///
+/// [`Tree::style_rules`]: crate::Tree::style_rules
+#[derive(Debug, Clone)]
+pub struct Rule {
+    /// Which nodes the rule styles.
+    pub matcher: Matcher,
+    /// The style given to their values.
+    pub style: Style,
+}
+
+impl Rule {
+    /// Creates a rule giving `style` to the nodes `matcher` selects.
+    pub fn new(matcher: Matcher, style: Style) -> Self {
+        Self { matcher, style }
+    }
+}
+
+/// Rules ready for rendering, see [`Tree::style_rules`].
+///
+/// Rules limited to one depth are bucketed by it, so a node is only tested
+/// against the rules that can match at its depth.
+///
+/// [`Tree::style_rules`]: crate::Tree::style_rules
+#[derive(Debug, Clone)]
+pub struct StyleRules {
+    rules: Vec<Rule>,
+    /// Indices of the rules limited to each depth.
+    by_depth: HashMap<usize, Vec<usize>>,
+    /// Indices of the rules for any depth.
+    any_depth: Vec<usize>,
+}
+
+impl From<Vec<Rule>> for StyleRules {
+    fn from(rules: Vec<Rule>) -> Self {
+        let mut by_depth: HashMap<usize, Vec<usize>> = HashMap::new();
+        let mut any_depth = Vec::new();
+        for (i, rule) in rules.iter().enumerate() {
+            match rule.matcher.depth() {
+                Some(depth) => by_depth.entry(depth).or_default().push(i),
+                None => any_depth.push(i),
+            }
+        }
+        Self {
+            rules,
+            by_depth,
+            any_depth,
+        }
+    }
+}
+
+impl StyleRules {
+    /// The rules in the order they apply.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// The styles of the rules matching `node` at `depth` layered in order,
+    /// or `None` if no rule matches.
+    pub(crate) fn style_for(&self, depth: usize, node: &dyn Node) -> Option<Style> {
+        let at_depth = self.by_depth.get(&depth).map_or(&[][..], Vec::as_slice);
+        if at_depth.is_empty() && self.any_depth.is_empty() {
+            return None;
+        }
+        let mut candidates: Vec<usize> = at_depth.iter().chain(&self.any_depth).copied().collect();
+        candidates.sort_unstable();
+
+        candidates
+            .into_iter()
+            .map(|i| &self.rules[i])
+            .filter(|rule| rule.matcher.matches(depth, node))
+            .fold(None, |acc, rule| {
+                Some(match acc {
+                    Some(under) => layer(&under, &rule.style),
+                    None => rule.style.clone(),
+                })
+            })
+    }
+}
+
+/// `over` on top of `under`: properties set on `over` win, the others come
+/// from `under`, including padding and margins, which [`Style::inherit`]
+/// leaves out.
+pub(crate) fn layer(under: &Style, over: &Style) -> Style {
+    let pick = |over: (i32, i32, i32, i32), under: (i32, i32, i32, i32)| {
+        let side = |o: i32, u: i32| if o != 0 { o } else { u };
+        (
+            side(over.0, under.0),
+            side(over.1, under.1),
+            side(over.2, under.2),
+            side(over.3, under.3),
+        )
+    };
+    let mut style = over.clone().inherit(under.clone());
+    let padding = pick(over.get_padding(), under.get_padding());
+    if padding != (0, 0, 0, 0) {
+        style = style.padding(padding.0, padding.1, padding.2, padding.3);
+    }
+    let margin = pick(over.get_margin(), under.get_margin());
+    if margin != (0, 0, 0, 0) {
+        style = style.margin(margin.0, margin.1, margin.2, margin.3);
+    }
+    style
+}
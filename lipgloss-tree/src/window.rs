@@ -0,0 +1,110 @@
+//! Rendering a range of lines of a tree, for fixed-height viewports.
+//!
+//! [`Tree::render_window`] renders only the requested lines and can nudge the
+//! range so a subtree root is never cut off from its first child, which
+//! looks broken when the root ends up as the last visible line:
+//!
+//! ```text
+//! ├── done
+//! └── pending      <- last visible line, children clipped
+//! ```
+
+use crate::children::Tree;
+use crate::renderer::LineInfo;
+
+/// Options for [`Tree::render_window`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss_tree::WindowOpts;
+///
+/// let opts = WindowOpts {
+///     avoid_orphan_roots: true,
+/// };
+/// assert_ne!(opts, WindowOpts::default());
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WindowOpts {
+    /// Shift the window up by one line when it would start or end between a
+    /// subtree root and its first child.
+    pub avoid_orphan_roots: bool,
+}
+
+impl Tree {
+    /// Renders the output lines `first_line..first_line + line_count`.
+    ///
+    /// The lines are exactly those of the full rendering, so a viewport can
+    /// scroll by changing `first_line`. A range running past the end is cut
+    /// short, and one starting past the end renders nothing.
+    ///
+    /// With [`WindowOpts::avoid_orphan_roots`] set, a window whose last line
+    /// is a subtree root with its first child just below, or whose first line
+    /// is a first child with its root just above, is shifted up by one line:
+    /// the root moves to the next window in the first case and into this one
+    /// in the second. A window already at the top is not moved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss_tree::{Tree, WindowOpts};
+    ///
+    /// let tree = Tree::new().root("Root").child(vec![
+    ///     "done".into(),
+    ///     Tree::new().root("pending").child(vec!["a".into(), "b".into()]).into(),
+    /// ]);
+    ///
+    /// // Lines 0..3 end on "pending" with its children clipped.
+    /// assert_eq!(
+    ///     tree.render_window(0, 3, WindowOpts::default()),
+    ///     "Root\n├── done\n└── pending"
+    /// );
+    ///
+    /// let opts = WindowOpts {
+    ///     avoid_orphan_roots: true,
+    /// };
+    /// // Ending on "pending": it moves to the next window instead.
+    /// assert_eq!(tree.render_window(1, 2, opts), "Root\n├── done");
+    /// // Starting on its first child: "pending" is pulled in above it.
+    /// assert_eq!(
+    ///     tree.render_window(3, 2, opts),
+    ///     "└── pending\n    ├── a"
+    /// );
+    /// ```
+    pub fn render_window(&self, first_line: usize, line_count: usize, opts: WindowOpts) -> String {
+        let mut map = Vec::new();
+        let output = self.renderer().render_mapped(self, true, "", &mut map);
+        if output.is_empty() || line_count == 0 {
+            return String::new();
+        }
+        let lines: Vec<&str> = output.split('\n').collect();
+        debug_assert_eq!(lines.len(), map.len());
+
+        let mut first = first_line;
+        if opts.avoid_orphan_roots && first > 0 && first < lines.len() {
+            let last = first.saturating_add(line_count - 1);
+            let splits_start = opens_children(&map, first - 1);
+            let splits_end = last.saturating_add(1) < lines.len() && opens_children(&map, last);
+            if splits_start || splits_end {
+                first -= 1;
+            }
+        }
+
+        lines
+            .iter()
+            .skip(first)
+            .take(line_count)
+            .copied()
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Whether line `line` is followed by the first line of a child of the node
+/// it belongs to.
+fn opens_children(map: &[LineInfo], line: usize) -> bool {
+    match (map.get(line), map.get(line + 1)) {
+        (Some(current), Some(next)) => next.first && next.depth == current.depth + 1,
+        _ => false,
+    }
+}
@@ -1,4 +1,6 @@
-use lipgloss_tree::{rounded_enumerator, Leaf, Tree};
+use std::sync::{Arc, Mutex};
+
+use lipgloss_tree::{children, rounded_enumerator, Children, Enumerate, Indent, Leaf, Tree};
 
 #[test]
 fn test_basic_tree() {
@@ -73,3 +75,664 @@ fn test_tree_offset() {
     assert!(output.contains("└── Child 4"));
     assert!(!output.contains("Child 5")); // Skipped by offset
 }
+
+type OffsetCase = ((usize, usize), (usize, usize), Vec<&'static str>);
+
+fn offset_fixture(outer: (usize, usize), inner: (usize, usize)) -> Tree {
+    Tree::new()
+        .root("Root")
+        .add_child("A")
+        .add_child(Leaf::new("h1", true))
+        .add_child("B")
+        .add_child(
+            Tree::new()
+                .root("Sub")
+                .add_child(Leaf::new("hx", true))
+                .add_child("x")
+                .add_child("y")
+                .add_child(Leaf::new("hy", true))
+                .add_child("z")
+                .offset(inner.0, inner.1),
+        )
+        .add_child(Leaf::new("h2", true))
+        .add_child("C")
+        .offset(outer.0, outer.1)
+}
+
+#[test]
+fn test_tree_offset_counts_visible_children_only() {
+    // (outer offset, inner offset, values expected to render in order)
+    let cases: Vec<OffsetCase> = vec![
+        ((0, 0), (0, 0), vec!["A", "B", "Sub", "x", "y", "z", "C"]),
+        ((1, 0), (0, 0), vec!["B", "Sub", "x", "y", "z", "C"]),
+        ((0, 1), (0, 0), vec!["A", "B", "Sub", "x", "y", "z"]),
+        ((2, 0), (0, 0), vec!["Sub", "x", "y", "z", "C"]),
+        ((1, 1), (1, 0), vec!["B", "Sub", "y", "z"]),
+        ((0, 0), (0, 1), vec!["A", "B", "Sub", "x", "y", "C"]),
+        ((0, 0), (1, 1), vec!["A", "B", "Sub", "y", "C"]),
+        ((2, 1), (2, 0), vec!["Sub", "z"]),
+        ((0, 0), (3, 0), vec!["A", "B", "Sub", "C"]),
+        ((4, 0), (0, 0), vec![]),
+        ((3, 3), (0, 0), vec![]),
+    ];
+
+    for (outer, inner, want) in cases {
+        let output = offset_fixture(outer, inner).to_string();
+        let got: Vec<&str> = output
+            .lines()
+            .skip(1) // root
+            .map(|line| line.trim_start_matches(['│', '├', '└', '─', ' ']))
+            .collect();
+        assert_eq!(got, want, "outer={:?} inner={:?}\n{}", outer, inner, output);
+        assert!(!output.contains('h'), "hidden leaf rendered:\n{}", output);
+    }
+}
+
+#[test]
+fn test_tree_offset_before_children_added() {
+    // Offsets are resolved at render time, not clamped when set.
+    let tree =
+        Tree::new()
+            .root("Root")
+            .offset(1, 1)
+            .child(vec!["A".into(), "B".into(), "C".into()]);
+    assert_eq!(tree.to_string(), "Root\n└── B");
+}
+
+#[test]
+fn test_block_child_renders_large_output_linearly() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    // Counts the enumerator and indenter calls made while rendering a block
+    // of `lines` lines. A block is one child, so the count must not grow
+    // with its length.
+    let render = |lines: usize| {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let (enum_calls, indent_calls) = (calls.clone(), calls.clone());
+        let text = (0..lines)
+            .map(|i| format!("log line {}", i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let tree = Tree::new()
+            .root("$ tail build.log")
+            .block_child(&text)
+            .child(vec!["done".into()])
+            .enumerator(move |children, i| {
+                enum_calls.fetch_add(1, Ordering::Relaxed);
+                if i + 1 == children.length() {
+                    "└──".to_string()
+                } else {
+                    "├──".to_string()
+                }
+            })
+            .indenter(move |children, i| {
+                indent_calls.fetch_add(1, Ordering::Relaxed);
+                if i + 1 == children.length() {
+                    "    ".to_string()
+                } else {
+                    "│   ".to_string()
+                }
+            });
+        let out = tree.to_string();
+        (calls.load(Ordering::Relaxed), out)
+    };
+
+    let (small_calls, _) = render(10);
+    let (calls, out) = render(10_000);
+    assert_eq!(calls, small_calls, "work grew with the block's length");
+
+    let lines: Vec<&str> = out.lines().collect();
+    assert_eq!(lines.len(), 10_002);
+    assert_eq!(lines[1], "├── log line 0");
+    assert_eq!(lines[10_000], "│   log line 9999");
+    assert_eq!(lines[10_001], "└── done");
+}
+
+#[test]
+fn test_block_child_matches_leaf_for_equal_width_lines() {
+    let text = "aaa\nbbb\nccc";
+    let block = Tree::new()
+        .root("R")
+        .block_child(text)
+        .child(vec!["x".into()]);
+    let leaf = Tree::new()
+        .root("R")
+        .child(vec![Leaf::new(text, false).into(), "x".into()]);
+    assert_eq!(block.to_string(), leaf.to_string());
+}
+
+#[test]
+fn test_block_child_takes_item_style() {
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+    use lipgloss::Style;
+
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::ANSI);
+    let style = Style::new().renderer(r).foreground("1");
+    let block = Tree::new()
+        .root("R")
+        .block_child("aaa\nbbb")
+        .item_style(style.clone());
+    let leaf = Tree::new()
+        .root("R")
+        .child(vec![Leaf::new("aaa\nbbb", false).into()])
+        .item_style(style);
+    assert_eq!(block.to_string(), leaf.to_string());
+    assert_eq!(
+        block.to_string(),
+        "R\n└── \x1b[31maaa\x1b[0m\n    \x1b[31mbbb\x1b[0m"
+    );
+}
+
+struct Glyphs {
+    mid: &'static str,
+    last: &'static str,
+}
+
+impl Enumerate for Glyphs {
+    fn enumerate(&self, children: &dyn Children, index: usize) -> String {
+        if index + 1 == children.length() {
+            self.last.to_string()
+        } else {
+            self.mid.to_string()
+        }
+    }
+}
+
+impl Indent for Glyphs {
+    fn indent(&self, children: &dyn Children, index: usize) -> String {
+        if index + 1 == children.length() {
+            "   ".to_string()
+        } else {
+            "|  ".to_string()
+        }
+    }
+}
+
+#[test]
+fn test_struct_enumerator_and_indenter() {
+    let glyphs = || Glyphs {
+        mid: "|-",
+        last: "`-",
+    };
+    let tree = Tree::new()
+        .root("R")
+        .child(vec![
+            "a".into(),
+            Tree::new()
+                .root("b")
+                .child(vec!["c".into()])
+                .enumerator_impl(glyphs())
+                .indenter_impl(glyphs())
+                .into(),
+            "d".into(),
+        ])
+        .enumerator_impl(glyphs())
+        .indenter_impl(glyphs());
+    assert_eq!(tree.to_string(), "R\n|- a\n|- b\n|  `- c\n`- d");
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_deprecated_getters_return_function_pointers() {
+    let plus: lipgloss_tree::Enumerator = |_, _| "+".to_string();
+    let tree = Tree::new().enumerator(plus);
+    assert_eq!(
+        tree.get_enumerator().map(|f| *f as usize),
+        Some(plus as usize)
+    );
+    assert!(tree.get_enumerator_impl().is_some());
+
+    // Only function pointers can be handed out as `&fn`.
+    let tree = Tree::new().enumerator(|_, _| "+".to_string());
+    assert!(tree.get_enumerator().is_none());
+    assert!(tree.get_enumerator_impl().is_some());
+    assert!(Tree::new()
+        .enumerator_impl(Glyphs { mid: "", last: "" })
+        .get_enumerator()
+        .is_none());
+}
+
+/// A node that still overrides the deprecated `&fn` getter.
+#[derive(Clone)]
+struct LegacyNode(Tree);
+
+impl std::fmt::Display for LegacyNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[allow(deprecated)]
+impl lipgloss_tree::Node for LegacyNode {
+    fn value(&self) -> String {
+        self.0.value()
+    }
+    fn children(&self) -> Box<dyn Children> {
+        self.0.children()
+    }
+    fn hidden(&self) -> bool {
+        false
+    }
+    fn set_hidden(&mut self, _hidden: bool) {}
+    fn set_value(&mut self, _value: String) {}
+    fn get_enumerator(&self) -> Option<&lipgloss_tree::Enumerator> {
+        const PLUS: lipgloss_tree::Enumerator = |_, _| "+".to_string();
+        Some(&PLUS)
+    }
+}
+
+#[test]
+fn test_renderer_honors_deprecated_node_getters() {
+    let legacy = LegacyNode(Tree::new().root("b").child(vec!["c".into()]));
+    let tree = Tree::new()
+        .root("R")
+        .child(vec![Box::new(legacy) as Box<dyn lipgloss_tree::Node>]);
+    assert_eq!(tree.to_string(), "R\n└── b\n    + c");
+}
+
+#[test]
+fn test_struct_enumerator_matches_equivalent_function() {
+    let build = |tree: Tree| tree.root("R").child(vec!["a".into(), "b".into()]);
+    let with_struct = build(Tree::new().enumerator_impl(Glyphs {
+        mid: "├──",
+        last: "╰──",
+    }));
+    let with_fn = build(Tree::new().enumerator(rounded_enumerator));
+    assert_eq!(with_struct.to_string(), with_fn.to_string());
+}
+
+#[test]
+fn test_enumerator_padding_not_added_to_indent() {
+    let tree = Tree::new()
+        .enumerator_style(lipgloss::Style::new().padding_right(1))
+        .child(vec![
+            Tree::new().root("multi\nline").into(),
+            Tree::new().root("sub").child(vec!["leaf".into()]).into(),
+        ]);
+    assert_eq!(
+        tree.to_string(),
+        "├── multi\n│   line \n└── sub\n    └── leaf"
+    );
+}
+
+/// Builds the same nested tree with a given indenter at every level.
+fn nested_tree(with: &dyn Fn(Tree) -> Tree) -> Tree {
+    with(Tree::new().root("R").child(vec![
+        with(Tree::new().root("a").child(vec![
+            "a1".into(),
+            with(Tree::new().root("a2").child(vec!["deep".into()])).into(),
+        ]))
+        .into(),
+        "b".into(),
+        with(Tree::new().root("c").child(vec!["c1".into()])).into(),
+    ]))
+}
+
+#[test]
+fn test_context_indenter_reproduces_default_output() {
+    let default = nested_tree(&|t| t);
+    let context = nested_tree(&|t| {
+        t.indenter_with_context(|ctx| if ctx.is_last { "    " } else { "│   " }.to_string())
+    });
+    assert_eq!(context.to_string(), default.to_string());
+}
+
+#[test]
+fn test_context_indenter_sees_depth_and_ancestors() {
+    // Each column shows its depth and, for the deepest, the ancestors.
+    let tree = nested_tree(&|t| {
+        t.indenter_with_context(|ctx| {
+            let ancestors: String = ctx
+                .ancestor_has_next
+                .iter()
+                .map(|&next| if next { '+' } else { '-' })
+                .collect();
+            assert_eq!(ctx.ancestor_has_next.len(), ctx.depth);
+            format!(
+                "{}{}{} ",
+                ctx.depth,
+                ancestors,
+                if ctx.is_last { '.' } else { ':' }
+            )
+        })
+    });
+    assert_eq!(
+        tree.to_string(),
+        "R\n\
+         ├── a\n\
+         0: ├── a1\n\
+         0: └── a2\n\
+         0: 1+. └── deep\n\
+         ├── b\n\
+         └── c\n\
+         0. └── c1"
+    );
+}
+
+#[test]
+fn test_render_prefixed_keeps_prefix_outside_styling() {
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+    use lipgloss::Style;
+
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::TrueColor);
+    let tree = Tree::new()
+        .root("Root")
+        .child(vec![
+            "plain".into(),
+            // A span left open across a line break.
+            "\x1b[1mspans\ntwo lines\x1b[0m".into(),
+            "last".into(),
+        ])
+        .enumerator_style(Style::new().renderer(r.clone()).foreground("#ff0000"))
+        .root_style(Style::new().renderer(r).italic(true));
+
+    let plain = tree.to_string();
+    let prefixed = tree.render_prefixed("// ");
+    assert_eq!(prefixed.lines().count(), plain.lines().count());
+
+    // Whether SGR attributes are still in effect at the end of a line.
+    fn open_at_end(line: &str) -> bool {
+        let mut open = false;
+        for (i, _) in line.match_indices("\x1b[") {
+            let seq = &line[i..i + line[i..].find('m').unwrap() + 1];
+            open = !(seq == "\x1b[m" || seq == "\x1b[0m");
+        }
+        open
+    }
+    // The join closes the span before the first line's padding and
+    // reopens it on the second.
+    assert!(
+        plain.lines().any(|l| l.contains("\x1b[1mtwo lines")),
+        "the span should carry over to its second line: {:?}",
+        plain
+    );
+    for (line, original) in prefixed.lines().zip(plain.lines()) {
+        assert!(line.starts_with("// "), "{:?}", line);
+        assert!(!open_at_end(line), "{:?}", line);
+        assert_eq!(
+            lipgloss::strip_ansi(line),
+            format!("// {}", lipgloss::strip_ansi(original))
+        );
+    }
+
+    let mut written = String::new();
+    tree.render_prefixed_to(&mut written, "// ").unwrap();
+    assert_eq!(written, prefixed);
+}
+
+#[test]
+fn test_style_for_receives_the_rendered_node() {
+    use lipgloss::Style;
+    use lipgloss_tree::{Node, NodeContext};
+    use std::sync::{Arc, Mutex};
+
+    type Seen = Arc<Mutex<Vec<(String, NodeContext)>>>;
+    let items: Seen = Arc::default();
+    let enumerators: Seen = Arc::default();
+    let record = |seen: &Seen| {
+        let seen = Arc::clone(seen);
+        move |node: &dyn Node, ctx: NodeContext| {
+            seen.lock().unwrap().push((node.value(), ctx));
+            Style::new()
+        }
+    };
+    let tree = Tree::new()
+        .root("Root")
+        .child(vec![
+            Box::new(Leaf::new("A", false)) as Box<dyn Node>,
+            Box::new(Leaf::new("B", false)),
+            Box::new(Leaf::new("Hidden", true)),
+            Box::new(Leaf::new("C", false)),
+            Box::new(
+                Tree::new()
+                    .root("D")
+                    .child(children!["E", "F"])
+                    .offset(1, 0),
+            ),
+            Box::new(Leaf::new("G", false)),
+        ])
+        .offset(1, 1)
+        .item_style_for(record(&items))
+        .enumerator_style_for(record(&enumerators));
+
+    let output = tree.to_string();
+    assert_eq!(output, "Root\n├──B\n├──C\n└──D\n    └── F");
+
+    let context = |index, visible_index, is_last| NodeContext {
+        index,
+        visible_index,
+        depth: 1,
+        is_last,
+    };
+    let lines: Vec<&str> = output.lines().collect();
+    for seen in [&items, &enumerators] {
+        let seen = seen.lock().unwrap();
+        assert!(!seen.is_empty());
+        for (value, ctx) in seen.iter() {
+            // The node handed over is the one drawn on the line the
+            // visible index points at.
+            assert!(
+                lines[1 + ctx.visible_index].ends_with(value.as_str()),
+                "{} at {:?}",
+                value,
+                ctx
+            );
+            let expected = match value.as_str() {
+                "B" => context(1, 0, false),
+                "C" => context(2, 1, false),
+                "D" => context(3, 2, true),
+                other => panic!("styled a node that is not rendered: {}", other),
+            };
+            assert_eq!(*ctx, expected);
+        }
+    }
+}
+
+#[test]
+fn test_style_for_is_preferred_over_style_func() {
+    use lipgloss::Style;
+    use lipgloss_tree::Node;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let func_calls = Arc::new(AtomicUsize::new(0));
+    let for_calls = Arc::new(AtomicUsize::new(0));
+    let counting = |calls: &Arc<AtomicUsize>| {
+        let calls = Arc::clone(calls);
+        move || {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Style::new()
+        }
+    };
+    let func = counting(&func_calls);
+    let for_ = counting(&for_calls);
+    let tree = Tree::new()
+        .child(children!["a", "b"])
+        .item_style_func(move |_, _| func())
+        .item_style_for(move |_: &dyn Node, _| for_());
+    assert_eq!(tree.to_string(), "├── a\n└── b");
+    assert_eq!(func_calls.load(Ordering::SeqCst), 0);
+    assert!(for_calls.load(Ordering::SeqCst) > 0);
+
+    // Without the node hook the old function still applies.
+    let func = counting(&func_calls);
+    let tree = Tree::new()
+        .child(children!["a", "b"])
+        .item_style_func(move |_, _| func());
+    assert_eq!(tree.to_string(), "├── a\n└── b");
+    assert!(func_calls.load(Ordering::SeqCst) > 0);
+}
+
+#[test]
+fn test_filter_prunes_branches_without_matches() {
+    use lipgloss_tree::Node;
+
+    let tree = |query: &'static str| {
+        Tree::new()
+            .root("project")
+            .child(children![
+                Tree::new().root("src").child(children![
+                    "main.rs",
+                    Tree::new()
+                        .root("config")
+                        .child(children!["parse.rs", "schema.json"]),
+                    Tree::new().root("ui").child(children!["view.rs"]),
+                ]),
+                Tree::new()
+                    .root("docs")
+                    .child(children!["guide.md", "config.md"]),
+                "Cargo.toml",
+            ])
+            .filter(move |node: &dyn Node| node.value().contains(query))
+    };
+
+    // Ancestors of every match stay; the last one left closes its branch.
+    assert_eq!(
+        tree(".rs").to_string(),
+        "project\n\
+         └── src\n\
+         \u{20}   ├── main.rs\n\
+         \u{20}   ├── config\n\
+         \u{20}   │   └── parse.rs\n\
+         \u{20}   └── ui\n\
+         \u{20}       └── view.rs"
+    );
+
+    // A branch goes once none of its descendants match, whatever its name.
+    assert_eq!(
+        tree("config").to_string(),
+        "project\n└── docs\n    └── config.md"
+    );
+    assert_eq!(tree("nothing").to_string(), "project");
+
+    // The offset counts the children left.
+    let tree = tree(".md").offset(0, 1);
+    assert_eq!(tree.children().length(), 0);
+    assert_eq!(tree.offset_hidden(), (0, 1));
+}
+
+#[test]
+fn test_node_styles_override_tree_styles() {
+    use lipgloss::Style;
+    use lipgloss_tree::Node;
+
+    let selected = Style::new().transform(|s| format!("> {}", s));
+    let items = || {
+        vec![
+            Box::new(Leaf::new("one", false)) as Box<dyn Node>,
+            Box::new(Leaf::new("two", false).with_style(selected.clone())),
+            Box::new(Leaf::new("three", false)),
+        ]
+    };
+
+    // Over the tree's item style...
+    let tree = Tree::new()
+        .child(items())
+        .item_style(Style::new().transform(|s| s.to_uppercase()));
+    assert_eq!(tree.to_string(), "├── ONE\n├── > two\n└── THREE");
+
+    // ...and over its item style function, which still styles the rest.
+    let tree = Tree::new()
+        .child(items())
+        .item_style_func(|_, i| Style::new().padding_left(i as i32));
+    assert_eq!(tree.to_string(), "├── one\n├── > two\n└──   three");
+
+    // Enumerators likewise, and a nested tree can carry its own.
+    let tree = Tree::new()
+        .child(children![
+            Leaf::new("a", false).with_enumerator_style(Style::new().padding_right(3)),
+            Tree::new()
+                .root("b")
+                .node_enumerator_style(Style::new().padding_right(2))
+                .node_style(selected.clone())
+                .child(children!["c"]),
+            "d",
+        ])
+        .enumerator_style(Style::new().padding_right(1));
+    assert_eq!(tree.to_string(), "├──   a\n├──  > b\n│   └── c\n└── d");
+}
+
+/// Calls seen by a [`recorder`]: its tag, the children's values and the index.
+type CallLog = Arc<Mutex<Vec<(&'static str, Vec<String>, usize)>>>;
+
+/// Records the values of the children and the index each call receives.
+fn recorder(
+    tag: &'static str,
+    log: &CallLog,
+) -> impl Fn(&dyn Children, usize) -> lipgloss::Style + Send + Sync + 'static {
+    let log = Arc::clone(log);
+    move |children, i| {
+        let values = (0..children.length())
+            .filter_map(|j| children.at(j).map(|n| n.value()))
+            .collect();
+        let mut log = log.lock().unwrap();
+        let call = (tag, values, i);
+        if !log.contains(&call) {
+            log.push(call);
+        }
+        lipgloss::Style::new().padding_right(1)
+    }
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|v| v.to_string()).collect()
+}
+
+#[test]
+fn test_style_funcs_receive_siblings_of_the_line_they_style() {
+    let log: CallLog = Arc::default();
+    let tree = Tree::new()
+        .root("Root")
+        .child(vec![
+            "leaf".into(),
+            Tree::new()
+                .root("Sub")
+                .child(vec!["x".into(), "y".into()])
+                .enumerator_style_func(recorder("sub", &log))
+                .into(),
+            "last".into(),
+        ])
+        .enumerator_style_func(recorder("outer", &log));
+    tree.to_string();
+
+    let outer = strings(&["leaf", "Sub", "last"]);
+    let sub = strings(&["x", "y"]);
+    let log = log.lock().unwrap();
+    // (a) leaves and (b) the subtree's own line get the outer function with
+    // the outer children and their own index; (c) the root is never passed.
+    assert_eq!(
+        *log,
+        vec![
+            ("outer", outer.clone(), 0),
+            ("outer", outer.clone(), 1),
+            ("sub", sub.clone(), 0),
+            ("sub", sub, 1),
+            ("outer", outer, 2),
+        ]
+    );
+}
+
+#[test]
+fn test_unstyled_subtree_shares_the_parent_style_funcs() {
+    let log: CallLog = Arc::default();
+    let tree = Tree::new()
+        .root("Root")
+        .child(vec![
+            Leaf::new("hidden", true).into(),
+            Tree::new().root("Sub").child(vec!["x".into()]).into(),
+        ])
+        .enumerator_style_func(recorder("enum", &log))
+        .item_style_func(recorder("item", &log));
+    assert_eq!(tree.to_string(), "Root\n└── Sub \n    └── x ");
+
+    let log = log.lock().unwrap();
+    for tag in ["enum", "item"] {
+        let calls: Vec<_> = log.iter().filter(|c| c.0 == tag).collect();
+        assert_eq!(
+            calls,
+            vec![&(tag, strings(&["Sub"]), 0), &(tag, strings(&["x"]), 0)]
+        );
+    }
+}
@@ -0,0 +1,149 @@
+use lipgloss_tree::{children, Leaf, Node, Tree};
+
+/// Renders as:
+///
+/// ```text
+/// home
+/// ├── docs
+/// │   ├── notes.txt
+/// │   └── drafts
+/// │       ├── a.md
+/// │       └── b.md
+/// ├── music
+/// │   └── albums
+/// │       └── blue
+/// │           └── track-01.flac
+/// └── todo.txt
+/// ```
+fn fixture() -> Tree {
+    Tree::new().root("home").child(children![
+        Tree::new().root("docs").child(children![
+            "notes.txt",
+            Tree::new().root("drafts").child(children![
+                "a.md",
+                "b.md",
+                Leaf::new("secret.md", true),
+            ]),
+        ]),
+        Tree::new()
+            .root("music")
+            .child(children![Tree::new().root("albums").child(children![
+                Tree::new().root("blue").child(children!["track-01.flac"])
+            ])]),
+        "todo.txt",
+    ])
+}
+
+#[test]
+fn test_max_depth_collapses_below_the_limit() {
+    let count = |hidden: usize| format!("(+{} more)", hidden);
+
+    assert_eq!(fixture().max_depth(0).to_string(), "home\n└── …");
+    assert_eq!(
+        fixture().max_depth(0).collapsed_label_fn(count).to_string(),
+        "home\n└── (+10 more)"
+    );
+    assert_eq!(
+        fixture().max_depth(1).collapsed_label_fn(count).to_string(),
+        "home\n\
+         ├── docs\n\
+         │   └── (+4 more)\n\
+         ├── music\n\
+         │   └── (+3 more)\n\
+         └── todo.txt"
+    );
+    assert_eq!(
+        fixture().max_depth(2).to_string(),
+        "home\n\
+         ├── docs\n\
+         │   ├── notes.txt\n\
+         │   └── drafts\n\
+         │       └── …\n\
+         ├── music\n\
+         │   └── albums\n\
+         │       └── …\n\
+         └── todo.txt"
+    );
+
+    // Deep enough, nothing collapses and the hidden leaf stays hidden.
+    assert_eq!(fixture().max_depth(4).to_string(), fixture().to_string());
+    assert!(!fixture().to_string().contains("secret"));
+}
+
+#[test]
+fn test_collapsed_levels_do_not_affect_layout() {
+    let wide = Tree::new().root("root").child(children![
+        Tree::new().root("a").child(children![
+            "a very long name that would widen the output".to_string()
+        ]),
+        "b",
+    ]);
+    let out = wide.max_depth(1).to_string();
+    assert_eq!(out, "root\n├── a\n│   └── …\n└── b");
+    assert_eq!(lipgloss::width(&out), 9);
+
+    // A collapsed tree's only child is the marker.
+    let limited = fixture().max_depth(1);
+    let docs = limited.children();
+    let docs = docs.at(0).unwrap().children();
+    assert_eq!(docs.length(), 1);
+    assert_eq!(docs.at(0).unwrap().value(), "…");
+}
+
+#[test]
+fn test_collapse_single_subtree() {
+    let tree = Tree::new().root("~").child(children![
+        Tree::new()
+            .root("ayman")
+            .child(children!["notes", "todo"])
+            .collapse(true),
+        Tree::new().root("bash").child(children!["zsh"]),
+        // Nothing to stand in for: no marker.
+        Tree::new()
+            .root("empty")
+            .child(children![Leaf::new("hidden", true)])
+            .collapse(true),
+    ]);
+    assert_eq!(
+        tree.to_string(),
+        "~\n├── ayman\n│   └── …\n├── bash\n│   └── zsh\n└── empty"
+    );
+    assert_eq!(tree.children().at(0).unwrap().descendant_count(), 2);
+
+    // A tree's own label wins over the one of the limiting tree, and an
+    // offset doesn't apply to the marker.
+    let tree = Tree::new()
+        .child(children![Tree::new()
+            .root("logs")
+            .child(children!["1", "2", "3"])
+            .offset(1, 0)
+            .collapsed_label_fn(|n| format!("{} entries", n))])
+        .max_depth(1)
+        .collapsed_label_fn(|_| "unused".to_string());
+    assert_eq!(tree.to_string(), "└── logs\n    └── 3 entries");
+}
+
+#[test]
+fn test_width_with_max_depth_and_styles() {
+    let mut r = lipgloss::renderer::Renderer::new();
+    r.set_color_profile(lipgloss::renderer::ColorProfileKind::ANSI);
+    let tree = fixture()
+        .max_depth(1)
+        .collapsed_label_fn(|hidden| format!("{} more entries hidden", hidden))
+        .item_style(lipgloss::Style::new().renderer(r).bold(true))
+        .width(16);
+
+    let out = tree.to_string();
+    assert_eq!(
+        lipgloss::strip_ansi(&out),
+        "home\n\
+         ├── docs\n\
+         │   └── 4 more …\n\
+         ├── music\n\
+         │   └── 3 more …\n\
+         └── todo.txt"
+    );
+    assert!(out.lines().all(|line| lipgloss::width(line) <= 16));
+    assert!(out.contains("\x1b[1mdocs"));
+    assert_eq!(tree.render_parts().join("\n"), out);
+}
@@ -0,0 +1,79 @@
+use lipgloss_tree::{children, Leaf, Tree};
+
+fn fixture() -> Tree {
+    Tree::new().root("~").child(children![
+        "notes.txt",
+        Tree::new().root("src").child(children!["main.rs"]),
+        "todo.txt",
+    ])
+}
+
+#[test]
+fn test_edits_render_like_fresh_trees() {
+    let mut tree = fixture();
+    assert_eq!(tree.to_string(), fixture().to_string());
+
+    tree.insert_child(0, "README.md");
+    tree.insert_child(4, Leaf::new("Cargo.toml", false));
+    assert_eq!(
+        tree.to_string(),
+        Tree::new()
+            .root("~")
+            .child(children![
+                "README.md",
+                "notes.txt",
+                Tree::new().root("src").child(children!["main.rs"]),
+                "todo.txt",
+                "Cargo.toml",
+            ])
+            .to_string()
+    );
+
+    let removed = tree.remove_child(1).unwrap();
+    assert_eq!(removed.value(), "notes.txt");
+    let old = tree
+        .replace_child(
+            1,
+            Tree::new()
+                .root("src")
+                .child(children!["main.rs", "lib.rs"]),
+        )
+        .unwrap();
+    assert_eq!(old.children().length(), 1);
+    assert_eq!(
+        tree.to_string(),
+        Tree::new()
+            .root("~")
+            .child(children![
+                "README.md",
+                Tree::new()
+                    .root("src")
+                    .child(children!["main.rs", "lib.rs"]),
+                "todo.txt",
+                "Cargo.toml",
+            ])
+            .to_string()
+    );
+
+    tree.clear_children();
+    assert_eq!(tree.to_string(), "~");
+    tree.insert_child(0, "again");
+    assert_eq!(
+        tree.to_string(),
+        Tree::new().root("~").child(children!["again"]).to_string()
+    );
+}
+
+#[test]
+fn test_out_of_range_edits_are_ignored() {
+    let mut tree = fixture();
+    tree.insert_child(4, "far away");
+    assert!(tree.remove_child(3).is_none());
+    assert!(tree.replace_child(3, "elsewhere").is_none());
+    assert_eq!(tree.to_string(), fixture().to_string());
+
+    let mut empty = Tree::new();
+    assert!(empty.remove_child(0).is_none());
+    empty.clear_children();
+    assert_eq!(empty.to_string(), "");
+}
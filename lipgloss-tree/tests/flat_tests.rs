@@ -0,0 +1,140 @@
+use lipgloss::{strip_ansi, Style};
+use lipgloss_tree::{Children, FlatOptions, Leaf, Node, Tree};
+
+/// Three levels: root, two services with a nested component, and leaves.
+fn fixture() -> Tree {
+    Tree::new().root("logs").child(vec![
+        Tree::new()
+            .root("api")
+            .child(vec![
+                "started".into(),
+                Tree::new()
+                    .root("auth")
+                    .child(vec!["login".into(), "logout".into()])
+                    .into(),
+                Leaf::new("debug", true).into(),
+            ])
+            .into(),
+        Tree::new()
+            .root("db")
+            .child(vec!["connected".into(), "slow query".into()])
+            .into(),
+        "idle".into(),
+    ])
+}
+
+fn visible_leaves(node: &dyn Node) -> usize {
+    let children = node.children();
+    (0..children.length())
+        .filter_map(|i| children.at(i))
+        .filter(|child| !child.hidden())
+        .map(|child| {
+            if child.children().length() == 0 {
+                1
+            } else {
+                visible_leaves(child)
+            }
+        })
+        .sum()
+}
+
+#[test]
+fn test_render_flat_one_line_per_leaf() {
+    let flat = fixture().render_flat("/");
+    let lines: Vec<&str> = flat.lines().collect();
+
+    assert_eq!(
+        lines,
+        vec![
+            "logs/api/started",
+            "logs/api/auth/login",
+            "logs/api/auth/logout",
+            "logs/db/connected",
+            "logs/db/slow query",
+            "logs/idle",
+        ]
+    );
+
+    // The hidden "debug" leaf is not counted.
+    assert_eq!(lines.len(), visible_leaves(&fixture()));
+}
+
+#[test]
+fn test_render_flat_separator_and_branches() {
+    let options = FlatOptions::new().separator(" > ").branches(true);
+    assert_eq!(
+        fixture().render_flat_with(&options),
+        [
+            "logs",
+            "logs > api",
+            "logs > api > started",
+            "logs > api > auth",
+            "logs > api > auth > login",
+            "logs > api > auth > logout",
+            "logs > db",
+            "logs > db > connected",
+            "logs > db > slow query",
+            "logs > idle",
+        ]
+        .join("\n")
+    );
+}
+
+#[test]
+fn test_render_flat_respects_offset_and_hidden() {
+    let tree = Tree::new()
+        .root("logs")
+        .child(vec![
+            Tree::new().root("api").child(vec!["a".into()]).into(),
+            Tree::new()
+                .root("db")
+                .hide(true)
+                .child(vec!["b".into()])
+                .into(),
+            "c".into(),
+            "d".into(),
+        ])
+        .offset(1, 1);
+    assert_eq!(tree.render_flat("/"), "logs/c");
+
+    assert_eq!(fixture().hide(true).render_flat("/"), "");
+}
+
+#[test]
+fn test_render_flat_without_root() {
+    let tree = Tree::new().child(vec![
+        "top".into(),
+        Tree::new().root("dir").child(vec!["file".into()]).into(),
+    ]);
+    assert_eq!(tree.render_flat("/"), "top\ndir/file");
+}
+
+#[test]
+fn test_render_flat_styles_leaf_value_only() {
+    let style = Style::new().bold(true);
+    let tree = Tree::new()
+        .root("logs")
+        .item_style_func(
+            move |_: &dyn Children, i| {
+                if i == 0 {
+                    style.clone()
+                } else {
+                    Style::new()
+                }
+            },
+        )
+        .child(vec!["warn".into(), "info".into()]);
+
+    let flat = tree.render_flat("/");
+    let lines: Vec<&str> = flat.lines().collect();
+    assert!(lines[0].starts_with("logs/"));
+    assert_ne!(lines[0], "logs/warn");
+    assert_eq!(strip_ansi(lines[0]), "logs/warn");
+    assert_eq!(lines[1], "logs/info");
+}
+
+#[test]
+fn test_render_flat_multiline_value() {
+    let tree = Tree::new().root("logs").child(vec!["first\nsecond".into()]);
+    assert_eq!(tree.render_flat("/"), "logs/first\nlogs/second");
+}
@@ -3,14 +3,7 @@ use std::path::PathBuf;
 
 use lipgloss::renderer::{set_color_profile, ColorProfileKind};
 use lipgloss_tree as tree;
-use lipgloss_tree::{new_string_data, root, Children, Filter, Tree};
-
-// Helper macro for easier child creation
-macro_rules! child {
-    ($($item:expr),* $(,)?) => {
-        vec![$($item.into()),*]
-    };
-}
+use lipgloss_tree::{children, new_string_data, root, Children, Filter, Tree};
 
 fn golden_dir() -> PathBuf {
     // lipgloss-tree is at .../lipgloss-rs/lipgloss-tree
@@ -31,11 +24,11 @@ fn assert_matches_golden(output: &str, rel: &str) {
 
 #[test]
 fn test_tree_before_after() {
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "Foo",
-        root("Bar").child(child![
+        root("Bar").child(children![
             "Qux",
-            root("Quux").child(child!["Foo", "Bar"]),
+            root("Quux").child(children!["Foo", "Bar"]),
             "Quuux"
         ]),
         "Baz"
@@ -51,11 +44,11 @@ fn test_tree_before_after() {
 
 #[test]
 fn test_tree_hidden() {
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "Foo",
-        root("Bar").child(child![
+        root("Bar").child(children![
             "Qux",
-            root("Quux").child(child!["Foo", "Bar"]).hide(true),
+            root("Quux").child(children!["Foo", "Bar"]).hide(true),
             "Quuux"
         ]),
         "Baz"
@@ -66,11 +59,11 @@ fn test_tree_hidden() {
 #[test]
 fn test_tree_all_hidden() {
     let tr = Tree::new()
-        .child(child![
+        .child(children![
             "Foo",
-            root("Bar").child(child![
+            root("Bar").child(children![
                 "Qux",
-                root("Quux").child(child!["Foo", "Bar"]),
+                root("Quux").child(children!["Foo", "Bar"]),
                 "Quuux"
             ]),
             "Baz"
@@ -81,9 +74,9 @@ fn test_tree_all_hidden() {
 
 #[test]
 fn test_tree_root() {
-    let tr = Tree::new().root("Root").child(child![
+    let tr = Tree::new().root("Root").child(children![
         "Foo",
-        root("Bar").child(child!["Qux", "Quuux"]),
+        root("Bar").child(children!["Qux", "Quuux"]),
         "Baz"
     ]);
     assert_matches_golden(&format!("{}", tr), "TestTreeRoot.golden");
@@ -91,8 +84,8 @@ fn test_tree_root() {
 
 #[test]
 fn test_tree_starts_with_subtree() {
-    let tr = Tree::new().child(child![
-        Tree::new().root("Bar").child(child!["Qux", "Quuux"]),
+    let tr = Tree::new().child(children![
+        Tree::new().root("Bar").child(children!["Qux", "Quuux"]),
         "Baz"
     ]);
     assert_matches_golden(&format!("{}", tr), "TestTreeStartsWithSubtree.golden");
@@ -100,11 +93,11 @@ fn test_tree_starts_with_subtree() {
 
 #[test]
 fn test_tree_add_two_subtrees_without_name() {
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "Bar",
         "Foo",
-        Tree::new().child(child!["Qux", "Qux", "Qux", "Qux", "Qux"]),
-        Tree::new().child(child!["Quux", "Quux", "Quux", "Quux", "Quux"]),
+        Tree::new().child(children!["Qux", "Qux", "Qux", "Qux", "Qux"]),
+        Tree::new().child(children!["Quux", "Quux", "Quux", "Quux", "Quux"]),
         "Baz"
     ]);
     assert_matches_golden(
@@ -115,11 +108,11 @@ fn test_tree_add_two_subtrees_without_name() {
 
 #[test]
 fn test_tree_last_node_is_subtree() {
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "Foo",
-        root("Bar").child(child![
+        root("Bar").child(children![
             "Qux",
-            root("Quux").child(child!["Foo", "Bar",]),
+            root("Quux").child(children!["Foo", "Bar",]),
             "Quuux",
         ]),
     ]);
@@ -129,9 +122,13 @@ fn test_tree_last_node_is_subtree() {
 #[test]
 fn test_tree_nil() {
     // Go test uses nil; here simulate by adding an empty-value container
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         Tree::new(), // Empty tree simulates nil
-        root("Bar").child(child!["Qux", root("Quux").child(child!["Bar"]), "Quuux",]),
+        root("Bar").child(children![
+            "Qux",
+            root("Quux").child(children!["Bar"]),
+            "Quuux",
+        ]),
         "Baz",
     ]);
     assert_matches_golden(&format!("{}", tr), "TestTreeNil.golden");
@@ -150,11 +147,11 @@ fn test_tree_custom_enumerators_and_styles() {
     let item_style = lipgloss::Style::new().foreground(lipgloss::Color::from("91"));
 
     let tr = Tree::new()
-        .child(child![
+        .child(children![
             "Foo",
-            Tree::new().root("Bar").child(child![
+            Tree::new().root("Bar").child(children![
                 "Qux",
-                Tree::new().root("Quux").child(child!["Foo", "Bar",]),
+                Tree::new().root("Quux").child(children!["Foo", "Bar",]),
                 "Quuux",
             ]),
             "Baz",
@@ -172,11 +169,11 @@ fn test_tree_custom_enumerators_and_styles() {
 
 #[test]
 fn test_tree_multiline_node() {
-    let tr = Tree::new().root("Big\nRoot\nNode").child(child![
+    let tr = Tree::new().root("Big\nRoot\nNode").child(children![
         "Foo",
-        Tree::new().root("Bar").child(child![
+        Tree::new().root("Bar").child(children![
             "Line 1\nLine 2\nLine 3\nLine 4",
-            Tree::new().root("Quux").child(child!["Foo", "Bar",]),
+            Tree::new().root("Quux").child(children!["Foo", "Bar",]),
             "Quuux",
         ]),
         "Baz\nLine 2",
@@ -186,10 +183,10 @@ fn test_tree_multiline_node() {
 
 #[test]
 fn test_tree_subtree_with_custom_enumerator() {
-    let tr = Tree::new().root("The Root Node™").child(child![
+    let tr = Tree::new().root("The Root Node™").child(children![
         Tree::new()
             .root("Parent")
-            .child(child!["child 1", "child 2",])
+            .child(children!["child 1", "child 2",])
             .item_style_func(|_, _| lipgloss::Style::new().set_string("*"))
             .enumerator_style_func(|_, _| {
                 lipgloss::Style::new().set_string("+").padding_right(1)
@@ -206,7 +203,7 @@ fn test_tree_subtree_with_custom_enumerator() {
 fn test_tree_mixed_enumerator_size() {
     let tr = Tree::new()
         .root("The Root Node™")
-        .child(child!["Foo", "Foo", "Foo", "Foo", "Foo",])
+        .child(children!["Foo", "Foo", "Foo", "Foo", "Foo",])
         .enumerator(|_, i| match i + 1 {
             1 => "I".into(),
             2 => "II".into(),
@@ -223,7 +220,7 @@ fn test_tree_mixed_enumerator_size() {
 fn test_tree_style_nil_funcs() {
     let tr = Tree::new()
         .root("Silly")
-        .child(child!["Willy ", "Nilly",])
+        .child(children!["Willy ", "Nilly",])
         // Use no-op closures to simulate nil funcs
         .item_style_func(|_, _| lipgloss::Style::new())
         .enumerator_style_func(|_, _| lipgloss::Style::new());
@@ -234,7 +231,7 @@ fn test_tree_style_nil_funcs() {
 fn test_tree_style_at() {
     let tr = Tree::new()
         .root("Root")
-        .child(child!["Foo", "Baz",])
+        .child(children!["Foo", "Baz",])
         .enumerator(|data, i| {
             if data.at(i).map(|n| n.value()) == Some("Foo".into()) {
                 ">".into()
@@ -273,7 +270,7 @@ fn test_root_style() {
     set_color_profile(ColorProfileKind::TrueColor);
     let tr = Tree::new()
         .root("Root")
-        .child(child!["Foo", "Baz",])
+        .child(children!["Foo", "Baz",])
         .root_style(lipgloss::Style::new().background(lipgloss::Color::from("#5A56E0")))
         .item_style(lipgloss::Style::new().background(lipgloss::Color::from("#04B575")));
     assert_matches_golden(&format!("{}", tr), "TestRootStyle.golden");
@@ -289,8 +286,8 @@ fn test_embed_list_within_tree() {
         .items(vec!["1", "2", "3"])
         .enumerator(list::alphabet);
     let t1 = Tree::new()
-        .child(child![format!("{}", list1)])
-        .child(child![format!("{}", list2)]);
+        .child(children![format!("{}", list1)])
+        .child(children![format!("{}", list2)]);
     assert_matches_golden(&format!("{}", t1), "TestEmbedListWithinTree.golden");
 }
 
@@ -308,7 +305,7 @@ fn test_multiline_prefix() {
         .indenter(|_, _| " ".to_string())
         .item_style(padding_style)
         .enumerator_style_func(|_, _| lipgloss::Style::new())
-        .child(child![
+        .child(children![
             "Foo Document\nThe Foo Files",
             "Bar Document\nThe Bar Files",
             "Baz Document\nThe Baz Files",
@@ -319,7 +316,7 @@ fn test_multiline_prefix() {
 #[test]
 fn test_multiline_prefix_subtree() {
     let padding_style = lipgloss::Style::new().padding(0, 0, 1, 1);
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "Foo",
         "Bar",
         Tree::new()
@@ -334,7 +331,7 @@ fn test_multiline_prefix_subtree() {
             .indenter(|_, _| " ".to_string())
             .item_style(padding_style.clone())
             .enumerator_style_func(|_, _| lipgloss::Style::new())
-            .child(child![
+            .child(children![
                 "Foo Document\nThe Foo Files",
                 "Bar Document\nThe Bar Files",
                 "Baz Document\nThe Baz Files",
@@ -361,7 +358,7 @@ fn test_multiline_prefix_inception() {
         .indenter(glow_indenter)
         .item_style(padding_style.clone())
         .enumerator_style_func(|_, _| lipgloss::Style::new())
-        .child(child![
+        .child(children![
             "Foo Document\nThe Foo Files",
             "Bar Document\nThe Bar Files",
             Tree::new()
@@ -369,7 +366,7 @@ fn test_multiline_prefix_inception() {
                 .indenter(glow_indenter)
                 .item_style(padding_style.clone())
                 .enumerator_style_func(|_, _| lipgloss::Style::new())
-                .child(child![
+                .child(children![
                     "Qux Document\nThe Qux Files",
                     "Quux Document\nThe Quux Files",
                     "Quuux Document\nThe Quuux Files",
@@ -381,7 +378,7 @@ fn test_multiline_prefix_inception() {
 
 #[test]
 fn test_types() {
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "0",    // simulating fmt.Sprintf("%v", 0)
         "true", // simulating fmt.Sprintf("%v", true)
         "Foo",  // []any{"Foo", "Bar"} expanded
@@ -394,10 +391,10 @@ fn test_types() {
 #[test]
 fn test_add_item_with_and_without_root() {
     // Test "with root"
-    let t1 = Tree::new().child(child![
+    let t1 = Tree::new().child(children![
         "Foo",
         "Bar",
-        Tree::new().child(child!["Baz"]),
+        Tree::new().child(children!["Baz"]),
         "Qux",
     ]);
     assert_matches_golden(
@@ -406,9 +403,9 @@ fn test_add_item_with_and_without_root() {
     );
 
     // Test "without root"
-    let t2 = Tree::new().child(child![
+    let t2 = Tree::new().child(children![
         "Foo",
-        Tree::new().root("Bar").child(child!["Baz"]),
+        Tree::new().root("Bar").child(children!["Baz"]),
         "Qux",
     ]);
     assert_matches_golden(
@@ -428,10 +425,103 @@ fn test_tree_table() {
         .row(vec!["Qux", "Baz"])
         .render();
 
-    let tr = Tree::new().child(child![
+    let tr = Tree::new().child(children![
         "Foo",
-        root("Bar").child(child!["Baz", "Baz", tbl, "Baz"]),
+        root("Bar").child(children!["Baz", "Baz", tbl, "Baz"]),
         "Qux"
     ]);
     assert_matches_golden(&format!("{}", tr), "TestTreeTable.golden");
 }
+
+const BLOCK_OUTPUT: &str = "Compiling lipgloss v0.1.1\nCompiling lipgloss-tree v0.1.1\nwarning: unused import\nFinished dev profile\nRunning target/debug/app";
+
+#[test]
+fn test_block_child_middle() {
+    let tr = Tree::new()
+        .root("$ cargo run")
+        .child(children!["env: dev"])
+        .block_child(BLOCK_OUTPUT)
+        .child(children!["exit status: 0"]);
+    assert_matches_golden(&format!("{}", tr), "TestBlockChildMiddle.golden");
+}
+
+#[test]
+fn test_block_child_last() {
+    let tr = Tree::new()
+        .root("$ cargo run")
+        .child(children!["env: dev"])
+        .block_child(BLOCK_OUTPUT);
+    assert_matches_golden(&format!("{}", tr), "TestBlockChildLast.golden");
+}
+
+#[test]
+fn test_width_budget() {
+    let tr = Tree::new()
+        .root("workspace_with_a_long_name")
+        .child(children![
+            root("src").child(children![
+                "main.rs",
+                root("components_directory").child(children![
+                    "button_component_with_variants.rs",
+                    root("forms").child(children!["text_input_field.rs", "checkbox.rs",]),
+                ]),
+                "a multi-line value\nwhose lines are each cut\nok",
+            ]),
+            "README_with_a_rather_long_title.md",
+        ])
+        .width(20);
+    assert_matches_golden(&format!("{}", tr), "TestWidthBudget.golden");
+}
+
+#[test]
+fn test_multiline_root() {
+    let tr = Tree::new().root("project\n~/src/project").child(children![
+        "Cargo.toml",
+        root("src\n(2 files)").child(children!["main.rs", "lib.rs"]),
+    ]);
+    assert_matches_golden(&format!("{}", tr), "TestMultilineRoot.golden");
+}
+
+fn multiline_guides() -> Tree {
+    Tree::new().root("Notes").child(children![
+        "Groceries\n2 days ago",
+        root("Work\nshared").child(children!["Standup\nToday\n9:30", "Retro\nFriday",]),
+        "Ideas\nlast week\n3 notes",
+    ])
+}
+
+#[test]
+fn test_multiline_guides() {
+    assert_matches_golden(
+        &format!("{}", multiline_guides()),
+        "TestMultilineGuides.golden",
+    );
+}
+
+#[test]
+fn test_multiline_guides_rounded() {
+    let tr = multiline_guides().enumerator(tree::rounded_enumerator);
+    assert_matches_golden(&format!("{}", tr), "TestMultilineGuidesRounded.golden");
+}
+
+#[test]
+fn test_multiline_guides_take_enumerator_style() {
+    let tr = multiline_guides().enumerator_style_func(|_, _| {
+        lipgloss::Style::new()
+            .foreground(lipgloss::Color::from("63"))
+            .padding_right(1)
+    });
+    set_color_profile(ColorProfileKind::TrueColor);
+    let out = format!("{}", tr);
+    let guide = out
+        .lines()
+        .find(|l| l.contains("2 days ago"))
+        .expect("continuation line");
+    let (before, _) = guide.split_once('│').expect("guide on continuation line");
+    assert!(
+        before.contains("\x1b["),
+        "guide should be styled: {:?}",
+        guide
+    );
+    assert_eq!(lipgloss::width(guide), lipgloss::width("│   2 days ago"));
+}
@@ -0,0 +1,85 @@
+use lipgloss::{html, Style};
+use lipgloss_tree::{Children, Leaf, Tree};
+
+fn numbered(_children: &dyn Children, index: usize) -> String {
+    format!("{}.", index + 1)
+}
+
+#[test]
+fn test_to_html_styled_tree() {
+    let tree = Tree::new()
+        .root("Project <src>")
+        .root_style(Style::new().bold(true))
+        .item_style(Style::new().foreground("#00ff00"))
+        .child(vec![
+            "main.rs".into(),
+            Leaf::new("secret", true).into(),
+            Tree::new()
+                .root("docs & notes")
+                .child(vec!["a.md".into(), "b.md".into()])
+                .into(),
+        ]);
+
+    let html = tree.to_html();
+    assert_eq!(
+        html,
+        "<div style=\"font-family:monospace;white-space:pre-wrap\">\
+         <span style=\"font-weight:bold\">Project &lt;src&gt;</span>\
+         <ul>\
+         <li><span style=\"color:#00ff00\">main.rs</span></li>\
+         <li><span style=\"color:#00ff00\">docs &amp; notes</span>\
+         <ul>\
+         <li><span style=\"color:#00ff00\">a.md</span></li>\
+         <li><span style=\"color:#00ff00\">b.md</span></li>\
+         </ul></li>\
+         </ul>\
+         </div>"
+    );
+    assert_eq!(
+        html::check_tags(&html).unwrap(),
+        ["div", "span", "ul", "li", "span", "li", "span", "ul", "li", "span", "li", "span"]
+    );
+}
+
+#[test]
+fn test_to_html_ordered_enumerator_and_style_func() {
+    let tree = Tree::new()
+        .enumerator(numbered)
+        .item_style_func(|_, i| {
+            if i % 2 == 0 {
+                Style::new().italic(true)
+            } else {
+                Style::new()
+            }
+        })
+        .child(vec![
+            "one".into(),
+            "two".into(),
+            "\x1b[1mthree\x1b[0m".into(),
+        ]);
+
+    let html = tree.to_html();
+    assert_eq!(
+        html,
+        "<div style=\"font-family:monospace;white-space:pre-wrap\"><ol>\
+         <li><span style=\"font-style:italic\">one</span></li>\
+         <li>two</li>\
+         <li><span style=\"font-style:italic\">three</span></li>\
+         </ol></div>"
+    );
+    html::check_tags(&html).unwrap();
+}
+
+#[test]
+fn test_to_html_hidden_and_empty_trees() {
+    let empty = "<div style=\"font-family:monospace;white-space:pre-wrap\"></div>";
+    assert_eq!(Tree::new().to_html(), empty);
+    assert_eq!(
+        Tree::new()
+            .root("x")
+            .child(vec!["y".into()])
+            .hide(true)
+            .to_html(),
+        empty
+    );
+}
@@ -0,0 +1,109 @@
+use lipgloss_tree::{children, Leaf, Tree};
+
+/// Renders as:
+///
+/// ```text
+/// project
+/// ├── README.md
+/// ├── src
+/// │   ├── main.rs
+/// │   └── a note
+/// │       on two lines
+/// └── Cargo.toml
+/// ```
+fn fixture() -> Tree {
+    Tree::new().root("project").child(children![
+        "README.md",
+        Tree::new().root("src").child(children![
+            "main.rs",
+            "a note\non two lines",
+            Tree::new()
+                .root("generated")
+                .child(children!["bindings.rs"])
+                .hide(true),
+        ]),
+        Leaf::new(".env", true),
+        "Cargo.toml",
+    ])
+}
+
+fn walk(tree: &Tree) -> Vec<(usize, String)> {
+    tree.iter()
+        .map(|(depth, node)| (depth, node.value()))
+        .collect()
+}
+
+#[test]
+fn test_iter_skips_hidden_subtrees() {
+    assert_eq!(
+        walk(&fixture()),
+        [
+            (0, "project".to_string()),
+            (1, "README.md".to_string()),
+            (1, "src".to_string()),
+            (2, "main.rs".to_string()),
+            (2, "a note\non two lines".to_string()),
+            (1, "Cargo.toml".to_string()),
+        ]
+    );
+    assert_eq!(fixture().visible_len(), 6);
+    assert_eq!(fixture().hide(true).visible_len(), 0);
+}
+
+#[test]
+fn test_visible_index_maps_rendered_lines_to_nodes() {
+    let tree = fixture();
+    let rendered = tree.to_string();
+    let lines: Vec<&str> = rendered.lines().collect();
+
+    // A multi-line value is one node over several lines: walking the nodes
+    // and stepping over their heights lands on the first line of each.
+    let mut line = 0;
+    for index in 0..tree.visible_len() {
+        let node = tree.node_at_visible_index(index).unwrap();
+        let first = node.value().lines().next().unwrap().to_string();
+        assert!(
+            lines[line].trim_end().ends_with(&first),
+            "{:?}",
+            lines[line]
+        );
+        line += node.value().lines().count();
+    }
+    assert_eq!(line, lines.len());
+
+    let note = tree.node_at_visible_index(4).unwrap();
+    assert_eq!(note.value(), "a note\non two lines");
+    assert!(tree.node_at_visible_index(6).is_none());
+}
+
+#[test]
+fn test_iter_follows_what_the_tree_shows() {
+    // Offsets drop children, and their markers are not nodes.
+    let tree = Tree::new()
+        .child(children!["a", "b", "c", "d"])
+        .offset(1, 1)
+        .offset_marker("...");
+    assert_eq!(walk(&tree), [(1, "b".to_string()), (1, "c".to_string())]);
+
+    // A collapsed subtree shows its marker; unnamed containers are walked
+    // through, one level below the item they hang under.
+    let tree = Tree::new().root("~").child(children![
+        Tree::new()
+            .root("logs")
+            .child(children!["1", "2"])
+            .collapse(true),
+        "item",
+        Tree::new().child(children!["nested"]),
+    ]);
+    assert_eq!(
+        walk(&tree),
+        [
+            (0, "~".to_string()),
+            (1, "logs".to_string()),
+            (2, "…".to_string()),
+            (1, "item".to_string()),
+            (2, "nested".to_string()),
+        ]
+    );
+    assert_eq!(tree.visible_len(), 5);
+}
@@ -0,0 +1,171 @@
+use lipgloss_tree::{Leaf, OutlineErrorKind, OutlineOptions, Tree};
+
+/// Small deterministic xorshift generator so the round-trip cases are
+/// reproducible without pulling in a randomness crate.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn value(&mut self) -> String {
+        const WORDS: &[&str] = &[
+            "alpha",
+            "beta",
+            "gamma",
+            "δέλτα",
+            "日本語",
+            "x",
+            "42",
+            "a-b",
+        ];
+        let words = 1 + self.below(3) as usize;
+        (0..words)
+            .map(|_| WORDS[self.below(WORDS.len() as u64) as usize])
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+fn random_tree(rng: &mut Rng, depth: usize) -> Tree {
+    let mut tree = Tree::new().root(rng.value());
+    for _ in 0..rng.below(4) {
+        let hidden = rng.below(6) == 0;
+        if depth < 3 && rng.below(3) == 0 {
+            tree = tree.add_child(random_tree(rng, depth + 1).hide(hidden));
+        } else {
+            tree = tree.add_child(Leaf::new(rng.value(), hidden));
+        }
+    }
+    tree
+}
+
+#[test]
+fn test_outline_round_trip_random_trees() {
+    let mut rng = Rng(0x9e37_79b9_7f4a_7c15);
+    let marked = OutlineOptions::new().hidden_marker("~ ");
+
+    for _ in 0..300 {
+        let mut tree = random_tree(&mut rng, 0);
+        if rng.below(4) == 0 {
+            tree = tree.root("");
+        }
+
+        let outline = tree.to_outline();
+        let restored = Tree::from_outline(&outline).unwrap();
+        assert_eq!(
+            restored.to_string(),
+            tree.to_string(),
+            "outline:\n{}",
+            outline
+        );
+
+        let outline = tree.to_outline_with(&marked);
+        let restored = Tree::from_outline_with(&outline, &marked).unwrap();
+        assert_eq!(
+            restored.to_string(),
+            tree.to_string(),
+            "outline:\n{}",
+            outline
+        );
+        assert_eq!(restored.to_outline_with(&marked), outline);
+    }
+}
+
+#[test]
+fn test_outline_skips_hidden_nodes_and_applies_offset() {
+    let tree = Tree::new()
+        .root("Root")
+        .child(vec![
+            "A".into(),
+            Leaf::new("hidden", true).into(),
+            "B".into(),
+            "C".into(),
+        ])
+        .offset(1, 0);
+
+    assert_eq!(tree.to_outline(), "Root\n  B\n  C");
+    assert_eq!(
+        tree.to_outline_with(&OutlineOptions::new().hidden_marker("# ")),
+        "Root\n  # hidden\n  B\n  C"
+    );
+}
+
+#[test]
+fn test_outline_rootless_tree() {
+    let tree = Tree::new().child(vec![
+        "one".into(),
+        Tree::new().root("two").child(vec!["three".into()]).into(),
+    ]);
+    assert_eq!(tree.to_outline(), "one\ntwo\n  three");
+    assert_eq!(
+        Tree::from_outline("one\ntwo\n  three").unwrap().to_string(),
+        tree.to_string()
+    );
+}
+
+#[test]
+fn test_outline_rootless_tree_with_one_child() {
+    let tree = Tree::new().child(vec![Tree::new().root("a").child(vec!["b".into()]).into()]);
+    assert_eq!(tree.to_outline(), "  a\n    b");
+
+    let restored = Tree::from_outline(&tree.to_outline()).unwrap();
+    assert_eq!(restored.to_string(), tree.to_string());
+    assert!(restored.to_string().starts_with("└── a"));
+
+    // Rooted, the lone line is the root as before.
+    assert_eq!(Tree::from_outline("a").unwrap().to_string(), "a");
+}
+
+#[test]
+fn test_outline_detects_indent_width() {
+    let expected = "Root\n  a\n    b\n  c";
+    for input in [
+        "Root\n  a\n    b\n  c",
+        "Root\n    a\n        b\n    c",
+        "Root\n\ta\n\t\tb\n\tc",
+        "\nRoot\n\n  a\n    b\n\n  c\n",
+    ] {
+        assert_eq!(
+            Tree::from_outline(input).unwrap().to_outline(),
+            expected,
+            "{:?}",
+            input
+        );
+    }
+    assert_eq!(Tree::from_outline("").unwrap().to_string(), "");
+}
+
+#[test]
+fn test_outline_errors_report_line_numbers() {
+    let cases = [
+        ("Root\n  a\n\tb", 3, OutlineErrorKind::MixedIndentation),
+        ("Root\n \ta", 2, OutlineErrorKind::MixedIndentation),
+        (
+            "Root\n    a\n      b",
+            3,
+            OutlineErrorKind::UnevenIndentation,
+        ),
+        ("Root\n  a\n      b", 3, OutlineErrorKind::UnexpectedIndent),
+        ("\ta\n\t\t\tb", 2, OutlineErrorKind::UnexpectedIndent),
+        ("  a\nb", 2, OutlineErrorKind::UnexpectedDedent),
+    ];
+    for (input, line, kind) in cases {
+        let err = Tree::from_outline(input).err().unwrap();
+        assert_eq!((err.line, err.kind), (line, kind), "{:?}", input);
+    }
+
+    let err = Tree::from_outline("Root\n  a\n\tb").err().unwrap();
+    assert_eq!(
+        err.to_string(),
+        "line 3: mixed tabs and spaces in indentation"
+    );
+}
@@ -0,0 +1,126 @@
+use lipgloss::renderer::{ColorProfileKind, Renderer};
+use lipgloss::Style;
+use lipgloss_tree::{children, Matcher, Rule, Tree};
+
+fn style() -> Style {
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::ANSI);
+    Style::new().renderer(r)
+}
+
+/// Renders as:
+///
+/// ```text
+/// ci
+/// ├── build
+/// │   ├── compile
+/// │   └── error: link failed
+/// ├── test
+/// │   └── unit
+/// │       └── error: 2 failed
+/// └── error summary
+/// ```
+fn fixture() -> Tree {
+    Tree::new().root("ci").child(children![
+        Tree::new()
+            .root("build")
+            .child(children!["compile", "error: link failed"]),
+        Tree::new().root("test").child(children![Tree::new()
+            .root("unit")
+            .child(children!["error: 2 failed"])]),
+        "error summary",
+    ])
+}
+
+#[test]
+fn test_rules_combine_depth_substring_and_leaf() {
+    let tree = fixture().style_rules(vec![
+        Rule::new(Matcher::Depth(0), style().bold(true)),
+        Rule::new(Matcher::Depth(1), style().foreground("4")),
+        Rule::new(
+            Matcher::ValueContains("error".into()),
+            style().foreground("1"),
+        ),
+        Rule::new(
+            Matcher::parse("leaf depth:3").unwrap(),
+            style().underline(true),
+        ),
+    ]);
+    let out = tree.to_string();
+    let lines: Vec<&str> = out.lines().collect();
+
+    assert_eq!(lines[0], "\x1b[1mci\x1b[0m");
+    assert_eq!(lines[1], "├── \x1b[34mbuild\x1b[0m");
+    assert_eq!(lines[2], "│   ├── compile");
+    assert_eq!(lines[3], "│   └── \x1b[31merror: link failed\x1b[0m");
+    assert_eq!(lines[4], "├── \x1b[34mtest\x1b[0m");
+    assert_eq!(lines[5], "│   └── unit");
+    // Both the substring and the leaf-at-depth rule match.
+    assert_eq!(lines[6], "│       └── \x1b[4;31merror: 2 failed\x1b[0m");
+    // The later substring rule overrides the depth rule's color.
+    assert_eq!(lines[7], "└── \x1b[31merror summary\x1b[0m");
+
+    assert_eq!(lipgloss::strip_ansi(&out), fixture().to_string());
+}
+
+#[test]
+fn test_rules_compose_with_tree_and_node_styles() {
+    let tree = Tree::new()
+        .child(children![
+            "plain",
+            "error",
+            Tree::new()
+                .root("error, styled")
+                .node_style(style().italic(true).padding_left(1)),
+        ])
+        .item_style(style().padding_left(1))
+        .style_rules(vec![
+            Rule::new(Matcher::IsLeaf, style().padding_left(2)),
+            Rule::new(Matcher::ValueContains("error".into()), style().bold(true)),
+        ]);
+
+    // Rules go on top of the item style, and a node's own style on top of
+    // the rules, its padding winning.
+    assert_eq!(
+        tree.to_string(),
+        "├──   plain\n├──   \x1b[1merror\x1b[0m\n└──  \x1b[1;3merror, styled\x1b[0m"
+    );
+
+    // A nested tree's own rules replace the outer ones below it. Their
+    // depths still count from the outermost root.
+    let tree = Tree::new()
+        .child(children![
+            "outer",
+            Tree::new()
+                .root("inner")
+                .child(children!["leaf"])
+                .style_rules(vec![Rule::new(Matcher::Depth(2), style().faint(true))]),
+        ])
+        .style_rules(vec![Rule::new(Matcher::IsLeaf, style().bold(true))]);
+    assert_eq!(
+        tree.to_string(),
+        "├── \x1b[1mouter\x1b[0m\n└── inner\n    └── \x1b[2mleaf\x1b[0m"
+    );
+}
+
+#[test]
+fn test_matcher_parse() {
+    assert_eq!(
+        Matcher::parse("depth:1 contains:err branch").unwrap(),
+        Matcher::All(vec![
+            Matcher::Depth(1),
+            Matcher::ValueContains("err".into()),
+            Matcher::IsBranch,
+        ])
+    );
+    assert_eq!(Matcher::parse("  leaf ").unwrap(), Matcher::IsLeaf);
+
+    let err = Matcher::parse("leaf size:3").unwrap_err();
+    assert_eq!(err.term, "size:3");
+    assert_eq!(err.to_string(), "unknown selector term \"size:3\"");
+    assert_eq!(Matcher::parse("contains:").unwrap_err().term, "contains:");
+    assert_eq!(
+        Matcher::parse("").unwrap_err().to_string(),
+        "empty selector"
+    );
+}
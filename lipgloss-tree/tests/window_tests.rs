@@ -0,0 +1,162 @@
+use lipgloss::{strip_ansi, Color, Style};
+use lipgloss_tree::{Leaf, Tree, WindowOpts};
+
+const AVOID: WindowOpts = WindowOpts {
+    avoid_orphan_roots: true,
+};
+
+/// Renders as:
+///
+/// ```text
+///  0 logs
+///  1 ├── api
+///  2 │   ├── started
+///  3 │   └── auth
+///  4 │       ├── login
+///  5 │       └── logout
+///  6 ├── db
+///  7 │   ├── connected
+///  8 │   └── slow
+///  9 │       query
+/// 10 └── idle
+/// ```
+fn fixture() -> Tree {
+    Tree::new().root("logs").child(vec![
+        Tree::new()
+            .root("api")
+            .child(vec![
+                "started".into(),
+                Tree::new()
+                    .root("auth")
+                    .child(vec!["login".into(), "logout".into()])
+                    .into(),
+                Leaf::new("debug", true).into(),
+            ])
+            .into(),
+        Tree::new()
+            .root("db")
+            .child(vec!["connected".into(), "slow\nquery".into()])
+            .into(),
+        "idle".into(),
+    ])
+}
+
+fn slice(tree: &Tree, first: usize, count: usize) -> String {
+    tree.to_string()
+        .split('\n')
+        .skip(first)
+        .take(count)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[test]
+fn test_window_matches_full_render() {
+    let tree = fixture();
+    let total = tree.to_string().lines().count();
+    for first in 0..=total {
+        for count in 0..=total - first + 1 {
+            assert_eq!(
+                tree.render_window(first, count, WindowOpts::default()),
+                slice(&tree, first, count),
+                "window {}+{}",
+                first,
+                count
+            );
+        }
+    }
+}
+
+#[test]
+fn test_window_past_end() {
+    let tree = fixture();
+    assert_eq!(tree.render_window(11, 5, AVOID), "");
+    assert_eq!(tree.render_window(9, 5, AVOID), slice(&tree, 9, 2));
+    assert_eq!(tree.render_window(0, 0, AVOID), "");
+    assert_eq!(Tree::new().render_window(0, 3, AVOID), "");
+}
+
+#[test]
+fn test_window_ending_on_subtree_root_shifts_up() {
+    let tree = fixture();
+    // Lines 2..=3 end on "auth", whose children start on line 4.
+    assert_eq!(tree.render_window(2, 2, AVOID), slice(&tree, 1, 2));
+    // Lines 4..=6 end on "db".
+    assert_eq!(tree.render_window(4, 3, AVOID), slice(&tree, 3, 3));
+    // Ending on a leaf or a last child is left alone.
+    assert_eq!(tree.render_window(3, 3, AVOID), slice(&tree, 3, 3));
+    assert_eq!(tree.render_window(8, 3, AVOID), slice(&tree, 8, 3));
+}
+
+#[test]
+fn test_window_starting_on_first_child_shifts_up() {
+    let tree = fixture();
+    // Line 4 is "login", the first child of "auth".
+    assert_eq!(tree.render_window(4, 3, AVOID), slice(&tree, 3, 3));
+    // Line 7 is "connected", the first child of "db".
+    assert_eq!(tree.render_window(7, 3, AVOID), slice(&tree, 6, 3));
+    // Line 5, the second child, and line 8, a multi-line leaf, stay put.
+    assert_eq!(tree.render_window(5, 3, AVOID), slice(&tree, 5, 3));
+    assert_eq!(tree.render_window(8, 2, AVOID), slice(&tree, 8, 2));
+    // The continuation line of a multi-line item is not a child.
+    assert_eq!(tree.render_window(9, 2, AVOID), slice(&tree, 9, 2));
+}
+
+#[test]
+fn test_window_at_top_is_not_shifted() {
+    let tree = fixture();
+    // "logs" then "api" with its first child clipped, but nothing is above.
+    assert_eq!(tree.render_window(0, 2, AVOID), slice(&tree, 0, 2));
+    // Starting on "api", the root's first child, pulls the root in.
+    assert_eq!(tree.render_window(1, 2, AVOID), slice(&tree, 0, 2));
+}
+
+#[test]
+fn test_window_container_children_belong_to_previous_item() {
+    // An unnamed container's children render under "a".
+    let tree = Tree::new().root("r").child(vec![
+        "a".into(),
+        Tree::new().child(vec!["x".into(), "y".into()]).into(),
+        "b".into(),
+    ]);
+    assert_eq!(tree.to_string(), "r\n├── a\n│   ├── x\n│   └── y\n└── b");
+    assert_eq!(tree.render_window(1, 1, AVOID), "r");
+    assert_eq!(tree.render_window(2, 2, AVOID), "├── a\n│   ├── x");
+    assert_eq!(tree.render_window(3, 2, AVOID), "│   └── y\n└── b");
+}
+
+#[test]
+fn test_window_keeps_styles() {
+    let tree = fixture()
+        .root_style(Style::new().bold(true))
+        .enumerator_style(Style::new().foreground(Color::from("63")).padding_right(1));
+    let full = tree.to_string();
+    let window = tree.render_window(3, 4, WindowOpts::default());
+    assert_eq!(window, slice(&tree, 3, 4));
+    assert_eq!(
+        strip_ansi(&window),
+        strip_ansi(
+            &full
+                .split('\n')
+                .skip(3)
+                .take(4)
+                .collect::<Vec<_>>()
+                .join("\n")
+        )
+    );
+}
+
+#[test]
+fn test_window_to_the_end_with_unbounded_count() {
+    let tree = fixture();
+    for first in 0..=11 {
+        assert_eq!(
+            tree.render_window(first, usize::MAX, AVOID),
+            tree.render_window(first, 11, AVOID)
+        );
+        assert_eq!(
+            tree.render_window(first, usize::MAX, WindowOpts::default()),
+            slice(&tree, first, usize::MAX)
+        );
+    }
+}
@@ -56,6 +56,33 @@ fn benchmark_render_performance(c: &mut Criterion) {
     // });
 }
 
+fn benchmark_render_to(c: &mut Criterion) {
+    let style = Style::new().bold(true).foreground("red").padding_left(1);
+    let fragments: Vec<String> = (0..10_000).map(|i| format!("item {}", i)).collect();
+
+    // Compose a frame from many short fragments, as a render loop would
+    c.bench_function("render_10k_fragments", |b| {
+        b.iter(|| {
+            let mut frame = String::new();
+            for f in &fragments {
+                frame.push_str(&style.render(black_box(f)));
+            }
+            black_box(frame)
+        })
+    });
+
+    c.bench_function("render_to_10k_fragments", |b| {
+        let mut frame = String::new();
+        b.iter(|| {
+            frame.clear();
+            for f in &fragments {
+                style.render_to(&mut frame, black_box(f)).unwrap();
+            }
+            black_box(frame.len())
+        })
+    });
+}
+
 fn benchmark_style_ranges(c: &mut Criterion) {
     let text = "The quick brown fox jumps over the lazy dog. ".repeat(20);
     let bold_style = Style::new().bold(true);
@@ -102,6 +129,7 @@ criterion_group!(
     benches,
     benchmark_style_comparison,
     benchmark_render_performance,
+    benchmark_render_to,
     benchmark_style_ranges,
     benchmark_dimension_validation,
     benchmark_safe_repeat
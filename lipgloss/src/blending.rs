@@ -3,7 +3,10 @@
 //! This module provides functions for blending multiple colors in one or two dimensions
 //! using the perceptually uniform CIELAB color space, matching the Go lipgloss implementation.
 
-use crate::color::{Color, TerminalColor};
+use crate::color::{
+    rgb_to_ansi16, rgb_to_ansi256, AdaptiveColor, Color, CompleteAdaptiveColor, CompleteColor,
+    TerminalColor,
+};
 use palette::{Clamp, FromColor, Lab, Srgb};
 use std::f64;
 
@@ -344,6 +347,95 @@ pub fn blend_2d(width: usize, height: usize, angle: f64, stops: Vec<Color>) -> V
     result
 }
 
+/// Blends adaptive colors in one linear dimension, keeping the gradient
+/// theme-aware.
+///
+/// The light and dark variants of the stops are blended independently with
+/// [`blend_1d`], so each step still picks its variant from the terminal
+/// background at render time. Since [`AdaptiveColor`] only holds static
+/// strings, the steps are returned as [`CompleteAdaptiveColor`]s, with the
+/// 256- and 16-color values mapped from the blended true color.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::{blending::{blend_1d, blend_1d_adaptive}, color::AdaptiveColor, Color};
+///
+/// let from = AdaptiveColor { Light: "#7c3aed", Dark: "#a855f7" };
+/// let to = AdaptiveColor { Light: "#0891b2", Dark: "#06b6d4" };
+/// let gradient = blend_1d_adaptive(4, vec![from, to]);
+///
+/// let lights = blend_1d(4, vec![Color::from("#7c3aed"), Color::from("#0891b2")]);
+/// assert_eq!(gradient.len(), 4);
+/// assert_eq!(gradient[1].light.TrueColor, lights[1].0);
+/// ```
+pub fn blend_1d_adaptive(steps: usize, stops: Vec<AdaptiveColor>) -> Vec<CompleteAdaptiveColor> {
+    let (lights, darks) = split_adaptive(&stops);
+    zip_adaptive(blend_1d(steps, lights), blend_1d(steps, darks))
+}
+
+/// Blends adaptive colors in two linear dimensions, keeping the gradient
+/// theme-aware.
+///
+/// The two-dimensional counterpart of [`blend_1d_adaptive`]: the light and
+/// dark variants are blended independently with [`blend_2d`], and the
+/// colors are returned in row-major order.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::{blending::blend_2d_adaptive, color::AdaptiveColor};
+///
+/// let from = AdaptiveColor { Light: "#7c3aed", Dark: "#a855f7" };
+/// let to = AdaptiveColor { Light: "#0891b2", Dark: "#06b6d4" };
+/// let gradient = blend_2d_adaptive(3, 2, 45.0, vec![from, to]);
+/// assert_eq!(gradient.len(), 6); // 3 * 2
+/// ```
+pub fn blend_2d_adaptive(
+    width: usize,
+    height: usize,
+    angle: f64,
+    stops: Vec<AdaptiveColor>,
+) -> Vec<CompleteAdaptiveColor> {
+    let (lights, darks) = split_adaptive(&stops);
+    zip_adaptive(
+        blend_2d(width, height, angle, lights),
+        blend_2d(width, height, angle, darks),
+    )
+}
+
+/// Splits adaptive stops into their light and dark sequences.
+fn split_adaptive(stops: &[AdaptiveColor]) -> (Vec<Color>, Vec<Color>) {
+    stops
+        .iter()
+        .map(|c| (Color::from(c.Light), Color::from(c.Dark)))
+        .unzip()
+}
+
+/// Pairs blended light and dark colors step by step.
+fn zip_adaptive(lights: Vec<Color>, darks: Vec<Color>) -> Vec<CompleteAdaptiveColor> {
+    lights
+        .into_iter()
+        .zip(darks)
+        .map(|(light, dark)| CompleteAdaptiveColor {
+            light: complete_color(light),
+            dark: complete_color(dark),
+        })
+        .collect()
+}
+
+/// Fills in every profile of a blended color, mapping the lower profiles
+/// from its true color.
+fn complete_color(color: Color) -> CompleteColor {
+    let (r, g, b, _) = color.rgba();
+    let (r, g, b) = (r as u8, g as u8, b as u8);
+    CompleteColor {
+        ANSI256: rgb_to_ansi256(r, g, b).to_string(),
+        ANSI: rgb_to_ansi16(r, g, b).to_string(),
+        TrueColor: color.0,
+    }
+}
+
 /// Ensures that a color is not completely transparent.
 ///
 /// If the alpha value is 0, sets it to 1. This is useful for gradient purposes
@@ -449,6 +541,50 @@ mod tests {
         assert_eq!(gradient2.len(), 4);
     }
 
+    #[test]
+    fn test_blend_adaptive_blends_light_and_dark_independently() {
+        let from = AdaptiveColor {
+            Light: "#ff0000",
+            Dark: "#00ff00",
+        };
+        let to = AdaptiveColor {
+            Light: "#0000ff",
+            Dark: "#ffffff",
+        };
+
+        let gradient = blend_1d_adaptive(5, vec![from.clone(), to.clone()]);
+        let lights = blend_1d(5, vec![Color::from(from.Light), Color::from(to.Light)]);
+        let darks = blend_1d(5, vec![Color::from(from.Dark), Color::from(to.Dark)]);
+        assert_eq!(gradient.len(), 5);
+        for (i, step) in gradient.iter().enumerate() {
+            assert_eq!(step.light.TrueColor, lights[i].0);
+            assert_eq!(step.dark.TrueColor, darks[i].0);
+        }
+        assert_eq!(gradient[0].light.ANSI256, "196");
+        assert_eq!(gradient[4].dark.ANSI, "15");
+
+        let gradient = blend_2d_adaptive(3, 2, 90.0, vec![from.clone(), to.clone()]);
+        let lights = blend_2d(
+            3,
+            2,
+            90.0,
+            vec![Color::from(from.Light), Color::from(to.Light)],
+        );
+        let darks = blend_2d(
+            3,
+            2,
+            90.0,
+            vec![Color::from(from.Dark), Color::from(to.Dark)],
+        );
+        assert_eq!(gradient.len(), 6);
+        for (i, step) in gradient.iter().enumerate() {
+            assert_eq!(step.light.TrueColor, lights[i].0);
+            assert_eq!(step.dark.TrueColor, darks[i].0);
+        }
+
+        assert!(blend_1d_adaptive(3, vec![]).is_empty());
+    }
+
     #[test]
     fn test_clamp() {
         assert_eq!(clamp(5, 0, 10), 5);
@@ -279,6 +279,135 @@ impl Border {
     pub fn get_left_size(&self) -> usize {
         get_border_edge_width(&[self.top_left, self.left, self.bottom_left])
     }
+
+    /// Returns the horizontal rule used between interior rows.
+    ///
+    /// `Border` has no dedicated field for interior horizontal lines, so this
+    /// derives one from the `middle` junction: a light cross (`┼`) yields `─`,
+    /// a heavy cross (`╋`) yields `━`, a double cross (`╬`) yields `═`, and so
+    /// on. Unrecognized junctions fall back to `top`, which matches the
+    /// behavior of borders whose edges and interior share one glyph.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipgloss::{double_border, normal_border, Border};
+    ///
+    /// assert_eq!(normal_border().middle_horizontal(), "─");
+    /// assert_eq!(double_border().middle_horizontal(), "═");
+    ///
+    /// // A heavy outer frame with light interior rules.
+    /// let mixed = Border::new(
+    ///     "═", "═", "║", "║", "╔", "╗", "╚", "╝", "╟", "╢", "┼", "╤", "╧",
+    /// );
+    /// assert_eq!(mixed.middle_horizontal(), "─");
+    /// ```
+    pub fn middle_horizontal(&self) -> &'static str {
+        match self.middle {
+            "┼" | "╂" | "╫" | "╀" | "╁" => "─",
+            "╋" | "┿" | "╇" | "╈" => "━",
+            "╬" | "╪" => "═",
+            "+" => "-",
+            _ => self.top,
+        }
+    }
+}
+
+/// Names one of the thirteen glyphs that make up a [`Border`].
+///
+/// Used with [`Style::border_char`](crate::Style::border_char) to override a
+/// single piece of whichever border a style uses.
+///
+/// # Examples
+///
+/// ```
+/// use lipgloss::{rounded_border, BorderPiece};
+///
+/// assert_eq!(rounded_border().piece(BorderPiece::TopLeft), "╭");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BorderPiece {
+    /// The top edge, [`Border::top`].
+    Top,
+    /// The bottom edge, [`Border::bottom`].
+    Bottom,
+    /// The left edge, [`Border::left`].
+    Left,
+    /// The right edge, [`Border::right`].
+    Right,
+    /// The top-left corner, [`Border::top_left`].
+    TopLeft,
+    /// The top-right corner, [`Border::top_right`].
+    TopRight,
+    /// The bottom-left corner, [`Border::bottom_left`].
+    BottomLeft,
+    /// The bottom-right corner, [`Border::bottom_right`].
+    BottomRight,
+    /// The left-side junction, [`Border::middle_left`].
+    MiddleLeft,
+    /// The right-side junction, [`Border::middle_right`].
+    MiddleRight,
+    /// The cross junction, [`Border::middle`].
+    Middle,
+    /// The top junction, [`Border::middle_top`].
+    MiddleTop,
+    /// The bottom junction, [`Border::middle_bottom`].
+    MiddleBottom,
+}
+
+impl BorderPiece {
+    /// Every piece, in [`Border`] field order.
+    pub const ALL: [BorderPiece; 13] = [
+        BorderPiece::Top,
+        BorderPiece::Bottom,
+        BorderPiece::Left,
+        BorderPiece::Right,
+        BorderPiece::TopLeft,
+        BorderPiece::TopRight,
+        BorderPiece::BottomLeft,
+        BorderPiece::BottomRight,
+        BorderPiece::MiddleLeft,
+        BorderPiece::MiddleRight,
+        BorderPiece::Middle,
+        BorderPiece::MiddleTop,
+        BorderPiece::MiddleBottom,
+    ];
+
+    /// Position of this piece in [`BorderPiece::ALL`].
+    pub(crate) const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+impl Border {
+    /// Returns the glyph this border uses for `piece`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipgloss::{normal_border, BorderPiece};
+    ///
+    /// let border = normal_border();
+    /// assert_eq!(border.piece(BorderPiece::Middle), "┼");
+    /// assert_eq!(border.piece(BorderPiece::Left), border.left);
+    /// ```
+    pub const fn piece(&self, piece: BorderPiece) -> &'static str {
+        match piece {
+            BorderPiece::Top => self.top,
+            BorderPiece::Bottom => self.bottom,
+            BorderPiece::Left => self.left,
+            BorderPiece::Right => self.right,
+            BorderPiece::TopLeft => self.top_left,
+            BorderPiece::TopRight => self.top_right,
+            BorderPiece::BottomLeft => self.bottom_left,
+            BorderPiece::BottomRight => self.bottom_right,
+            BorderPiece::MiddleLeft => self.middle_left,
+            BorderPiece::MiddleRight => self.middle_right,
+            BorderPiece::Middle => self.middle,
+            BorderPiece::MiddleTop => self.middle_top,
+            BorderPiece::MiddleBottom => self.middle_bottom,
+        }
+    }
 }
 
 /// Returns a border using standard single-line box-drawing characters.
@@ -862,4 +991,28 @@ mod tests {
         assert_eq!(hb_inner.middle_top, "");
         assert_eq!(hb_inner.middle_bottom, "");
     }
+
+    #[test]
+    fn piece_follows_field_order() {
+        let b = normal_border();
+        let fields = [
+            b.top,
+            b.bottom,
+            b.left,
+            b.right,
+            b.top_left,
+            b.top_right,
+            b.bottom_left,
+            b.bottom_right,
+            b.middle_left,
+            b.middle_right,
+            b.middle,
+            b.middle_top,
+            b.middle_bottom,
+        ];
+        for (i, piece) in BorderPiece::ALL.into_iter().enumerate() {
+            assert_eq!(piece.index(), i);
+            assert_eq!(b.piece(piece), fields[i], "{:?}", piece);
+        }
+    }
 }
@@ -45,6 +45,8 @@
 use crate::renderer::{default_renderer, ColorProfileKind, Renderer};
 use palette::color_difference::EuclideanDistance;
 use palette::{Clamp, FromColor, Hsv, Lab, Srgb};
+use std::fmt;
+use std::sync::{Arc, OnceLock};
 
 /// A color intended to be rendered in the terminal.
 ///
@@ -137,6 +139,17 @@ pub trait TerminalColor {
     {
         self.token(default_renderer())
     }
+
+    /// Captures this color so it can be resolved when rendering.
+    ///
+    /// Styles store colors in this form and only turn them into tokens at
+    /// render time, against the renderer in use at that point. The default
+    /// implementation records the token for every color profile and
+    /// background, which is exact for any color whose token depends only on
+    /// those two settings. The built-in color types keep themselves instead.
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::snapshot(self)
+    }
 }
 
 // Convenience: allow using &str and String directly as colors in Style builders.
@@ -147,6 +160,9 @@ impl TerminalColor for &str {
     fn rgba(&self) -> (u32, u32, u32, u32) {
         Color::from(*self).rgba()
     }
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(Color::from(*self))
+    }
 }
 
 impl TerminalColor for String {
@@ -156,6 +172,125 @@ impl TerminalColor for String {
     fn rgba(&self) -> (u32, u32, u32, u32) {
         Color::from(self.as_str()).rgba()
     }
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(Color::from(self.as_str()))
+    }
+}
+
+/// A color held by a [`Style`](crate::Style) until it is rendered.
+///
+/// Unlike a token, a `StyleColor` is not tied to a color profile: it is
+/// resolved against the active renderer each time it is rendered, so changing
+/// the profile or background after a style is built still takes effect, and
+/// per-profile values such as [`CompleteColor`]'s are honored.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::color::{CompleteColor, StyleColor, TerminalColor};
+/// use lipgloss::renderer::{ColorProfileKind, Renderer};
+///
+/// let color = StyleColor::new(CompleteColor {
+///     TrueColor: "#ff0000".to_string(),
+///     ANSI256: "196".to_string(),
+///     ANSI: "9".to_string(),
+/// });
+///
+/// let mut r = Renderer::new();
+/// r.set_color_profile(ColorProfileKind::ANSI);
+/// assert_eq!(color.token(&r), "9");
+/// r.set_color_profile(ColorProfileKind::TrueColor);
+/// assert_eq!(color.token(&r), "#ff0000");
+/// ```
+#[derive(Clone)]
+pub struct StyleColor(Arc<dyn TerminalColor + Send + Sync>);
+
+impl StyleColor {
+    /// Wraps a color without resolving it.
+    pub fn new<C: TerminalColor + Send + Sync + 'static>(color: C) -> Self {
+        StyleColor(Arc::new(color))
+    }
+
+    /// Records the tokens of any color for every profile and background.
+    fn snapshot<C: TerminalColor + ?Sized>(color: &C) -> Self {
+        let tokens = probe_renderers()
+            .iter()
+            .map(|r| color.token(r))
+            .collect::<Vec<_>>();
+        StyleColor(Arc::new(ColorSnapshot {
+            tokens,
+            rgba: color.rgba(),
+        }))
+    }
+}
+
+impl TerminalColor for StyleColor {
+    fn token(&self, r: &Renderer) -> String {
+        self.0.token(r)
+    }
+
+    fn rgba(&self) -> (u32, u32, u32, u32) {
+        self.0.rgba()
+    }
+
+    fn to_style_color(&self) -> StyleColor {
+        self.clone()
+    }
+}
+
+impl fmt::Debug for StyleColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Show the most precise form: true color on a dark background.
+        f.debug_tuple("StyleColor")
+            .field(&self.0.token(&probe_renderers()[0]))
+            .finish()
+    }
+}
+
+/// Profiles captured by [`ColorSnapshot`], in `probe_renderers` order.
+const PROBE_PROFILES: [ColorProfileKind; 4] = [
+    ColorProfileKind::TrueColor,
+    ColorProfileKind::ANSI256,
+    ColorProfileKind::ANSI,
+    ColorProfileKind::NoColor,
+];
+
+/// One renderer per profile and background, dark before light.
+fn probe_renderers() -> &'static [Renderer] {
+    static PROBES: OnceLock<Vec<Renderer>> = OnceLock::new();
+    PROBES.get_or_init(|| {
+        let mut probes = Vec::with_capacity(PROBE_PROFILES.len() * 2);
+        for profile in PROBE_PROFILES {
+            for dark in [true, false] {
+                let mut r = Renderer::new();
+                r.set_color_profile(profile);
+                r.set_has_dark_background(dark);
+                probes.push(r);
+            }
+        }
+        probes
+    })
+}
+
+/// Tokens of a foreign [`TerminalColor`] for every probe renderer.
+struct ColorSnapshot {
+    tokens: Vec<String>,
+    rgba: (u32, u32, u32, u32),
+}
+
+impl TerminalColor for ColorSnapshot {
+    fn token(&self, r: &Renderer) -> String {
+        let profile = PROBE_PROFILES
+            .iter()
+            .position(|p| *p == r.color_profile())
+            .unwrap_or(0);
+        let idx = profile * 2 + usize::from(!r.has_dark_background());
+        self.tokens.get(idx).cloned().unwrap_or_default()
+    }
+
+    fn rgba(&self) -> (u32, u32, u32, u32) {
+        self.rgba
+    }
 }
 
 /// Maps an sRGB 8-bit color to the nearest ANSI256 index using termenv's exact algorithm.
@@ -261,7 +396,7 @@ fn dist2(r1: u8, g1: u8, b1: u8, r2: u8, g2: u8, b2: u8) -> u32 {
 /// # Returns
 ///
 /// RGB tuple (red, green, blue) with values 0-255
-fn ansi256_to_rgb_u8(idx: u8) -> (u8, u8, u8) {
+pub(crate) fn ansi256_to_rgb_u8(idx: u8) -> (u8, u8, u8) {
     match idx {
         0..=15 => ANSI16_RGB[idx as usize],
         16..=231 => {
@@ -383,6 +518,14 @@ const ANSI16_RGB: [(u8, u8, u8); 16] = [
 /// // Returns "196" for ANSI256 terminals
 /// ```
 pub(crate) fn resolve_color_token_for_profile(s: &str, profile: ColorProfileKind) -> String {
+    // Named colors stay palette indices under every profile, so the
+    // terminal's theme decides what they look like.
+    if let Some(idx) = ansi_color_index(s) {
+        return match profile {
+            ColorProfileKind::NoColor => String::new(),
+            _ => idx.to_string(),
+        };
+    }
     match profile {
         ColorProfileKind::NoColor => String::new(),
         ColorProfileKind::TrueColor => {
@@ -433,6 +576,59 @@ pub(crate) fn resolve_color_token_for_profile(s: &str, profile: ColorProfileKind
     }
 }
 
+/// The names of the 16 standard ANSI colors, in palette order.
+pub const ANSI_COLOR_NAMES: [&str; 16] = [
+    "black",
+    "red",
+    "green",
+    "yellow",
+    "blue",
+    "magenta",
+    "cyan",
+    "white",
+    "bright-black",
+    "bright-red",
+    "bright-green",
+    "bright-yellow",
+    "bright-blue",
+    "bright-magenta",
+    "bright-cyan",
+    "bright-white",
+];
+
+/// Looks up the palette index of one of the 16 standard ANSI color names.
+///
+/// Names are matched case-insensitively: `"red"` is 1 and `"bright-blue"`
+/// is 12, see [`ANSI_COLOR_NAMES`]. A [`Color`] or [`CompleteColor`] field
+/// holding a name renders as that palette index under every color profile,
+/// including true color, rather than as a fixed RGB value, so the
+/// terminal's theme applies to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::color::ansi_color_index;
+///
+/// assert_eq!(ansi_color_index("red"), Some(1));
+/// assert_eq!(ansi_color_index("Bright-Blue"), Some(12));
+/// assert_eq!(ansi_color_index("#ff0000"), None);
+/// ```
+pub fn ansi_color_index(name: &str) -> Option<u8> {
+    ANSI_COLOR_NAMES
+        .iter()
+        .position(|n| n.eq_ignore_ascii_case(name))
+        .map(|i| i as u8)
+}
+
+/// Replaces a color name with its palette index, leaving other strings as
+/// they are.
+fn resolve_color_name(s: &str) -> String {
+    match ansi_color_index(s) {
+        Some(idx) => idx.to_string(),
+        None => s.to_string(),
+    }
+}
+
 impl Color {
     /// Returns the color token for a specific renderer with color profile mapping.
     ///
@@ -469,13 +665,17 @@ impl TerminalColor for Color {
     fn rgba(&self) -> (u32, u32, u32, u32) {
         if let Some((r, g, b, a)) = parse_hex_rgba(&self.0) {
             (r, g, b, a)
-        } else if let Ok(idx) = self.0.parse::<u32>() {
+        } else if let Ok(idx) = resolve_color_name(&self.0).parse::<u32>() {
             let (r, g, b) = ansi256_to_rgb_u8((idx % 256) as u8);
             (r as u32, g as u32, b as u32, 0xFFFF)
         } else {
             (0x0, 0x0, 0x0, 0xFFFF)
         }
     }
+
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(self.clone())
+    }
 }
 
 #[cfg(test)]
@@ -735,6 +935,71 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_named_colors_render_as_palette_indices() {
+        use crate::{
+            renderer::{ColorProfileKind, Renderer},
+            Style,
+        };
+
+        let profiles = [
+            ColorProfileKind::TrueColor,
+            ColorProfileKind::ANSI256,
+            ColorProfileKind::ANSI,
+        ];
+        for (idx, name) in ANSI_COLOR_NAMES.iter().enumerate() {
+            let idx = idx as u32;
+            for profile in profiles {
+                let mut r = Renderer::new();
+                r.set_color_profile(profile);
+                let (fg, bg) = match profile {
+                    ColorProfileKind::ANSI if idx < 8 => {
+                        ((30 + idx).to_string(), (40 + idx).to_string())
+                    }
+                    ColorProfileKind::ANSI => ((82 + idx).to_string(), (92 + idx).to_string()),
+                    _ => (format!("38;5;{}", idx), format!("48;5;{}", idx)),
+                };
+
+                for name in [name.to_string(), name.to_uppercase()] {
+                    assert_eq!(Color::from(name.as_str()).token(&r), idx.to_string());
+                    let style = Style::new().renderer(r.clone());
+                    assert_eq!(
+                        style.clone().foreground(name.as_str()).render("x"),
+                        format!("\x1b[{}mx\x1b[0m", fg),
+                        "{} under {:?}",
+                        name,
+                        profile
+                    );
+                    assert_eq!(
+                        style.background(name.as_str()).render("x"),
+                        format!("\x1b[{}mx\x1b[0m", bg),
+                        "{} under {:?}",
+                        name,
+                        profile
+                    );
+
+                    let complete = CompleteColor {
+                        TrueColor: name.clone(),
+                        ANSI256: name.clone(),
+                        ANSI: name.clone(),
+                    };
+                    assert_eq!(complete.token(&r), idx.to_string());
+                }
+            }
+
+            let (r, g, b) = ansi256_to_rgb_u8(idx as u8);
+            assert_eq!(
+                Color::from(*name).rgba(),
+                (r as u32, g as u32, b as u32, 0xFFFF)
+            );
+        }
+
+        let mut r = Renderer::new();
+        r.set_color_profile(ColorProfileKind::NoColor);
+        assert_eq!(Color::from("red").token(&r), "");
+        assert_eq!(ansi_color_index("brightred"), None);
+    }
+
     #[test]
     fn test_hex_to_color_conversion() {
         let test_cases = [
@@ -1186,6 +1451,10 @@ impl TerminalColor for NoColor {
         // Black, 100% opacity
         (0x0, 0x0, 0x0, 0xFFFF)
     }
+
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(*self)
+    }
 }
 
 /// A color specified by hex or ANSI/ANSI256 value as a string.
@@ -1194,9 +1463,13 @@ impl TerminalColor for NoColor {
 /// - Hex colors: `#RGB`, `#RRGGBB`, `#RGBA`, `#RRGGBBAA`
 /// - ANSI colors: `"0"` through `"15"` (basic ANSI colors)
 /// - ANSI256 colors: `"0"` through `"255"` (extended palette)
+/// - Color names: the 16 standard names such as `"red"` or `"bright-blue"`,
+///   in any case (see [`ANSI_COLOR_NAMES`])
 ///
 /// The color will be automatically converted to the appropriate format based
-/// on the terminal's color profile capabilities.
+/// on the terminal's color profile capabilities. Named colors are the
+/// exception: they always render as their palette index, even on true color
+/// terminals, so they follow the terminal's theme.
 ///
 /// # Examples
 ///
@@ -1300,7 +1573,7 @@ impl Color {
         if let Some((r, g, b, a)) = parse_hex_rgba_8bit(&self.0) {
             // Convert 8-bit values to true 16-bit for Go compatibility
             srgb_to_true_rgba16(Srgb::new(r as u8, g as u8, b as u8), a as u8)
-        } else if let Ok(idx) = self.0.parse::<u32>() {
+        } else if let Ok(idx) = resolve_color_name(&self.0).parse::<u32>() {
             let (r, g, b) = ansi256_to_rgb_u8((idx % 256) as u8);
             srgb_to_true_rgba16(Srgb::new(r, g, b), 255)
         } else {
@@ -1349,6 +1622,10 @@ impl TerminalColor for ANSIColor {
         let (r, g, b) = ansi256_to_rgb_u8((self.0 % 256) as u8);
         (r as u32, g as u32, b as u32, 0xFFFF)
     }
+
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(*self)
+    }
 }
 
 /// Provides color options for light and dark backgrounds.
@@ -1408,6 +1685,10 @@ impl TerminalColor for AdaptiveColor {
         };
         Color::from(color_str).rgba()
     }
+
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(self.clone())
+    }
 }
 
 /// Specifies exact color values for all terminal color profiles.
@@ -1419,7 +1700,8 @@ impl TerminalColor for AdaptiveColor {
 ///
 /// Unlike other color types that perform automatic conversion, CompleteColor
 /// uses the exact values you specify for each profile, giving you full
-/// control over the color appearance.
+/// control over the color appearance. Any field may hold one of the 16
+/// standard color names instead of a number, such as `ANSI: "bright-red"`.
 ///
 /// # Examples
 ///
@@ -1456,9 +1738,9 @@ pub struct CompleteColor {
 impl TerminalColor for CompleteColor {
     fn token(&self, r: &Renderer) -> String {
         match r.color_profile() {
-            ColorProfileKind::TrueColor => self.TrueColor.clone(),
-            ColorProfileKind::ANSI256 => self.ANSI256.clone(),
-            ColorProfileKind::ANSI => self.ANSI.clone(),
+            ColorProfileKind::TrueColor => resolve_color_name(&self.TrueColor),
+            ColorProfileKind::ANSI256 => resolve_color_name(&self.ANSI256),
+            ColorProfileKind::ANSI => resolve_color_name(&self.ANSI),
             ColorProfileKind::NoColor => String::new(),
         }
     }
@@ -1471,6 +1753,10 @@ impl TerminalColor for CompleteColor {
             (0x0, 0x0, 0x0, 0xFFFF)
         }
     }
+
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(self.clone())
+    }
 }
 
 /// Provides exact color values for light/dark backgrounds across all profiles.
@@ -1730,6 +2016,10 @@ impl TerminalColor for CompleteAdaptiveColor {
             self.light.rgba()
         }
     }
+
+    fn to_style_color(&self) -> StyleColor {
+        StyleColor::new(self.clone())
+    }
 }
 
 // --- Helper Functions ---
@@ -2081,6 +2371,121 @@ pub fn is_dark_color<C: TerminalColor>(color: &C) -> bool {
     luminance < 127.5 // Midpoint of 0-255
 }
 
+/// Returns the WCAG 2.x relative luminance of a color, from 0.0 (black) to 1.0 (white).
+///
+/// # Arguments
+/// * `color` - The color to measure
+///
+/// # Examples
+/// ```rust
+/// use lipgloss::color::{Color, relative_luminance};
+///
+/// assert_eq!(relative_luminance(&Color::from("#000000")), 0.0);
+/// assert!((relative_luminance(&Color::from("#ffffff")) - 1.0).abs() < 1e-9);
+/// ```
+pub fn relative_luminance<C: TerminalColor>(color: &C) -> f64 {
+    let (r, g, b, _a) = color.rgba();
+    luminance_u8(r as u8, g as u8, b as u8)
+}
+
+/// Returns the WCAG 2.x contrast ratio between two colors, from 1.0 to 21.0.
+///
+/// The order of the arguments does not matter. WCAG recommends at least 4.5
+/// for body text and 3.0 for large text.
+///
+/// # Arguments
+/// * `a` - The first color
+/// * `b` - The second color
+///
+/// # Examples
+/// ```rust
+/// use lipgloss::color::{Color, contrast_ratio};
+///
+/// let black = Color::from("#000000");
+/// let white = Color::from("#ffffff");
+/// assert!((contrast_ratio(&black, &white) - 21.0).abs() < 1e-9);
+/// ```
+pub fn contrast_ratio<A: TerminalColor, B: TerminalColor>(a: &A, b: &B) -> f64 {
+    ratio_of(relative_luminance(a), relative_luminance(b))
+}
+
+fn luminance_u8(r: u8, g: u8, b: u8) -> f64 {
+    let channel = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+fn ratio_of(l1: f64, l2: f64) -> f64 {
+    let (hi, lo) = if l1 >= l2 { (l1, l2) } else { (l2, l1) };
+    (hi + 0.05) / (lo + 0.05)
+}
+
+/// Nudges a foreground token until it reaches `min_ratio` against `bg`.
+///
+/// Both tokens may be hex or numeric palette indices. Candidates are produced
+/// with [`lighten`] (dark backgrounds) or [`darken`] (light backgrounds) and
+/// quantized to `profile` before being measured, so the result is a color the
+/// terminal can actually show. Faint text is measured as the foreground blended
+/// halfway toward the background, approximating how terminals dim it.
+///
+/// Returns the original token when it already meets the ratio, otherwise the
+/// first candidate that does, or the best candidate found.
+pub(crate) fn ensure_contrast(
+    fg: &str,
+    bg: &str,
+    faint: bool,
+    min_ratio: f64,
+    profile: ColorProfileKind,
+) -> String {
+    let bg_color = Color(bg.to_string());
+    let (br, bgc, bb, _) = bg_color.rgba();
+    let bg_lum = luminance_u8(br as u8, bgc as u8, bb as u8);
+    let measure = |token: &str| {
+        let (r, g, b, _) = Color(token.to_string()).rgba();
+        let (r, g, b) = if faint {
+            (
+                ((r + br) / 2) as u8,
+                ((g + bgc) / 2) as u8,
+                ((b + bb) / 2) as u8,
+            )
+        } else {
+            (r as u8, g as u8, b as u8)
+        };
+        ratio_of(luminance_u8(r, g, b), bg_lum)
+    };
+
+    let mut best = (measure(fg), fg.to_string());
+    if best.0 >= min_ratio {
+        return best.1;
+    }
+
+    let start = Color(fg.to_string());
+    let dark_bg = is_dark_color(&bg_color);
+    for step in 1..=20 {
+        let percent = step as f64 * 0.05;
+        let candidate = if dark_bg {
+            lighten(&start, percent)
+        } else {
+            darken(&start, percent)
+        };
+        let token = resolve_color_token_for_profile(&candidate.0, profile);
+        let ratio = measure(&token);
+        if ratio > best.0 {
+            best = (ratio, token);
+        }
+        if best.0 >= min_ratio {
+            break;
+        }
+    }
+    best.1
+}
+
 /// A function type that returns a color based on whether the terminal has a light or dark background.
 ///
 /// This type alias represents a boxed closure that takes two color references
@@ -4,7 +4,7 @@
 //! perceptually uniform color spaces, enabling the creation of beautiful
 //! gradients in terminal user interfaces.
 
-use crate::color::Color;
+use crate::color::{Color, TerminalColor};
 use palette::{FromColor, Lab, Mix, Srgb};
 
 /// Creates a gradient of colors by blending two sRGB colors.
@@ -172,6 +172,81 @@ pub fn bilinear_interpolation_grid(
         .collect()
 }
 
+/// Creates a function that maps numbers in a range to colors, for heatmaps.
+///
+/// `stops` place colors along the range, each at a position from `0.0`
+/// (`min`) to `1.0` (`max`), and need not be sorted. Values between two stops
+/// are blended in the CIE L*a*b* color space; a value exactly at a stop gets
+/// that stop's color unchanged. Values outside the range are clamped to the
+/// nearest end. `min` may be greater than `max` for a scale that runs the
+/// other way, e.g. green for high values. When `min == max` every value maps
+/// to the lowest stop.
+///
+/// # Arguments
+///
+/// * `min` - The value mapped to position `0.0`.
+/// * `max` - The value mapped to position `1.0`.
+/// * `stops` - `(position, color)` pairs along the range.
+///
+/// # Returns
+///
+/// A function from a value to its color. It returns `Color::from("")`
+/// (no color) when `stops` is empty.
+///
+/// # Example
+///
+/// ```
+/// use lipgloss::color::Color;
+/// use lipgloss::gradient::value_mapper;
+///
+/// let heat = value_mapper(
+///     0.0,
+///     100.0,
+///     &[(0.0, Color::from("#00ff00")), (1.0, Color::from("#ff0000"))],
+/// );
+/// assert_eq!(heat(0.0), Color::from("#00ff00"));
+/// assert_eq!(heat(250.0), Color::from("#ff0000"));
+/// ```
+pub fn value_mapper(min: f64, max: f64, stops: &[(f64, Color)]) -> impl Fn(f64) -> Color {
+    let mut stops: Vec<(f64, Color)> = stops
+        .iter()
+        .filter(|(position, _)| !position.is_nan())
+        .cloned()
+        .collect();
+    stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    move |value| {
+        let (Some(first), Some(last)) = (stops.first(), stops.last()) else {
+            return Color::from("");
+        };
+        let t = if max != min && !value.is_nan() {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        if t <= first.0 {
+            return first.1.clone();
+        }
+        if t >= last.0 {
+            return last.1.clone();
+        }
+
+        let i = stops.partition_point(|(position, _)| *position <= t);
+        let (low, high) = (&stops[i - 1], &stops[i]);
+        if t == low.0 {
+            return low.1.clone();
+        }
+        let factor = ((t - low.0) / (high.0 - low.0)) as f32;
+        let to_lab = |color: &Color| {
+            let (r, g, b, _) = color.rgba();
+            Lab::from_color(Srgb::new(r as u8, g as u8, b as u8).into_format::<f32>())
+        };
+        let blended = Srgb::from_color(to_lab(&low.1).mix(to_lab(&high.1), factor));
+        let blended = blended.into_format::<u8>();
+        Color::from_rgb(blended.red, blended.green, blended.blue)
+    }
+}
+
 /// Helper function to parse hex color strings into Srgb<u8>.
 fn parse_hex_color(hex: &str) -> Srgb<u8> {
     let hex = hex.trim_start_matches('#');
@@ -180,3 +255,53 @@ fn parse_hex_color(hex: &str) -> Srgb<u8> {
     let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0);
     Srgb::new(r, g, b)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stops() -> Vec<(f64, Color)> {
+        vec![
+            (1.0, Color::from("#ff0000")),
+            (0.0, Color::from("#00ff00")),
+            (0.5, Color::from("#ffff00")),
+        ]
+    }
+
+    #[test]
+    fn test_value_mapper_hits_stops_and_clamps() {
+        let heat = value_mapper(0.0, 100.0, &stops());
+        assert_eq!(heat(0.0), Color::from("#00ff00"));
+        assert_eq!(heat(50.0), Color::from("#ffff00"));
+        assert_eq!(heat(100.0), Color::from("#ff0000"));
+        assert_eq!(heat(-5.0), Color::from("#00ff00"));
+        assert_eq!(heat(1e9), Color::from("#ff0000"));
+        assert_eq!(heat(f64::NAN), Color::from("#00ff00"));
+    }
+
+    #[test]
+    fn test_value_mapper_blends_between_stops() {
+        let heat = value_mapper(0.0, 100.0, &stops());
+        let (r, g, b, _) = heat(75.0).rgba();
+        assert_eq!(b, 0);
+        assert!(r > 200 && g > 0 && g < 255, "{:?}", (r, g, b));
+    }
+
+    #[test]
+    fn test_value_mapper_degenerate_inputs() {
+        let flat = value_mapper(10.0, 10.0, &stops());
+        assert_eq!(flat(10.0), Color::from("#00ff00"));
+        assert_eq!(flat(99.0), Color::from("#00ff00"));
+
+        // A reversed range runs the scale the other way.
+        let reversed = value_mapper(100.0, 0.0, &stops());
+        assert_eq!(reversed(100.0), Color::from("#00ff00"));
+        assert_eq!(reversed(50.0), Color::from("#ffff00"));
+        assert_eq!(reversed(0.0), Color::from("#ff0000"));
+        assert_eq!(reversed(-5.0), Color::from("#ff0000"));
+        assert_eq!(reversed(1e9), Color::from("#00ff00"));
+
+        let none = value_mapper(0.0, 1.0, &[]);
+        assert_eq!(none(0.5), Color::from(""));
+    }
+}
@@ -0,0 +1,226 @@
+//! HTML export helpers shared by the table, tree and list crates.
+//!
+//! Enabled with the `html` feature. These helpers map a [`Style`] to inline
+//! CSS and escape text, so components can produce HTML reports from the same
+//! definitions they use for terminal output.
+//!
+//! Colors are written as hex RGB regardless of the terminal's color profile,
+//! and ANSI escape sequences are removed from content before it is escaped.
+//!
+//! ```rust
+//! use lipgloss::{html, Style};
+//!
+//! let style = Style::new().bold(true).foreground("#ff0000");
+//! assert_eq!(html::style_css(&style), "color:#ff0000;font-weight:bold");
+//! assert_eq!(html::escape_text("a < \x1b[1mb\x1b[0m"), "a &lt; b");
+//! ```
+
+use crate::color::{ansi256_to_rgb_u8, StyleColor, TerminalColor};
+use crate::renderer::{ColorProfileKind, Renderer};
+use crate::style::properties::{ALIGN_HORIZONTAL_KEY, UNDERLINE_COLOR_KEY};
+use crate::{Style, UnderlineStyle};
+
+/// Inline CSS for the outermost element of an export: terminal-like
+/// monospace text that keeps spaces and line breaks.
+pub const CONTAINER_CSS: &str = "font-family:monospace;white-space:pre-wrap";
+
+/// Escapes text for use in HTML element content or attribute values.
+///
+/// ANSI escape sequences are stripped first, since they have no meaning
+/// in HTML.
+pub fn escape_text(s: &str) -> String {
+    let plain = crate::utils::strip_ansi(s);
+    let mut out = String::with_capacity(plain.len());
+    for ch in plain.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Returns the inline CSS for a style's text properties.
+///
+/// Covers foreground and background colors, bold, italic, underline (with
+/// its style and color), strikethrough, faint and an explicitly set
+/// horizontal alignment.
+/// Properties are separated by `;` with no trailing separator; the result
+/// is empty when none apply.
+pub fn style_css(style: &Style) -> String {
+    let r = css_renderer();
+    let css_color = |color: &StyleColor| css_color(color, &r);
+    let mut props = Vec::new();
+    let (fg, bg) = if style.get_reverse() {
+        (&style.bg_color, &style.fg_color)
+    } else {
+        (&style.fg_color, &style.bg_color)
+    };
+    if let Some(color) = fg.as_ref().and_then(css_color) {
+        props.push(format!("color:{}", color));
+    }
+    if let Some(color) = bg.as_ref().and_then(css_color) {
+        props.push(format!("background-color:{}", color));
+    }
+    if style.get_bold() {
+        props.push("font-weight:bold".to_string());
+    }
+    if style.get_italic() {
+        props.push("font-style:italic".to_string());
+    }
+    let decorations: Vec<&str> = [
+        (style.get_underline(), "underline"),
+        (style.get_strikethrough(), "line-through"),
+    ]
+    .iter()
+    .filter(|(on, _)| *on)
+    .map(|(_, name)| *name)
+    .collect();
+    if !decorations.is_empty() {
+        props.push(format!("text-decoration:{}", decorations.join(" ")));
+    }
+    if style.get_underline() {
+        let line = match style.get_underline_style() {
+            UnderlineStyle::Single => None,
+            UnderlineStyle::Double => Some("double"),
+            UnderlineStyle::Curly => Some("wavy"),
+            UnderlineStyle::Dotted => Some("dotted"),
+            UnderlineStyle::Dashed => Some("dashed"),
+        };
+        if let Some(line) = line {
+            props.push(format!("text-decoration-style:{}", line));
+        }
+        let color = style.underline_color.as_ref().and_then(css_color);
+        if let Some(color) = color.filter(|_| style.is_set(UNDERLINE_COLOR_KEY)) {
+            props.push(format!("text-decoration-color:{}", color));
+        }
+    }
+    if style.get_faint() {
+        props.push("opacity:0.6".to_string());
+    }
+    if style.is_set(ALIGN_HORIZONTAL_KEY) {
+        let pos = style.get_align_horizontal().value();
+        let align = if pos <= 0.0 {
+            "left"
+        } else if pos >= 1.0 {
+            "right"
+        } else {
+            "center"
+        };
+        props.push(format!("text-align:{}", align));
+    }
+    props.join(";")
+}
+
+/// Returns the inline CSS for a style's padding, or an empty string when it
+/// has none.
+///
+/// Horizontal padding is measured in `ch` and vertical padding in `em`,
+/// matching the terminal's cells and lines.
+pub fn padding_css(style: &Style) -> String {
+    let (top, right, bottom, left) = style.get_padding();
+    if top <= 0 && right <= 0 && bottom <= 0 && left <= 0 {
+        return String::new();
+    }
+    format!(
+        "padding:{}em {}ch {}em {}ch",
+        top.max(0),
+        right.max(0),
+        bottom.max(0),
+        left.max(0)
+    )
+}
+
+/// Formats a `style` attribute, or nothing when `css` is empty.
+pub fn style_attr(css: &str) -> String {
+    if css.is_empty() {
+        String::new()
+    } else {
+        format!(" style=\"{}\"", css)
+    }
+}
+
+/// Checks that every tag in `html` is closed in the order it was opened.
+///
+/// This is a small balance check for exported markup, not a parser: it
+/// expects no void elements, comments or `>` inside attribute values, which
+/// the exporters never produce.
+///
+/// # Errors
+///
+/// Returns a description of the first unterminated, mismatched or unclosed
+/// tag, or of a stray `>` outside a tag.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::html::check_tags;
+///
+/// assert_eq!(
+///     check_tags("<ul><li>a</li></ul>").unwrap(),
+///     vec!["ul".to_string(), "li".to_string()]
+/// );
+/// assert!(check_tags("<ul><li>a</ul>").is_err());
+/// ```
+pub fn check_tags(html: &str) -> Result<Vec<String>, String> {
+    let mut stack: Vec<&str> = Vec::new();
+    let mut seen = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        if rest[..start].contains('>') {
+            return Err(format!("stray '>' in {}", html));
+        }
+        let end = match rest[start..].find('>') {
+            Some(end) => start + end,
+            None => return Err(format!("unterminated tag in {}", html)),
+        };
+        let tag = &rest[start + 1..end];
+        if let Some(name) = tag.strip_prefix('/') {
+            let open = stack.pop();
+            if open != Some(name) {
+                return Err(format!("</{}> closes {:?} in {}", name, open, html));
+            }
+        } else {
+            let name = tag.split_whitespace().next().unwrap_or_default();
+            seen.push(name.to_string());
+            stack.push(name);
+        }
+        rest = &rest[end + 1..];
+    }
+    if rest.contains('>') {
+        return Err(format!("stray '>' in {}", html));
+    }
+    if !stack.is_empty() {
+        return Err(format!("unclosed {:?} in {}", stack, html));
+    }
+    Ok(seen)
+}
+
+/// The renderer colors are resolved against: true color on a dark
+/// background, whatever the terminal running the export reports.
+fn css_renderer() -> Renderer {
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::TrueColor);
+    r.set_has_dark_background(true);
+    r
+}
+
+/// Resolves a color to its true-color hex form, or `None` for "no color".
+///
+/// Adaptive colors resolve for a dark background. ANSI and ANSI256 indices
+/// are mapped through the standard xterm palette.
+fn css_color(color: &StyleColor, r: &Renderer) -> Option<String> {
+    let token = color.token(r);
+    if token.starts_with('#') {
+        Some(token)
+    } else if let Ok(idx) = token.parse::<u8>() {
+        let (red, green, blue) = ansi256_to_rgb_u8(idx);
+        Some(format!("#{:02x}{:02x}{:02x}", red, green, blue))
+    } else {
+        None
+    }
+}
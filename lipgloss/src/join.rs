@@ -25,7 +25,23 @@
 
 use crate::position::Position;
 use crate::security::safe_repeat;
-use crate::utils::width_visible as line_width;
+use crate::utils::{collapse_resets, expand_tabs, width_visible as line_width, SgrState};
+
+/// Appends one line of a block: the block's SGR state carried over from its
+/// previous line is reopened first, and closed again after the line unless
+/// `last` says nothing follows it in the output. `state` keeps what is left
+/// open, for the block's next line.
+fn push_line<'a>(out: &mut String, state: &mut SgrState<'a>, line: &'a str, last: bool) {
+    if line.is_empty() {
+        return;
+    }
+    out.push_str(&state.reopen());
+    out.push_str(line);
+    state.track(line);
+    if state.is_open() && !last {
+        out.push_str("\x1b[0m");
+    }
+}
 
 /// Joins multiple strings horizontally with vertical alignment control.
 ///
@@ -36,6 +52,12 @@ use crate::utils::width_visible as line_width;
 ///
 /// The function preserves ANSI escape sequences and calculates visible width
 /// correctly for proper alignment, ensuring that styled text displays correctly.
+/// Tabs are expanded to [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab stops
+/// first so each block is as wide as it was measured. Padding is always
+/// unstyled: a block line that ends with attributes still open is closed
+/// with a reset before its padding or the next block, and the attributes
+/// are reopened where the block's next line starts. Back-to-back resets
+/// are collapsed.
 ///
 /// # Arguments
 ///
@@ -92,7 +114,10 @@ pub fn join_horizontal(pos: Position, strs: &[&str]) -> String {
     let mut max_height: usize = 0;
 
     for s in strs {
-        let lines: Vec<String> = s.split('\n').map(|l| l.to_string()).collect();
+        let lines: Vec<String> = s
+            .split('\n')
+            .map(|l| expand_tabs(l, crate::DEFAULT_TAB_WIDTH))
+            .collect();
         if lines.len() > max_height {
             max_height = lines.len();
         }
@@ -136,26 +161,21 @@ pub fn join_horizontal(pos: Position, strs: &[&str]) -> String {
 
     // Merge line-by-line, padding each line of each block to its own max width
     let mut out = String::new();
+    let mut states: Vec<SgrState> = blocks.iter().map(|_| SgrState::new()).collect();
     for i in 0..max_height {
         for (j, block) in blocks.iter().enumerate() {
-            let line = if i < block.len() {
-                block[i].as_str()
-            } else {
-                ""
-            };
-            out.push_str(line);
-
+            let line = block[i].as_str();
             let pad = max_widths[j].saturating_sub(line_width(line));
-            if pad > 0 {
-                out.push_str(&safe_repeat(' ', pad));
-            }
+            let last = i == max_height - 1 && j == blocks.len() - 1 && pad == 0;
+            push_line(&mut out, &mut states[j], line, last);
+            out.push_str(&safe_repeat(' ', pad));
         }
         if i < max_height - 1 {
             out.push('\n');
         }
     }
 
-    out
+    collapse_resets(&out)
 }
 
 /// Joins multiple strings vertically with horizontal alignment control.
@@ -167,6 +187,11 @@ pub fn join_horizontal(pos: Position, strs: &[&str]) -> String {
 ///
 /// The function preserves ANSI escape sequences and calculates visible width
 /// correctly for proper alignment, ensuring that styled text displays correctly.
+/// Tabs are expanded to [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab stops
+/// first so each block is as wide as it was measured. As with
+/// [`join_horizontal`], padding is emitted outside any open styling, which
+/// is reopened on the block's next line, and back-to-back resets are
+/// collapsed.
 ///
 /// # Arguments
 ///
@@ -224,7 +249,10 @@ pub fn join_vertical(pos: Position, strs: &[&str]) -> String {
     let mut blocks: Vec<Vec<String>> = Vec::with_capacity(strs.len());
     let mut max_width: usize = 0;
     for s in strs {
-        let lines: Vec<String> = s.split('\n').map(|l| l.to_string()).collect();
+        let lines: Vec<String> = s
+            .split('\n')
+            .map(|l| expand_tabs(l, crate::DEFAULT_TAB_WIDTH))
+            .collect();
         let w = lines.iter().map(|l| line_width(l)).max().unwrap_or(0);
         if w > max_width {
             max_width = w;
@@ -235,45 +263,24 @@ pub fn join_vertical(pos: Position, strs: &[&str]) -> String {
     let v = pos.value();
     let mut out = String::new();
     for (bi, block) in blocks.iter().enumerate() {
+        // Styling left open carries over between the lines of a block only
+        let mut state = SgrState::new();
         for (li, line) in block.iter().enumerate() {
             let line = line.as_str();
             let w = max_width.saturating_sub(line_width(line));
-            if (v - 0.0).abs() < f64::EPSILON {
-                // Left
-                out.push_str(line);
-                if w > 0 {
-                    out.push_str(&safe_repeat(' ', w));
-                }
-            } else if (v - 1.0).abs() < f64::EPSILON {
-                // Right
-                if w > 0 {
-                    out.push_str(&safe_repeat(' ', w));
-                }
-                out.push_str(line);
-            } else {
-                // Middle
-                if w < 1 {
-                    out.push_str(line);
-                } else {
-                    let split = (w as f64 * v).round() as usize;
-                    let right = w - split;
-                    let left = w - right;
-                    if left > 0 {
-                        out.push_str(&safe_repeat(' ', left));
-                    }
-                    out.push_str(line);
-                    if right > 0 {
-                        out.push_str(&safe_repeat(' ', right));
-                    }
-                }
-            }
+            // Left puts all padding after the line, right all of it before
+            let left = ((w as f64 * v).round() as usize).min(w);
+            let last_line = bi == blocks.len() - 1 && li == block.len() - 1;
+            out.push_str(&safe_repeat(' ', left));
+            push_line(&mut out, &mut state, line, last_line && w == left);
+            out.push_str(&safe_repeat(' ', w - left));
 
             // newline unless this is the very last line of the last block
-            if !(bi == blocks.len() - 1 && li == block.len() - 1) {
+            if !last_line {
                 out.push('\n');
             }
         }
     }
 
-    out
+    collapse_resets(&out)
 }
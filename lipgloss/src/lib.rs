@@ -139,11 +139,46 @@
 /// - [`Style::tab_width`] - Set tab width or disable conversion
 pub const NO_TAB_CONVERSION: i32 = -1;
 
+/// Tab stop interval unless [`Style::tab_width`] says otherwise.
+///
+/// This is the crate's single tab policy: [`Style::render`] replaces each tab
+/// with the spaces up to the next multiple of this many columns in its line,
+/// the measuring functions ([`width`], [`width_visible`], [`get_lines`],
+/// [`get_lines_visible`]) count tabs the same way, and
+/// [`join_horizontal`] and [`join_vertical`] expand tabs before laying blocks
+/// out, so a measured block is exactly as wide as it renders. Use
+/// [`size::width_with_tabs`] to measure with a different tab width.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::{width, Style, DEFAULT_TAB_WIDTH};
+///
+/// let rendered = Style::new().render("\tfoo");
+/// assert_eq!(rendered, "    foo");
+/// assert_eq!(width("\tfoo"), DEFAULT_TAB_WIDTH as usize + 3);
+/// assert_eq!(width("\tfoo"), width(&rendered));
+/// ```
+pub const DEFAULT_TAB_WIDTH: i32 = 4;
+
+/// Returns the version of this crate.
+///
+/// # Examples
+///
+/// ```rust
+/// assert!(!lipgloss::version().is_empty());
+/// ```
+pub const fn version() -> &'static str {
+    env!("CARGO_PKG_VERSION")
+}
+
 pub mod align;
 pub mod blending;
 pub mod border;
 pub mod color;
 pub mod gradient;
+#[cfg(feature = "html")]
+pub mod html;
 pub mod join;
 pub mod position;
 pub mod renderer;
@@ -154,10 +189,10 @@ pub mod utils;
 pub mod whitespace;
 
 pub use align::*;
-pub use blending::{blend_1d, blend_2d};
+pub use blending::{blend_1d, blend_1d_adaptive, blend_2d, blend_2d_adaptive};
 pub use border::*;
 pub use color::*;
-pub use gradient::{bilinear_interpolation_grid, gradient, gradient_rgb};
+pub use gradient::{bilinear_interpolation_grid, gradient, gradient_rgb, value_mapper};
 pub use join::*;
 pub use position::*;
 pub use renderer::*;
@@ -167,17 +202,25 @@ pub use style::*;
 // Avoid re-exporting all of utils to prevent name clashes with size (width/height).
 // Re-export only the public helpers and API-parity items we want at the crate root.
 pub use utils::{
+    expand_tabs,
     get_lines,
     get_lines_visible,
+    has_ansi,
     new_range,
+    prefix_lines,
+    reapply_after_resets,
+    slice_ansi,
     strip_ansi,
+    strip_non_sgr,
     style_ranges,
     style_runes,
+    truncate_ellipsis,
     which_sides_bool,
     which_sides_color,
     // CSS helper functions for shorthand notation
     which_sides_int,
     width_visible,
+    write_prefixed,
     // Go API parity aliases and types
     NewRange,
     Range,
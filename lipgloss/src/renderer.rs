@@ -31,6 +31,13 @@
 //! The module provides a global default renderer that can be accessed via
 //! [`default_renderer()`] and configured via [`set_color_profile()`] and
 //! [`set_has_dark_background()`].
+//!
+//! # Bug Reports
+//!
+//! [`debug_report()`] describes the settings the default renderer ended up
+//! with and the environment variables it looked at. Paste it into a bug
+//! report, and [`Renderer::from_debug_report`] rebuilds a renderer with the
+//! same settings, whatever the environment it runs in.
 
 use std::sync::{Arc, OnceLock, RwLock};
 
@@ -131,6 +138,10 @@ struct Inner {
     has_dark_background: bool,
     explicit_background: bool,
     has_dark_background_once: OnceLock<()>,
+
+    // Environment recorded in a debug report this renderer was rebuilt from,
+    // reported in place of the current one.
+    replayed_env: Option<Vec<(String, Option<String>)>>,
 }
 
 impl Default for Renderer {
@@ -145,6 +156,7 @@ impl Default for Renderer {
             has_dark_background: true, // common case; will be lazily detected
             explicit_background: false,
             has_dark_background_once: OnceLock::new(),
+            replayed_env: None,
         };
         Self {
             inner: Arc::new(RwLock::new(inner)),
@@ -373,8 +385,204 @@ impl Renderer {
     pub fn take_output_clone(&self) -> Option<Output> {
         self.output()
     }
+
+    /// Describe the renderer's effective settings for a bug report.
+    ///
+    /// The report is plain `key = value` lines under a version header: the
+    /// color profile, the background, the output capabilities and the value
+    /// of each environment variable consulted during detection (`NO_COLOR`,
+    /// `COLORTERM`, `TERM` and `COLORFGBG`). No other variables are read.
+    /// Values that do not look like a plain terminal setting, such as long
+    /// strings or paths, are shown as `(redacted)`.
+    ///
+    /// Reading the settings runs detection if it has not happened yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipgloss::renderer::{ColorProfileKind, Renderer};
+    ///
+    /// let mut renderer = Renderer::new();
+    /// renderer.set_color_profile(ColorProfileKind::ANSI256);
+    /// let report = renderer.debug_report();
+    /// assert!(report.starts_with("lipgloss renderer report v1\n"));
+    /// assert!(report.contains("\nprofile = ANSI256\n"));
+    /// ```
+    pub fn debug_report(&self) -> String {
+        let profile = self.color_profile();
+        let dark = self.has_dark_background();
+        let output = self.output();
+        let env = self
+            .inner
+            .read()
+            .ok()
+            .and_then(|g| g.replayed_env.clone())
+            .unwrap_or_else(|| {
+                REPORTED_ENV
+                    .iter()
+                    .map(|&key| (key.to_string(), std::env::var(key).ok()))
+                    .collect()
+            });
+
+        let mut lines = vec![
+            REPORT_HEADER.to_string(),
+            format!("profile = {:?}", profile),
+            format!("dark_background = {}", dark),
+        ];
+        if let Some(o) = output {
+            lines.push(format!("output.supports_ansi = {}", o.supports_ansi));
+            lines.push(format!("output.is_tty_like = {}", o.is_tty_like));
+        }
+        for (key, value) in env {
+            let value = match value {
+                None => "(unset)".to_string(),
+                Some(v) => redact_env_value(&v),
+            };
+            lines.push(format!("env.{} = {}", key, value));
+        }
+        lines.join("\n")
+    }
+
+    /// Rebuild a renderer from a [`debug_report`](Self::debug_report).
+    ///
+    /// The profile, background and output are set explicitly, so nothing is
+    /// detected from the current environment, and the environment recorded
+    /// in the report is carried over into the new renderer's own report.
+    /// Blank lines and unknown keys are ignored, so reports from newer
+    /// versions with more settings still load.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`DebugReportError`] when the header is missing or a known
+    /// setting is malformed or absent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use lipgloss::renderer::{ColorProfileKind, Renderer};
+    ///
+    /// let report = "lipgloss renderer report v1\n\
+    ///               profile = TrueColor\n\
+    ///               dark_background = false";
+    /// let renderer = Renderer::from_debug_report(report).unwrap();
+    /// assert_eq!(renderer.color_profile(), ColorProfileKind::TrueColor);
+    /// assert!(!renderer.has_dark_background());
+    /// ```
+    pub fn from_debug_report(report: &str) -> Result<Self, DebugReportError> {
+        let error = |line: usize, message: &str| DebugReportError {
+            line,
+            message: message.to_string(),
+        };
+        let mut lines = report
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+        match lines.next() {
+            Some((_, REPORT_HEADER)) => {}
+            Some((n, _)) => return Err(error(n, "not a lipgloss renderer report")),
+            None => return Err(error(0, "empty report")),
+        }
+
+        let mut profile = None;
+        let mut dark = None;
+        let mut supports_ansi = None;
+        let mut is_tty_like = None;
+        let mut env = Vec::new();
+        for (n, line) in lines {
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(error(n, "expected `key = value`"));
+            };
+            let (key, value) = (key.trim(), value.trim());
+            let flag = || match value {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(error(n, "expected `true` or `false`")),
+            };
+            match key {
+                "profile" => {
+                    profile = Some(match value {
+                        "TrueColor" => ColorProfileKind::TrueColor,
+                        "ANSI256" => ColorProfileKind::ANSI256,
+                        "ANSI" => ColorProfileKind::ANSI,
+                        "NoColor" => ColorProfileKind::NoColor,
+                        _ => return Err(error(n, "unknown color profile")),
+                    })
+                }
+                "dark_background" => dark = Some(flag()?),
+                "output.supports_ansi" => supports_ansi = Some(flag()?),
+                "output.is_tty_like" => is_tty_like = Some(flag()?),
+                _ => {
+                    if let Some(var) = key.strip_prefix("env.") {
+                        let value = (value != "(unset)").then(|| value.to_string());
+                        env.push((var.to_string(), value));
+                    }
+                }
+            }
+        }
+
+        let mut renderer = Renderer::new();
+        renderer.set_color_profile(profile.ok_or_else(|| error(0, "missing `profile`"))?);
+        renderer
+            .set_has_dark_background(dark.ok_or_else(|| error(0, "missing `dark_background`"))?);
+        if let (Some(supports_ansi), Some(is_tty_like)) = (supports_ansi, is_tty_like) {
+            renderer.set_output(Output {
+                supports_ansi,
+                is_tty_like,
+            });
+        }
+        if let Ok(mut inner) = renderer.inner.write() {
+            inner.replayed_env = Some(env);
+        }
+        Ok(renderer)
+    }
+}
+
+/// First line of every debug report, versioned so the format can change.
+const REPORT_HEADER: &str = "lipgloss renderer report v1";
+
+/// Environment variables consulted by detection, in report order.
+const REPORTED_ENV: [&str; 4] = ["NO_COLOR", "COLORTERM", "TERM", "COLORFGBG"];
+
+/// Keep short values made of characters seen in terminal settings; anything
+/// else may be a path or a secret someone exported by accident.
+fn redact_env_value(value: &str) -> String {
+    let plain = value.len() <= 64
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "._-+;:".contains(c));
+    if plain {
+        value.to_string()
+    } else {
+        "(redacted)".to_string()
+    }
+}
+
+/// Error returned by [`Renderer::from_debug_report`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DebugReportError {
+    /// The 1-based line at fault, or 0 when the report as a whole is.
+    pub line: usize,
+    /// What is wrong with it.
+    pub message: String,
+}
+
+impl std::fmt::Display for DebugReportError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.line == 0 {
+            write!(f, "invalid renderer report: {}", self.message)
+        } else {
+            write!(
+                f,
+                "invalid renderer report, line {}: {}",
+                self.line, self.message
+            )
+        }
+    }
 }
 
+impl std::error::Error for DebugReportError {}
+
 // ---- Global default renderer (package-level behavior like Go) ----
 
 static DEFAULT_RENDERER: OnceLock<Renderer> = OnceLock::new();
@@ -439,6 +647,7 @@ pub fn set_default_renderer(r: Renderer) {
                 if src.has_dark_background_once.get().is_some() {
                     let _ = dst.has_dark_background_once.set(());
                 }
+                dst.replayed_env = src.replayed_env.clone();
             }
         }
     }
@@ -469,6 +678,23 @@ pub fn color_profile() -> ColorProfileKind {
     default_renderer().color_profile()
 }
 
+/// Describe the default renderer's effective settings for a bug report.
+///
+/// See [`Renderer::debug_report`] for the format.
+///
+/// # Examples
+///
+/// ```
+/// use lipgloss::renderer::{debug_report, Renderer};
+///
+/// let report = debug_report();
+/// let replay = Renderer::from_debug_report(&report).unwrap();
+/// assert_eq!(replay.debug_report(), report);
+/// ```
+pub fn debug_report() -> String {
+    default_renderer().debug_report()
+}
+
 /// Set the color profile on the default renderer.
 ///
 /// This will always mutate the default renderer, regardless of initialization
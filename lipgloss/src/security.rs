@@ -23,6 +23,11 @@ pub const MAX_RENDER_MEMORY_BUDGET: usize = 50_000_000; // 50MB
 /// sequences (e.g., an ESC without a terminating byte), mitigating potential DoS.
 pub const MAX_ANSI_SEQ_LEN: usize = 64;
 
+/// Maximum number of bytes to scan when parsing a single OSC sequence.
+/// OSC payloads (e.g. hyperlink URLs) are legitimately longer than CSI
+/// sequences, but are still bounded to avoid scanning unterminated input.
+pub const MAX_OSC_SEQ_LEN: usize = 2048;
+
 /// Validates that a dimension value is within safe bounds.
 ///
 /// # Arguments
@@ -34,7 +34,7 @@
 //! assert!(width(emoji_text) > emoji_text.chars().count());
 //! ```
 
-use crate::utils::{height as str_height, width_visible as line_width};
+use crate::utils::{expand_tabs, height as str_height, width_visible as line_width};
 
 /// Width returns the cell width of characters in the string. ANSI sequences are
 /// ignored and characters wider than one cell (such as Chinese characters and
@@ -45,6 +45,10 @@ use crate::utils::{height as str_height, width_visible as line_width};
 ///
 /// For multi-line strings, this returns the width of the widest line.
 ///
+/// Tabs advance to the next [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab
+/// stop, the same width a default [`Style`](crate::Style) renders them at. Use
+/// [`width_with_tabs`] for styles with a different [`tab_width`](crate::Style::tab_width).
+///
 /// # Arguments
 ///
 /// * `s` - The string to measure
@@ -86,6 +90,35 @@ pub fn width(s: &str) -> usize {
     w
 }
 
+/// Returns the cell width of a string as rendered with the given tab width.
+///
+/// `tab_width` follows [`Style::tab_width`](crate::Style::tab_width): a
+/// positive value advances each tab to the next multiple of that many cells
+/// and `0` ignores tabs.
+/// Tabs left in place by a negative value such as
+/// [`NO_TAB_CONVERSION`](crate::NO_TAB_CONVERSION) count as one cell, although
+/// the terminal may advance further to its next tab stop. Otherwise this
+/// behaves like [`width`].
+///
+/// # Examples
+///
+/// ```
+/// use lipgloss::size::{width, width_with_tabs};
+/// use lipgloss::Style;
+///
+/// let style = Style::new().tab_width(2);
+/// assert_eq!(width_with_tabs("\tfoo", 2), 5);
+/// assert_eq!(width_with_tabs("\tfoo", 2), width(&style.render("\tfoo")));
+/// assert_eq!(width_with_tabs("\tfoo", 0), 3);
+/// ```
+pub fn width_with_tabs(s: &str, tab_width: i32) -> usize {
+    if tab_width < 0 {
+        width(&s.replace('\t', " "))
+    } else {
+        width(&expand_tabs(s, tab_width))
+    }
+}
+
 /// Height returns height of a string in cells. This is done simply by counting
 /// `\n` characters. If your strings use `\r\n` for newlines you should convert
 /// them to `\n` first, or write a separate function for measuring height.
@@ -12,7 +12,7 @@
 //!
 //! - **Font Weight**: `bold()`, `faint()`
 //! - **Font Style**: `italic()`
-//! - **Text Decoration**: `underline()`, `strikethrough()`, `blink()`
+//! - **Text Decoration**: `underline()`, `underline_style()`, `strikethrough()`, `blink()`
 //! - **Visual Effects**: `reverse()` (swap foreground/background)
 //! - **Spacing Control**: `underline_spaces()`, `strikethrough_spaces()`, `color_whitespace()`
 //! - **Layout**: `inline()` (render without line breaks)
@@ -61,6 +61,40 @@
 
 use crate::style::{properties::*, Style};
 
+/// The shape of an underline, set with [`Style::underline_style`].
+///
+/// Shapes other than [`Single`](UnderlineStyle::Single) are drawn with the
+/// extended `4:n` form of SGR 4. Terminals limited to 16 colors rarely know
+/// it, so under the ANSI and no-color profiles every shape is drawn as a
+/// plain underline.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum UnderlineStyle {
+    /// A single straight line, as drawn by [`Style::underline`].
+    #[default]
+    Single,
+    /// Two straight lines.
+    Double,
+    /// A wavy line, as used for spell-check squiggles.
+    Curly,
+    /// A dotted line.
+    Dotted,
+    /// A dashed line.
+    Dashed,
+}
+
+impl UnderlineStyle {
+    /// The SGR parameter that turns this underline on.
+    pub(crate) fn sgr(self) -> &'static str {
+        match self {
+            UnderlineStyle::Single => "4",
+            UnderlineStyle::Double => "4:2",
+            UnderlineStyle::Curly => "4:3",
+            UnderlineStyle::Dotted => "4:4",
+            UnderlineStyle::Dashed => "4:5",
+        }
+    }
+}
+
 impl Style {
     /// Set whether to render text inline without adding line breaks.
     ///
@@ -206,6 +240,31 @@ impl Style {
         self
     }
 
+    /// Set the shape of the underline, and turn underline on.
+    ///
+    /// Curly, dotted and dashed underlines need terminal support for the
+    /// extended SGR 4 form; see [`UnderlineStyle`] for how they degrade.
+    /// Pair with [`underline_color`](Self::underline_color) for
+    /// spell-check-style squiggles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{ColorProfileKind, Renderer, Style, UnderlineStyle};
+    ///
+    /// let mut renderer = Renderer::new();
+    /// renderer.set_color_profile(ColorProfileKind::TrueColor);
+    /// let squiggle = Style::new()
+    ///     .renderer(renderer)
+    ///     .underline_style(UnderlineStyle::Curly);
+    /// assert_eq!(squiggle.render("teh"), "\x1b[4:3mteh\x1b[0m");
+    /// ```
+    pub fn underline_style(mut self, style: UnderlineStyle) -> Self {
+        self.underline_style = style;
+        self.set_prop(UNDERLINE_STYLE_KEY);
+        self.underline(true)
+    }
+
     /// Set whether to render text with strikethrough decoration.
     ///
     /// When `true`, text appears with a line through the middle of each character,
@@ -513,6 +572,9 @@ impl Style {
     /// # Note
     ///
     /// This setting affects both foreground and background colors applied to the style.
+    /// It covers the content and padding only: margins are colored by
+    /// [`margin_background`](Self::margin_background), never by the style's
+    /// background.
     pub fn color_whitespace(mut self, v: bool) -> Self {
         self.set_attr(ATTR_COLOR_WHITESPACE, v);
         self.set_prop(COLOR_WHITESPACE_KEY);
@@ -26,7 +26,7 @@
 //!     .border_right(false);
 //! ```
 
-use crate::border::Border;
+use crate::border::{Border, BorderPiece};
 use crate::style::{properties::*, Style};
 use crate::utils::which_sides_bool;
 
@@ -337,4 +337,82 @@ impl Style {
 
         self
     }
+
+    /// Overrides a single piece of the border with `glyph`.
+    ///
+    /// The override applies to whichever [`Border`] the style uses at render
+    /// time, so a later [`border`] or [`border_style`] call swaps the rest of
+    /// the border but keeps the overridden pieces. This makes shapes such as
+    /// tabs easy to build from a stock border.
+    ///
+    /// `glyph` must occupy exactly one terminal column and contain no control
+    /// characters; anything else is ignored and the style is returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{rounded_border, BorderPiece, Style};
+    ///
+    /// // An active tab: open at the bottom.
+    /// let tab = Style::new()
+    ///     .border(rounded_border())
+    ///     .border_char(BorderPiece::Bottom, " ")
+    ///     .border_char(BorderPiece::BottomLeft, "┘")
+    ///     .border_char(BorderPiece::BottomRight, "└");
+    /// assert_eq!(tab.render("Go"), "╭──╮\n│Go│\n┘  └");
+    ///
+    /// // Wide or multi-character glyphs are rejected.
+    /// let same = tab.clone().border_char(BorderPiece::Top, "══");
+    /// assert_eq!(same.get_border_char(BorderPiece::Top), None);
+    /// ```
+    ///
+    /// [`border`]: Self::border
+    /// [`border_style`]: Self::border_style
+    pub fn border_char(mut self, piece: BorderPiece, glyph: &str) -> Self {
+        if crate::width(glyph) == 1 && !glyph.chars().any(char::is_control) {
+            self.border_chars[piece.index()] = Some(glyph.to_string());
+        }
+        self
+    }
+
+    /// Overrides the four corners of the border, clockwise from the top left.
+    ///
+    /// Shorthand for [`border_char`](Self::border_char) on each corner, with
+    /// the same rules: a glyph that isn't exactly one column wide is ignored
+    /// and that corner keeps its glyph. A corner is only drawn where both of
+    /// its sides are.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{normal_border, Style};
+    ///
+    /// let style = Style::new().border(normal_border()).border_corners("+", "+", "+", "+");
+    /// assert_eq!(style.render("x"), "+─+\n│x│\n+─+");
+    ///
+    /// // Without a right side, its corners are left out.
+    /// assert_eq!(style.border_right(false).render("x"), "+─\n│x\n+─");
+    /// ```
+    pub fn border_corners(
+        self,
+        top_left: &str,
+        top_right: &str,
+        bottom_right: &str,
+        bottom_left: &str,
+    ) -> Self {
+        self.border_char(BorderPiece::TopLeft, top_left)
+            .border_char(BorderPiece::TopRight, top_right)
+            .border_char(BorderPiece::BottomRight, bottom_right)
+            .border_char(BorderPiece::BottomLeft, bottom_left)
+    }
+
+    /// Returns the glyph drawn for `piece`: the override set with
+    /// [`border_char`](Self::border_char) if there is one, otherwise the
+    /// border's own glyph.
+    pub(crate) fn border_glyph(&self, border: &Border, piece: BorderPiece) -> &str {
+        self.border_chars[piece.index()]
+            .as_deref()
+            .unwrap_or(border.piece(piece))
+    }
 }
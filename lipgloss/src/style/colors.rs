@@ -38,8 +38,7 @@
 //! let style = Style::new().margin_background("white");
 //! ```
 
-use crate::color::TerminalColor;
-use crate::renderer::default_renderer;
+use crate::color::{Color, TerminalColor};
 use crate::style::{properties::*, Style};
 use crate::utils::which_sides_color;
 
@@ -74,11 +73,49 @@ impl Style {
     /// let style = Style::new().foreground("196"); // Bright red
     /// ```
     pub fn foreground<C: TerminalColor>(mut self, color: C) -> Self {
-        self.fg_color = Some(color.token(default_renderer()));
+        self.fg_color = Some(color.to_style_color());
         self.set_prop(FOREGROUND_KEY);
         self
     }
 
+    /// Sets a minimum contrast ratio between the foreground and the background.
+    ///
+    /// When set, the foreground is checked at render time against the style's
+    /// background, or against the renderer's backdrop (black for dark terminals,
+    /// white for light ones) when no background is set. If the WCAG contrast
+    /// ratio falls short, the foreground is lightened or darkened until it is
+    /// met within the active color profile. Faint text is measured as dimmed.
+    /// The guard is off by default; values are clamped to the 1.0–21.0 range.
+    ///
+    /// # Arguments
+    ///
+    /// * `ratio` - The minimum WCAG contrast ratio, e.g. `4.5` for body text
+    ///
+    /// # Returns
+    ///
+    /// Returns a new [`Style`] with the contrast guard enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// // Muted labels stay readable even when dimmed
+    /// let style = Style::new()
+    ///     .foreground("8")
+    ///     .faint(true)
+    ///     .min_contrast(4.5);
+    /// ```
+    pub fn min_contrast(mut self, ratio: f64) -> Self {
+        self.min_contrast = if ratio.is_nan() {
+            1.0
+        } else {
+            ratio.clamp(1.0, 21.0)
+        };
+        self.set_prop(MIN_CONTRAST_KEY);
+        self
+    }
+
     /// Sets the background color.
     ///
     /// This method sets the background color that will be used behind text content.
@@ -108,11 +145,139 @@ impl Style {
     /// let style = Style::new().background("#2E3440");
     /// ```
     pub fn background<C: TerminalColor>(mut self, color: C) -> Self {
-        self.bg_color = Some(color.token(default_renderer()));
+        self.bg_color = Some(color.to_style_color());
         self.set_prop(BACKGROUND_KEY);
         self
     }
 
+    /// Sets the color of the underline.
+    ///
+    /// The underline is drawn in this color (SGR 58) instead of the
+    /// foreground color. It has no effect unless underline is on, and the
+    /// ANSI and no-color profiles, which have no way to express it, draw the
+    /// underline in the foreground color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{ColorProfileKind, Renderer, Style, UnderlineStyle};
+    ///
+    /// let mut renderer = Renderer::new();
+    /// renderer.set_color_profile(ColorProfileKind::TrueColor);
+    /// let style = Style::new()
+    ///     .renderer(renderer)
+    ///     .underline_style(UnderlineStyle::Curly)
+    ///     .underline_color("#ff0000");
+    /// assert_eq!(style.render("teh"), "\x1b[4:3;58;2;255;0;0mteh\x1b[0m");
+    /// ```
+    pub fn underline_color<C: TerminalColor>(mut self, color: C) -> Self {
+        self.underline_color = Some(color.to_style_color());
+        self.set_prop(UNDERLINE_COLOR_KEY);
+        self
+    }
+
+    /// Sets a foreground gradient blended across the rendered text.
+    ///
+    /// At render time the stops are blended with
+    /// [`blend_1d`](crate::blending::blend_1d) into one color per visible
+    /// character of the content, so the text fades from the first stop to the
+    /// last. Escape sequences already in the content are left alone and take no
+    /// step; a wide character or a character with combining marks takes one.
+    /// Padding, borders and margins are not part of the gradient. By default
+    /// the gradient restarts on every line; see [`Style::gradient_spans_block`].
+    ///
+    /// The gradient takes precedence over [`Style::foreground`] for the text
+    /// it covers. A single stop colors every character with it; no stops
+    /// turn the gradient off.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - The gradient stops, from the start of a line to its end
+    ///
+    /// # Returns
+    ///
+    /// Returns a new [`Style`] with the foreground gradient applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{Color, Style};
+    ///
+    /// let style = Style::new().foreground_gradient(&[
+    ///     Color::from("#ff0000"),
+    ///     Color::from("#0000ff"),
+    /// ]);
+    /// let title = style.render("Are you sure you want to eat marmalade?");
+    /// ```
+    pub fn foreground_gradient(mut self, colors: &[Color]) -> Self {
+        self.fg_gradient = colors.to_vec();
+        self.set_prop(FOREGROUND_GRADIENT_KEY);
+        self
+    }
+
+    /// Sets a background gradient blended across the rendered text.
+    ///
+    /// Works like [`Style::foreground_gradient`], coloring the background of
+    /// each visible character instead. It takes precedence over
+    /// [`Style::background`] for the text it covers; padding and alignment
+    /// space keep the plain background.
+    ///
+    /// # Arguments
+    ///
+    /// * `colors` - The gradient stops, from the start of a line to its end
+    ///
+    /// # Returns
+    ///
+    /// Returns a new [`Style`] with the background gradient applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{Color, Style};
+    ///
+    /// let style = Style::new()
+    ///     .foreground("#ffffff")
+    ///     .background_gradient(&[Color::from("#5a56e0"), Color::from("#ee6ff8")]);
+    /// let banner = style.render(" lipgloss ");
+    /// ```
+    pub fn background_gradient(mut self, colors: &[Color]) -> Self {
+        self.bg_gradient = colors.to_vec();
+        self.set_prop(BACKGROUND_GRADIENT_KEY);
+        self
+    }
+
+    /// Sets whether gradients run once across the whole block.
+    ///
+    /// By default [`Style::foreground_gradient`] and
+    /// [`Style::background_gradient`] restart on every line, so each line fades
+    /// from the first stop to the last. When enabled, the gradient is spread
+    /// over the characters of all lines in reading order instead, so the last
+    /// character of the block takes the last stop.
+    ///
+    /// # Arguments
+    ///
+    /// * `v` - `true` to span the block, `false` to restart per line
+    ///
+    /// # Returns
+    ///
+    /// Returns a new [`Style`] with the span mode applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{Color, Style};
+    ///
+    /// let style = Style::new()
+    ///     .foreground_gradient(&[Color::from("#ff0000"), Color::from("#0000ff")])
+    ///     .gradient_spans_block(true);
+    /// let poem = style.render("roses are red\nviolets are blue");
+    /// ```
+    pub fn gradient_spans_block(mut self, v: bool) -> Self {
+        self.set_attr(ATTR_GRADIENT_SPANS_BLOCK, v);
+        self.set_prop(GRADIENT_SPANS_BLOCK_KEY);
+        self
+    }
+
     /// Sets the foreground color for the top border.
     ///
     /// This method sets the color used to render the top border characters.
@@ -142,7 +307,7 @@ impl Style {
     /// [`border_top`]: Self::border_top
     /// [`border`]: Self::border
     pub fn border_top_foreground<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_top_fg_color = Some(c.token(default_renderer()));
+        self.border_top_fg_color = Some(c.to_style_color());
         self.set_prop(BORDER_TOP_FOREGROUND_KEY);
         self
     }
@@ -175,7 +340,7 @@ impl Style {
     /// [`border_right`]: Self::border_right
     /// [`border`]: Self::border
     pub fn border_right_foreground<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_right_fg_color = Some(c.token(default_renderer()));
+        self.border_right_fg_color = Some(c.to_style_color());
         self.set_prop(BORDER_RIGHT_FOREGROUND_KEY);
         self
     }
@@ -208,7 +373,7 @@ impl Style {
     /// [`border_bottom`]: Self::border_bottom
     /// [`border`]: Self::border
     pub fn border_bottom_foreground<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_bottom_fg_color = Some(c.token(default_renderer()));
+        self.border_bottom_fg_color = Some(c.to_style_color());
         self.set_prop(BORDER_BOTTOM_FOREGROUND_KEY);
         self
     }
@@ -241,7 +406,7 @@ impl Style {
     /// [`border_left`]: Self::border_left
     /// [`border`]: Self::border
     pub fn border_left_foreground<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_left_fg_color = Some(c.token(default_renderer()));
+        self.border_left_fg_color = Some(c.to_style_color());
         self.set_prop(BORDER_LEFT_FOREGROUND_KEY);
         self
     }
@@ -274,7 +439,7 @@ impl Style {
     /// [`border_top`]: Self::border_top
     /// [`border`]: Self::border
     pub fn border_top_background<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_top_bg_color = Some(c.token(default_renderer()));
+        self.border_top_bg_color = Some(c.to_style_color());
         self.set_prop(BORDER_TOP_BACKGROUND_KEY);
         self
     }
@@ -307,7 +472,7 @@ impl Style {
     /// [`border_right`]: Self::border_right
     /// [`border`]: Self::border
     pub fn border_right_background<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_right_bg_color = Some(c.token(default_renderer()));
+        self.border_right_bg_color = Some(c.to_style_color());
         self.set_prop(BORDER_RIGHT_BACKGROUND_KEY);
         self
     }
@@ -340,7 +505,7 @@ impl Style {
     /// [`border_bottom`]: Self::border_bottom
     /// [`border`]: Self::border
     pub fn border_bottom_background<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_bottom_bg_color = Some(c.token(default_renderer()));
+        self.border_bottom_bg_color = Some(c.to_style_color());
         self.set_prop(BORDER_BOTTOM_BACKGROUND_KEY);
         self
     }
@@ -373,7 +538,7 @@ impl Style {
     /// [`border_left`]: Self::border_left
     /// [`border`]: Self::border
     pub fn border_left_background<C: TerminalColor>(mut self, c: C) -> Self {
-        self.border_left_bg_color = Some(c.token(default_renderer()));
+        self.border_left_bg_color = Some(c.to_style_color());
         self.set_prop(BORDER_LEFT_BACKGROUND_KEY);
         self
     }
@@ -465,6 +630,9 @@ impl Style {
     /// outside of any borders and padding. The margin background color is only visible
     /// when margins are applied using methods like [`margin`] or [`margin_top`].
     ///
+    /// Without it margins are transparent: neither the style's [`background`]
+    /// nor one inherited from another style ever paints them.
+    ///
     /// # Arguments
     ///
     /// * `c` - Any type implementing [`TerminalColor`] for the margin background color
@@ -488,8 +656,9 @@ impl Style {
     ///
     /// [`margin`]: Self::margin
     /// [`margin_top`]: Self::margin_top
+    /// [`background`]: Self::background
     pub fn margin_background<C: TerminalColor>(mut self, c: C) -> Self {
-        self.margin_bg_color = Some(c.token(default_renderer()));
+        self.margin_bg_color = Some(c.to_style_color());
         self.set_prop(MARGIN_BACKGROUND_KEY);
         self
     }
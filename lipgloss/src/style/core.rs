@@ -16,9 +16,11 @@
 //! - **Flexible Rendering**: Support for custom renderers and text transforms
 
 use crate::border::{hidden_border, Border};
+use crate::color::{Color, StyleColor};
 use crate::position::{Position, LEFT, TOP};
 use crate::renderer::Renderer;
 use crate::style::properties::*;
+use crate::style::UnderlineStyle;
 use std::sync::Arc;
 
 /// A comprehensive style definition for terminal text rendering.
@@ -98,15 +100,22 @@ pub struct Style {
     // Store bool values as bitfield
     pub(crate) attrs: u32,
 
-    // Colors - stored as strings for simplicity and cloning
-    pub(crate) fg_color: Option<String>,
-    pub(crate) bg_color: Option<String>,
+    // Colors - kept unresolved and turned into tokens at render time
+    pub(crate) fg_color: Option<StyleColor>,
+    pub(crate) bg_color: Option<StyleColor>,
+    pub(crate) fg_gradient: Vec<Color>,
+    pub(crate) bg_gradient: Vec<Color>,
+    pub(crate) underline_color: Option<StyleColor>,
+
+    // Underline shape, drawn when the underline attribute is on
+    pub(crate) underline_style: UnderlineStyle,
 
     // Size constraints
     pub(crate) width: i32,
     pub(crate) height: i32,
     pub(crate) max_width: i32,
     pub(crate) max_height: i32,
+    pub(crate) truncation_indicator: String,
 
     // Alignment
     pub(crate) align_horizontal: Position,
@@ -123,21 +132,24 @@ pub struct Style {
     pub(crate) margin_right: i32,
     pub(crate) margin_bottom: i32,
     pub(crate) margin_left: i32,
-    pub(crate) margin_bg_color: Option<String>,
+    pub(crate) margin_bg_color: Option<StyleColor>,
 
     // Borders
     pub(crate) border_style: Border,
-    pub(crate) border_top_fg_color: Option<String>,
-    pub(crate) border_right_fg_color: Option<String>,
-    pub(crate) border_bottom_fg_color: Option<String>,
-    pub(crate) border_left_fg_color: Option<String>,
-    pub(crate) border_top_bg_color: Option<String>,
-    pub(crate) border_right_bg_color: Option<String>,
-    pub(crate) border_bottom_bg_color: Option<String>,
-    pub(crate) border_left_bg_color: Option<String>,
+    pub(crate) border_top_fg_color: Option<StyleColor>,
+    pub(crate) border_right_fg_color: Option<StyleColor>,
+    pub(crate) border_bottom_fg_color: Option<StyleColor>,
+    pub(crate) border_left_fg_color: Option<StyleColor>,
+    pub(crate) border_top_bg_color: Option<StyleColor>,
+    pub(crate) border_right_bg_color: Option<StyleColor>,
+    pub(crate) border_bottom_bg_color: Option<StyleColor>,
+    pub(crate) border_left_bg_color: Option<StyleColor>,
+    // Per-piece glyph overrides, indexed by BorderPiece
+    pub(crate) border_chars: [Option<String>; 13],
 
     // Misc
     pub(crate) tab_width: i32,
+    pub(crate) min_contrast: f64,
     pub(crate) transform: Option<Arc<dyn Fn(String) -> String + Send + Sync>>,
 }
 
@@ -168,10 +180,15 @@ impl Default for Style {
             attrs: 0,
             fg_color: None,
             bg_color: None,
+            fg_gradient: Vec::new(),
+            bg_gradient: Vec::new(),
+            underline_color: None,
+            underline_style: UnderlineStyle::Single,
             width: 0,
             height: 0,
             max_width: 0,
             max_height: 0,
+            truncation_indicator: String::new(),
             align_horizontal: LEFT,
             align_vertical: TOP,
             padding_top: 0,
@@ -192,7 +209,9 @@ impl Default for Style {
             border_right_bg_color: None,
             border_bottom_bg_color: None,
             border_left_bg_color: None,
+            border_chars: Default::default(),
             tab_width: TAB_WIDTH_DEFAULT,
+            min_contrast: 0.0,
             transform: None,
         }
     }
@@ -418,10 +437,15 @@ impl std::fmt::Debug for Style {
             .field("attrs", &self.attrs)
             .field("fg_color", &self.fg_color)
             .field("bg_color", &self.bg_color)
+            .field("fg_gradient", &self.fg_gradient)
+            .field("bg_gradient", &self.bg_gradient)
+            .field("underline_color", &self.underline_color)
+            .field("underline_style", &self.underline_style)
             .field("width", &self.width)
             .field("height", &self.height)
             .field("max_width", &self.max_width)
             .field("max_height", &self.max_height)
+            .field("truncation_indicator", &self.truncation_indicator)
             .field("align_horizontal", &self.align_horizontal)
             .field("align_vertical", &self.align_vertical)
             .field("padding_top", &self.padding_top)
@@ -442,7 +466,9 @@ impl std::fmt::Debug for Style {
             .field("border_right_bg_color", &self.border_right_bg_color)
             .field("border_bottom_bg_color", &self.border_bottom_bg_color)
             .field("border_left_bg_color", &self.border_left_bg_color)
+            .field("border_chars", &self.border_chars)
             .field("tab_width", &self.tab_width)
+            .field("min_contrast", &self.min_contrast)
             // skip transform
             .finish()
     }
@@ -69,6 +69,7 @@ impl Style {
             || self.get_height() != other.get_height()
             || self.get_max_width() != other.get_max_width()
             || self.get_max_height() != other.get_max_height()
+            || self.get_truncation_indicator() != other.get_truncation_indicator()
         {
             return false;
         }
@@ -89,6 +90,7 @@ impl Style {
             || self.get_border_right() != other.get_border_right()
             || self.get_border_bottom() != other.get_border_bottom()
             || self.get_border_left() != other.get_border_left()
+            || self.border_chars != other.border_chars
         {
             return false;
         }
@@ -101,7 +103,14 @@ impl Style {
         }
 
         // Compare other properties using getter methods
-        if self.get_tab_width() != other.get_tab_width() {
+        if self.get_tab_width() != other.get_tab_width()
+            || self.get_min_contrast() != other.get_min_contrast()
+            || self.get_foreground_gradient() != other.get_foreground_gradient()
+            || self.get_background_gradient() != other.get_background_gradient()
+            || self.get_gradient_spans_block() != other.get_gradient_spans_block()
+            || self.get_underline_style() != other.get_underline_style()
+            || self.get_underline_color() != other.get_underline_color()
+        {
             return false;
         }
 
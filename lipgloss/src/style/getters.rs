@@ -36,10 +36,11 @@
 //! assert_eq!(style.get_margin(), (1, 1, 1, 1));
 //! ```
 
-use crate::border::{hidden_border, Border};
-use crate::color::Color;
+use crate::border::{hidden_border, Border, BorderPiece};
+use crate::color::{Color, StyleColor, TerminalColor};
 use crate::position::{Position, LEFT, TOP};
-use crate::style::{properties::*, Style};
+use crate::renderer::default_renderer;
+use crate::style::{properties::*, Style, UnderlineStyle};
 
 impl Style {
     // ---------- Text attribute getters ----------
@@ -115,6 +116,28 @@ impl Style {
         self.is_set(UNDERLINE_KEY) && self.get_attr(ATTR_UNDERLINE)
     }
 
+    /// Gets the shape of the underline.
+    ///
+    /// Returns the shape set with [`Style::underline_style`], or
+    /// [`UnderlineStyle::Single`] if none has been set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{Style, UnderlineStyle};
+    ///
+    /// let style = Style::new().underline_style(UnderlineStyle::Dotted);
+    /// assert_eq!(style.get_underline_style(), UnderlineStyle::Dotted);
+    /// assert_eq!(Style::new().get_underline_style(), UnderlineStyle::Single);
+    /// ```
+    pub fn get_underline_style(&self) -> UnderlineStyle {
+        if self.is_set(UNDERLINE_STYLE_KEY) {
+            self.underline_style
+        } else {
+            UnderlineStyle::Single
+        }
+    }
+
     /// Gets the strikethrough text attribute setting.
     ///
     /// Returns `true` if strikethrough text has been explicitly enabled, `false` otherwise.
@@ -307,7 +330,7 @@ impl Style {
     /// ```
     pub fn get_foreground(&self) -> Option<Color> {
         if self.is_set(FOREGROUND_KEY) {
-            self.fg_color.as_ref().map(|s| Color::from(s.as_str()))
+            self.fg_color.as_ref().map(|c| self.resolve_color(c))
         } else {
             None
         }
@@ -335,7 +358,7 @@ impl Style {
     /// ```
     pub fn get_background(&self) -> Option<Color> {
         if self.is_set(BACKGROUND_KEY) {
-            self.bg_color.as_ref().map(|s| Color::from(s.as_str()))
+            self.bg_color.as_ref().map(|c| self.resolve_color(c))
         } else {
             None
         }
@@ -363,14 +386,47 @@ impl Style {
     /// ```
     pub fn get_margin_background(&self) -> Option<Color> {
         if self.is_set(MARGIN_BACKGROUND_KEY) {
-            self.margin_bg_color
-                .as_ref()
-                .map(|s| Color::from(s.as_str()))
+            self.margin_bg_color.as_ref().map(|c| self.resolve_color(c))
         } else {
             None
         }
     }
 
+    /// Resolves a stored color to a token using this style's renderer, or the
+    /// default renderer when none is set.
+    pub(crate) fn color_token(&self, c: &StyleColor) -> String {
+        match &self.r {
+            Some(r) => c.token(r),
+            None => c.token(default_renderer()),
+        }
+    }
+
+    /// Gets the underline color.
+    ///
+    /// Returns the color set with [`Style::underline_color`], or `None` if
+    /// the underline takes the foreground color.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().underline_color("#ff0000");
+    /// assert!(style.get_underline_color().is_some());
+    /// assert!(Style::new().get_underline_color().is_none());
+    /// ```
+    pub fn get_underline_color(&self) -> Option<Color> {
+        if self.is_set(UNDERLINE_COLOR_KEY) {
+            self.underline_color.as_ref().map(|c| self.resolve_color(c))
+        } else {
+            None
+        }
+    }
+
+    fn resolve_color(&self, c: &StyleColor) -> Color {
+        Color::from(self.color_token(c).as_str())
+    }
+
     // ---------- Size getters ----------
 
     /// Gets the width constraint.
@@ -479,6 +535,28 @@ impl Style {
         }
     }
 
+    /// Gets the truncation indicator.
+    ///
+    /// Returns the indicator set with [`Style::truncation_indicator`], or an
+    /// empty string if none has been configured.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().truncation_indicator("…");
+    /// assert_eq!(style.get_truncation_indicator(), "…");
+    /// assert_eq!(Style::new().get_truncation_indicator(), "");
+    /// ```
+    pub fn get_truncation_indicator(&self) -> String {
+        if self.is_set(TRUNCATION_INDICATOR_KEY) {
+            self.truncation_indicator.clone()
+        } else {
+            String::new()
+        }
+    }
+
     // ---------- Alignment getters ----------
 
     /// Gets the horizontal alignment setting.
@@ -975,6 +1053,24 @@ impl Style {
         !self.is_set(BORDER_LEFT_KEY) || self.get_attr(ATTR_BORDER_LEFT)
     }
 
+    /// Gets the override set for a border piece with
+    /// [`border_char`](Self::border_char), if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{rounded_border, BorderPiece, Style};
+    ///
+    /// let style = Style::new()
+    ///     .border(rounded_border())
+    ///     .border_char(BorderPiece::BottomLeft, "┴");
+    /// assert_eq!(style.get_border_char(BorderPiece::BottomLeft), Some("┴"));
+    /// assert_eq!(style.get_border_char(BorderPiece::BottomRight), None);
+    /// ```
+    pub fn get_border_char(&self, piece: BorderPiece) -> Option<&str> {
+        self.border_chars[piece.index()].as_deref()
+    }
+
     // ---------- Other getters ----------
 
     /// Gets the tab width setting.
@@ -1004,6 +1100,78 @@ impl Style {
         }
     }
 
+    /// Gets the minimum foreground contrast ratio.
+    ///
+    /// # Returns
+    ///
+    /// Returns the ratio set with [`Style::min_contrast`], or `0.0` if the
+    /// contrast guard is off.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// assert_eq!(Style::new().min_contrast(4.5).get_min_contrast(), 4.5);
+    /// assert_eq!(Style::new().get_min_contrast(), 0.0);
+    /// ```
+    pub fn get_min_contrast(&self) -> f64 {
+        if self.is_set(MIN_CONTRAST_KEY) {
+            self.min_contrast
+        } else {
+            0.0
+        }
+    }
+
+    /// Gets the foreground gradient stops.
+    ///
+    /// # Returns
+    ///
+    /// Returns the stops set with [`Style::foreground_gradient`], or an empty
+    /// `Vec` if no foreground gradient is set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{Color, Style};
+    ///
+    /// let stops = [Color::from("#ff0000"), Color::from("#0000ff")];
+    /// let style = Style::new().foreground_gradient(&stops);
+    /// assert_eq!(style.get_foreground_gradient(), stops);
+    /// assert!(Style::new().get_foreground_gradient().is_empty());
+    /// ```
+    pub fn get_foreground_gradient(&self) -> Vec<Color> {
+        if self.is_set(FOREGROUND_GRADIENT_KEY) {
+            self.fg_gradient.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Gets the background gradient stops.
+    ///
+    /// # Returns
+    ///
+    /// Returns the stops set with [`Style::background_gradient`], or an empty
+    /// `Vec` if no background gradient is set.
+    pub fn get_background_gradient(&self) -> Vec<Color> {
+        if self.is_set(BACKGROUND_GRADIENT_KEY) {
+            self.bg_gradient.clone()
+        } else {
+            Vec::new()
+        }
+    }
+
+    /// Gets whether gradients span the whole block.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if gradients run once across all lines, `false` if they
+    /// restart on each line (the default).
+    pub fn get_gradient_spans_block(&self) -> bool {
+        self.is_set(GRADIENT_SPANS_BLOCK_KEY) && self.get_attr(ATTR_GRADIENT_SPANS_BLOCK)
+    }
+
     /// Gets information about the transform function.
     ///
     /// Since transform functions cannot be cloned or inspected, this method returns
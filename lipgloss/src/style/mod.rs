@@ -61,6 +61,7 @@ pub use core::Style;
 
 // Specialized functionality modules
 mod attributes;
+pub use attributes::UnderlineStyle;
 mod borders;
 mod colors;
 mod equality;
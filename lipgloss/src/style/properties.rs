@@ -329,14 +329,112 @@ pub(crate) const TAB_WIDTH_KEY: PropKey = 1 << 41;
 /// This function will be applied to the content during rendering.
 pub(crate) const TRANSFORM_KEY: PropKey = 1 << 42;
 
+/// Property key for the minimum foreground contrast ratio.
+///
+/// When set, the foreground color is adjusted at render time until it meets
+/// the configured WCAG contrast ratio against the background.
+pub(crate) const MIN_CONTRAST_KEY: PropKey = 1 << 43;
+
+/// Property key for the foreground gradient.
+///
+/// When set, the gradient stops are blended across the visible characters of
+/// the content at render time, each character taking its own foreground.
+pub(crate) const FOREGROUND_GRADIENT_KEY: PropKey = 1 << 44;
+
+/// Property key for the background gradient.
+///
+/// When set, the gradient stops are blended across the visible characters of
+/// the content at render time, each character taking its own background.
+pub(crate) const BACKGROUND_GRADIENT_KEY: PropKey = 1 << 45;
+
+/// Property key for the gradient span mode.
+///
+/// When set, indicates whether gradients span the whole block has been
+/// explicitly configured, rather than restarting on every line.
+pub(crate) const GRADIENT_SPANS_BLOCK_KEY: PropKey = 1 << 46;
+
+/// Property key for the truncation indicator.
+///
+/// When set, the indicator marks content cut off by the maximum width or
+/// maximum height constraints.
+pub(crate) const TRUNCATION_INDICATOR_KEY: PropKey = 1 << 47;
+
+/// Property key for the underline style.
+///
+/// When set, indicates that the shape of the underline (curly, dotted, ...)
+/// has been explicitly configured.
+pub(crate) const UNDERLINE_STYLE_KEY: PropKey = 1 << 48;
+
+/// Property key for the underline color.
+///
+/// When set, the underline is drawn in its own color (SGR 58) rather than
+/// the foreground color.
+pub(crate) const UNDERLINE_COLOR_KEY: PropKey = 1 << 49;
+
+// Property classes - Masks grouping the keys above for the *_only projections
+
+/// Keys for every color property: text, margin and border colors, the
+/// minimum contrast adjustment applied to the foreground, gradients and the
+/// underline color.
+pub(crate) const COLOR_KEYS: PropKey = FOREGROUND_KEY
+    | BACKGROUND_KEY
+    | MARGIN_BACKGROUND_KEY
+    | BORDER_TOP_FOREGROUND_KEY
+    | BORDER_RIGHT_FOREGROUND_KEY
+    | BORDER_BOTTOM_FOREGROUND_KEY
+    | BORDER_LEFT_FOREGROUND_KEY
+    | BORDER_TOP_BACKGROUND_KEY
+    | BORDER_RIGHT_BACKGROUND_KEY
+    | BORDER_BOTTOM_BACKGROUND_KEY
+    | BORDER_LEFT_BACKGROUND_KEY
+    | MIN_CONTRAST_KEY
+    | FOREGROUND_GRADIENT_KEY
+    | BACKGROUND_GRADIENT_KEY
+    | GRADIENT_SPANS_BLOCK_KEY
+    | UNDERLINE_COLOR_KEY;
+
+/// Keys for the boolean text attributes and their whitespace variants.
+pub(crate) const ATTRIBUTE_KEYS: PropKey = BOLD_KEY
+    | ITALIC_KEY
+    | UNDERLINE_KEY
+    | UNDERLINE_STYLE_KEY
+    | STRIKETHROUGH_KEY
+    | REVERSE_KEY
+    | BLINK_KEY
+    | FAINT_KEY
+    | UNDERLINE_SPACES_KEY
+    | STRIKETHROUGH_SPACES_KEY
+    | COLOR_WHITESPACE_KEY;
+
+/// Keys for sizing, alignment, padding, margins and border edges.
+pub(crate) const BOX_MODEL_KEYS: PropKey = WIDTH_KEY
+    | HEIGHT_KEY
+    | MAX_WIDTH_KEY
+    | MAX_HEIGHT_KEY
+    | TRUNCATION_INDICATOR_KEY
+    | ALIGN_HORIZONTAL_KEY
+    | ALIGN_VERTICAL_KEY
+    | PADDING_TOP_KEY
+    | PADDING_RIGHT_KEY
+    | PADDING_BOTTOM_KEY
+    | PADDING_LEFT_KEY
+    | MARGIN_TOP_KEY
+    | MARGIN_RIGHT_KEY
+    | MARGIN_BOTTOM_KEY
+    | MARGIN_LEFT_KEY
+    | BORDER_STYLE_KEY
+    | BORDER_TOP_KEY
+    | BORDER_RIGHT_KEY
+    | BORDER_BOTTOM_KEY
+    | BORDER_LEFT_KEY;
+
 // Default values - These define standard default values for properties
 
 /// Default tab width in characters.
 ///
 /// This is the standard width used for tab characters when no custom tab width
-/// has been configured. The value of 4 is commonly used in many text editors
-/// and terminals.
-pub(crate) const TAB_WIDTH_DEFAULT: i32 = 4;
+/// has been configured. See [`crate::DEFAULT_TAB_WIDTH`] for the tab policy.
+pub(crate) const TAB_WIDTH_DEFAULT: i32 = crate::DEFAULT_TAB_WIDTH;
 
 // Attribute bitfield constants for Style struct - These store the actual boolean values
 //
@@ -423,3 +521,9 @@ pub(crate) const ATTR_BORDER_BOTTOM: u32 = 1 << 13;
 ///
 /// When this bit is set in the Style's `attrs` field, the left border edge is visible.
 pub(crate) const ATTR_BORDER_LEFT: u32 = 1 << 14;
+
+/// Attribute flag for gradients spanning the whole block.
+///
+/// When this bit is set in the Style's `attrs` field, foreground and background
+/// gradients run once across all lines instead of restarting on each line.
+pub(crate) const ATTR_GRADIENT_SPANS_BLOCK: u32 = 1 << 15;
@@ -4,11 +4,185 @@
 //! into terminal-ready output with appropriate ANSI escape sequences for colors,
 //! attributes, borders, spacing, and layout.
 
-use crate::color::parse_hex_rgba;
-use crate::renderer::{default_renderer, ColorProfileKind};
+use crate::blending::blend_1d;
+use crate::border::BorderPiece;
+use crate::color::{ensure_contrast, parse_hex_rgba, Color, StyleColor, TerminalColor};
+use crate::renderer::{default_renderer, ColorProfileKind, Renderer};
 use crate::security::{safe_repeat, safe_str_repeat};
-use crate::style::{properties::*, Style};
+use crate::style::{properties::*, Style, UnderlineStyle};
+use crate::utils::{escape_len, reapply_after_resets};
 use crate::width_visible;
+use unicode_width::UnicodeWidthChar;
+
+/// The edge and corner glyphs drawn around a rendered block, after any
+/// per-piece overrides have been applied.
+struct BoxGlyphs<'a> {
+    top: &'a str,
+    bottom: &'a str,
+    left: &'a str,
+    right: &'a str,
+    top_left: &'a str,
+    top_right: &'a str,
+    bottom_left: &'a str,
+    bottom_right: &'a str,
+}
+
+/// A rendered block's border: the top and bottom rows (empty when that side
+/// is off) and the pieces drawn either side of each content line.
+struct BorderRows {
+    top: String,
+    left: String,
+    right: String,
+    bottom: String,
+    /// Width of the content between the side pieces
+    width: usize,
+}
+
+/// Margin pieces around a rendered block: the number of blank rows above and
+/// below, the blank row itself, and the fill either side of each line.
+#[derive(Default)]
+struct Margins {
+    top: usize,
+    bottom: usize,
+    blank: String,
+    left: String,
+    right: String,
+}
+
+/// A piece of a content line as the gradient sees it.
+enum GradientPiece<'a> {
+    /// An escape sequence, copied through untouched
+    Escape(&'a str),
+    /// A visible character with any zero-width characters attached to it
+    Cluster(&'a str),
+}
+
+/// Splits a line into escape sequences and character clusters. A cluster is
+/// a character followed by any zero-width characters (combining marks,
+/// variation selectors), emoji skin tones and anything joined on with a
+/// zero-width joiner, so a wide or composed character takes a single
+/// gradient step.
+fn gradient_pieces(line: &str) -> Vec<GradientPiece<'_>> {
+    let mut pieces = Vec::new();
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        if c == '\x1b' {
+            let len = escape_len(rest);
+            pieces.push(GradientPiece::Escape(&rest[..len]));
+            rest = &rest[len..];
+            continue;
+        }
+        let mut end = c.len_utf8();
+        let mut joined = c == '\u{200d}';
+        for next in rest[end..].chars() {
+            let attached = next != '\x1b'
+                && (UnicodeWidthChar::width(next).unwrap_or(1) == 0
+                    || ('\u{1f3fb}'..='\u{1f3ff}').contains(&next));
+            if !(attached || (joined && next != '\x1b')) {
+                break;
+            }
+            joined = next == '\u{200d}';
+            end += next.len_utf8();
+        }
+        pieces.push(GradientPiece::Cluster(&rest[..end]));
+        rest = &rest[end..];
+    }
+    pieces
+}
+
+/// SGR parameters selecting `color` as the foreground (or background) in
+/// the given profile.
+fn gradient_sgr(color: &Color, profile: ColorProfileKind, background: bool) -> Option<String> {
+    if color.0.is_empty() {
+        return None;
+    }
+    let (r, g, b, _) = color.rgba();
+    let (r, g, b) = (r as u8, g as u8, b as u8);
+    let base = if background { 40 } else { 30 };
+    match profile {
+        ColorProfileKind::TrueColor => Some(format!("{};2;{};{};{}", base + 8, r, g, b)),
+        ColorProfileKind::ANSI256 => Some(format!(
+            "{};5;{}",
+            base + 8,
+            crate::color::rgb_to_ansi256(r, g, b)
+        )),
+        ColorProfileKind::ANSI => {
+            let idx = crate::color::rgb_to_ansi16(r, g, b) as u32;
+            if idx < 8 {
+                Some(format!("{}", base + idx))
+            } else {
+                Some(format!("{}", base + 60 + idx - 8))
+            }
+        }
+        ColorProfileKind::NoColor => None,
+    }
+}
+
+/// SGR parameters selecting the underline color `tok`, a hex color or an
+/// ANSI index, in the given profile. Only extended color profiles have one.
+fn underline_color_sgr(tok: &str, profile: ColorProfileKind) -> Option<String> {
+    if tok.starts_with('#') {
+        let (r, g, b, _) = parse_hex_rgba(tok)?;
+        match profile {
+            ColorProfileKind::TrueColor => Some(format!("58;2;{};{};{}", r, g, b)),
+            ColorProfileKind::ANSI256 => Some(format!(
+                "58;5;{}",
+                crate::color::rgb_to_ansi256(r as u8, g as u8, b as u8)
+            )),
+            ColorProfileKind::ANSI | ColorProfileKind::NoColor => None,
+        }
+    } else {
+        let idx = tok.parse::<u32>().ok()? % 256;
+        match profile {
+            ColorProfileKind::TrueColor | ColorProfileKind::ANSI256 => {
+                Some(format!("58;5;{}", idx))
+            }
+            ColorProfileKind::ANSI | ColorProfileKind::NoColor => None,
+        }
+    }
+}
+
+/// How the style's SGR sequence is laid over each canvas line.
+enum Paint<'a> {
+    /// No attributes to apply
+    None,
+    /// Paint the whole line, spaces included
+    Whole(&'a str),
+    /// Paint only the text between leading and trailing spaces
+    Content(&'a str),
+}
+
+impl Paint<'_> {
+    fn write_line<W: std::fmt::Write>(&self, w: &mut W, line: &str) -> std::fmt::Result {
+        match *self {
+            Paint::None => w.write_str(line),
+            Paint::Whole(prefix) => {
+                // Pre-styled content ends its own styling with resets, which
+                // would also clear ours for the rest of the line.
+                w.write_str(prefix)?;
+                if line.contains("\x1b[") {
+                    w.write_str(&reapply_after_resets(line, prefix))?;
+                } else {
+                    w.write_str(line)?;
+                }
+                w.write_str("\x1b[0m")
+            }
+            Paint::Content(prefix) => {
+                let content = line.trim_matches(' ');
+                if content.is_empty() {
+                    return w.write_str(line);
+                }
+                let start = line.len() - line.trim_start_matches(' ').len();
+                let end = start + content.len();
+                w.write_str(&line[..start])?;
+                w.write_str(prefix)?;
+                w.write_str(content)?;
+                w.write_str("\x1b[0m")?;
+                w.write_str(&line[end..])
+            }
+        }
+    }
+}
 
 impl Style {
     /// Renders text with all configured style properties applied.
@@ -48,16 +222,49 @@ impl Style {
     /// This method efficiently builds ANSI sequences by only including codes for
     /// properties that have been explicitly set on the style, minimizing output size.
     pub fn render(&self, s: &str) -> String {
-        // Content to render: prefer internal value if set
-        let content = if !self.value.is_empty() {
+        let mut out = String::with_capacity(s.len());
+        self.render_to(&mut out, s)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    /// Renders text like [`render`](Self::render), writing the result into `w`
+    /// instead of returning a new `String`.
+    ///
+    /// Use this when composing many styled fragments into one buffer, such as
+    /// a frame built up line by line: the output is appended to `w` as it is
+    /// produced, so no intermediate `String` is allocated for the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - The writer to append the styled output to
+    /// * `s` - The text content to render with this style
+    ///
+    /// # Errors
+    ///
+    /// Returns any error reported by `w`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().padding_left(1);
+    /// let mut frame = String::new();
+    /// for word in ["one", "two"] {
+    ///     style.render_to(&mut frame, word).unwrap();
+    /// }
+    /// assert_eq!(frame, " one two");
+    /// ```
+    pub fn render_to<W: std::fmt::Write>(&self, w: &mut W, s: &str) -> std::fmt::Result {
+        // Content to render: prefer internal value if set. This is the base
+        // rendered string built up before colorizing: borders, padding, etc.
+        let mut rendered = if !self.value.is_empty() {
             self.value.clone()
         } else {
             s.to_string()
         };
 
-        // First, build the base rendered string before colorizing: borders, padding, etc.
-        let mut rendered = content.clone();
-
         // Normalize newlines: convert CRLF/CR to LF
         if rendered.contains('\r') {
             rendered = rendered.replace("\r\n", "\n");
@@ -76,31 +283,28 @@ impl Style {
             }
         }
 
-        // Tabs handling: default 4 spaces, 0 removes, -1 keeps as-is, n>0 replaces with n spaces
-        let tabw = self.get_tab_width();
-        if tabw == 0 {
-            rendered = rendered.replace('\t', "");
-        } else if tabw > 0 {
-            let spaces = safe_repeat(' ', tabw as usize);
-            rendered = rendered.replace('\t', &spaces);
-        } // tabw < 0 => keep tabs as-is
+        // Tabs go to tab stops every n columns (4 by default), 0 removes them,
+        // -1 keeps them as-is. This happens before anything is measured.
+        rendered = crate::utils::expand_tabs(&rendered, self.get_tab_width());
 
         // Max height truncation
         let mh = self.get_max_height();
         if mh > 0 {
-            let lines: Vec<&str> = rendered.split('\n').collect();
-            if (lines.len() as i32) > mh {
-                rendered = lines[..mh as usize].join("\n");
-            }
+            rendered = self.truncate_height(&rendered);
         }
 
         // Max width truncation per line (ANSI-aware)
         let mw = self.get_max_width();
         if mw > 0 {
+            let indicator = self.get_truncation_indicator();
             let lines: Vec<&str> = rendered.split('\n').collect();
             let mut out_lines: Vec<String> = Vec::with_capacity(lines.len());
             for line in lines {
-                out_lines.push(Self::truncate_visible_line(line, mw as usize));
+                out_lines.push(Self::truncate_line_with_indicator(
+                    line,
+                    mw as usize,
+                    &indicator,
+                ));
             }
             rendered = out_lines.join("\n");
         }
@@ -137,6 +341,11 @@ impl Style {
             }
         }
 
+        // Gradients color the content itself, before any padding goes around it
+        if self.is_set(FOREGROUND_GRADIENT_KEY) || self.is_set(BACKGROUND_GRADIENT_KEY) {
+            rendered = self.apply_gradients(&rendered);
+        }
+
         // Horizontal alignment is now handled in the final intelligent styling pass
 
         // Padding left/right applied per line
@@ -217,7 +426,15 @@ impl Style {
             sgr.push("3".to_string());
         }
         if self.get_attr(ATTR_UNDERLINE) && self.is_set(UNDERLINE_KEY) {
-            sgr.push("4".to_string());
+            // Only profiles with extended colors are assumed to know the
+            // extended underline shapes; the rest get a plain underline.
+            let shape = match profile {
+                ColorProfileKind::TrueColor | ColorProfileKind::ANSI256 => {
+                    self.get_underline_style()
+                }
+                ColorProfileKind::ANSI | ColorProfileKind::NoColor => UnderlineStyle::Single,
+            };
+            sgr.push(shape.sgr().to_string());
         }
         if self.get_attr(ATTR_BLINK) && self.is_set(BLINK_KEY) {
             sgr.push("5".to_string());
@@ -231,7 +448,9 @@ impl Style {
 
         // Foreground color
         if !matches!(profile, ColorProfileKind::NoColor) && self.is_set(FOREGROUND_KEY) {
-            if let Some(ref tok) = self.fg_color {
+            let guarded = self.contrast_guarded_fg(&eff, profile);
+            let fg = guarded.or_else(|| self.fg_color.as_ref().map(|c| c.token(&eff)));
+            if let Some(ref tok) = fg {
                 if tok.starts_with('#') {
                     if let Some((r, g, b, _a)) = parse_hex_rgba(tok) {
                         match profile {
@@ -239,8 +458,21 @@ impl Style {
                                 // RGB values are already 8-bit (0-255) cast to u32
                                 sgr.push(format!("38;2;{};{};{}", r, g, b));
                             }
-                            ColorProfileKind::ANSI | ColorProfileKind::ANSI256 => {
-                                sgr.push("38;5;0".to_string())
+                            ColorProfileKind::ANSI256 => {
+                                // Convert RGB to ANSI256 foreground color
+                                let ansi256_idx =
+                                    crate::color::rgb_to_ansi256(r as u8, g as u8, b as u8);
+                                sgr.push(format!("38;5;{}", ansi256_idx));
+                            }
+                            ColorProfileKind::ANSI => {
+                                // Convert RGB to ANSI16 foreground color
+                                let ansi16_idx =
+                                    crate::color::rgb_to_ansi16(r as u8, g as u8, b as u8);
+                                if ansi16_idx < 8 {
+                                    sgr.push(format!("{}", 30 + ansi16_idx));
+                                } else {
+                                    sgr.push(format!("{}", 82 + ansi16_idx));
+                                }
                             }
                             ColorProfileKind::NoColor => {}
                         }
@@ -275,7 +507,7 @@ impl Style {
 
         // Background color
         if !matches!(profile, ColorProfileKind::NoColor) && self.is_set(BACKGROUND_KEY) {
-            if let Some(ref tok) = self.bg_color {
+            if let Some(ref tok) = self.bg_color.as_ref().map(|c| c.token(&eff)) {
                 if tok.starts_with('#') {
                     if let Some((r, g, b, _a)) = parse_hex_rgba(tok) {
                         match profile {
@@ -293,7 +525,11 @@ impl Style {
                                 // Convert RGB to ANSI16 background color
                                 let ansi16_idx =
                                     crate::color::rgb_to_ansi16(r as u8, g as u8, b as u8);
-                                sgr.push(format!("{}", 40 + ansi16_idx));
+                                if ansi16_idx < 8 {
+                                    sgr.push(format!("{}", 40 + ansi16_idx));
+                                } else {
+                                    sgr.push(format!("{}", 92 + ansi16_idx));
+                                }
                             }
                             ColorProfileKind::NoColor => {}
                         }
@@ -323,6 +559,14 @@ impl Style {
             }
         }
 
+        // Underline color, which the ANSI profile has no way to express
+        if self.get_underline() && self.is_set(UNDERLINE_COLOR_KEY) {
+            let tok = self.underline_color.as_ref().map(|c| c.token(&eff));
+            if let Some(code) = tok.and_then(|tok| underline_color_sgr(&tok, profile)) {
+                sgr.push(code);
+            }
+        }
+
         // Final styling pass - "Layout First, Styling Second" approach
         let target_width = self.get_width();
         let target_height = self.get_height();
@@ -344,20 +588,32 @@ impl Style {
         // If no SGR codes, no width/height constraints, no borders, and no margins, we're done.
         if sgr.is_empty() && target_width <= 0 && target_height <= 0 && !has_borders && !has_margins
         {
-            return rendered;
+            return w.write_str(&rendered);
         }
 
         let lines: Vec<&str> = rendered.split('\n').collect();
         let mut final_lines = Vec::with_capacity(lines.len());
 
+        // Without a width, a multi-line block is as wide as its widest line.
+        // Short lines and padding rows are filled out to it so that the painted
+        // area is a rectangle and the right margin starts on the same column
+        // on every line.
+        let canvas_width = if target_width > 0 {
+            target_width as usize
+        } else if lines.len() > 1 {
+            lines.iter().map(|l| width_visible(l)).max().unwrap_or(0)
+        } else {
+            0
+        };
+
         // LAYOUT FIRST: Create full-width canvas with alignment padding
         for line in lines {
             let mut canvas_line = line.to_string();
 
-            // If a width is set, create full-width canvas with alignment padding
-            if target_width > 0 {
+            // Create full-width canvas with alignment padding
+            if canvas_width > 0 {
                 let line_vis_width = width_visible(&canvas_line);
-                let gap = (target_width as usize).saturating_sub(line_vis_width);
+                let gap = canvas_width.saturating_sub(line_vis_width);
 
                 if gap > 0 {
                     let h_pos = self.get_align_horizontal().value();
@@ -409,327 +665,422 @@ impl Style {
             final_lines = height_adjusted;
         }
 
+        // STYLING SECOND: Apply styling to entire canvas. This happens before the
+        // borders go on, so the background covers content and padding only.
+        let prefix = if sgr.is_empty() {
+            String::new()
+        } else {
+            format!("\x1b[{}m", sgr.join(";"))
+        };
+        let paint = if sgr.is_empty() {
+            Paint::None
+        } else if self.get_background().is_some()
+            || self.get_color_whitespace()
+            || (self.get_underline() && self.get_underline_spaces())
+            || (self.get_strikethrough() && self.get_strikethrough_spaces())
+        {
+            // For background colors or styled spaces, style the entire canvas
+            Paint::Whole(&prefix)
+        } else {
+            // For foreground-only styling, style only non-whitespace parts
+            Paint::Content(&prefix)
+        };
+
         // Apply borders after layout constraints have been applied
         let render_borders = (self.get_border_top()
             || self.get_border_right()
             || self.get_border_bottom()
             || self.get_border_left())
             && self.is_set(BORDER_STYLE_KEY);
-        if render_borders {
-            let b = self.get_border_style();
+        let borders = if render_borders {
             // Compute target width from the maximum visible width across all lines
-            let mut w: usize = 0;
-            for l in &final_lines {
-                w = w.max(width_visible(l));
-            }
-            // Determine effective renderer/profile
-            let eff = self.r.clone().unwrap_or_else(|| default_renderer().clone());
-            let profile = eff.color_profile();
-
-            // Helper to build SGR for a side using per-side then combined tokens
-            let edge_sgr = |fg_opt: &Option<String>,
-                            bg_opt: &Option<String>,
-                            fg_combined: &Option<String>,
-                            bg_combined: &Option<String>|
-             -> String {
-                if matches!(profile, ColorProfileKind::NoColor) {
-                    return String::new();
+            let w = final_lines
+                .iter()
+                .map(|l| width_visible(l))
+                .max()
+                .unwrap_or(0);
+            Some(self.border_rows(w))
+        } else {
+            None
+        };
+
+        // Margins go around everything, so they need the width of the whole block
+        let block_width = match &borders {
+            Some(b) => {
+                let side = width_visible(&b.left) + b.width + width_visible(&b.right);
+                side.max(width_visible(&b.top))
+                    .max(width_visible(&b.bottom))
+            }
+            None => final_lines
+                .iter()
+                .map(|l| width_visible(l))
+                .max()
+                .unwrap_or(0),
+        };
+        let margins = self.margins(block_width);
+
+        let mut first = true;
+        let mut row_start = |w: &mut W| -> std::fmt::Result {
+            if !std::mem::take(&mut first) {
+                w.write_char('\n')?;
+            }
+            Ok(())
+        };
+        for _ in 0..margins.top {
+            row_start(w)?;
+            w.write_str(&margins.blank)?;
+        }
+        if let Some(b) = borders.as_ref().filter(|b| !b.top.is_empty()) {
+            row_start(w)?;
+            w.write_str(&margins.left)?;
+            w.write_str(&b.top)?;
+            w.write_str(&margins.right)?;
+        }
+        for line in &final_lines {
+            row_start(w)?;
+            w.write_str(&margins.left)?;
+            match &borders {
+                Some(b) => {
+                    w.write_str(&b.left)?;
+                    paint.write_line(w, line)?;
+                    let pad = b.width.saturating_sub(width_visible(line));
+                    if pad > 0 {
+                        w.write_str(&safe_repeat(' ', pad))?;
+                    }
+                    w.write_str(&b.right)?;
                 }
-                let fg = fg_opt.as_ref().or(fg_combined.as_ref());
-                let bg = bg_opt.as_ref().or(bg_combined.as_ref());
-                let mut parts: Vec<String> = Vec::new();
-                if let Some(tok) = fg {
-                    if tok.starts_with('#') {
-                        if let Some((r, g, b, _)) = parse_hex_rgba(tok) {
-                            // RGB values are already 8-bit (0-255) cast to u32
-                            parts.push(format!("38;2;{};{};{}", r, g, b));
-                        } else {
-                            // fallback for ANSI profiles
-                            parts.push("38;5;0".to_string());
-                        }
-                    } else if let Ok(idx) = tok.parse::<u32>() {
-                        let idx = idx % 256;
-                        match profile {
-                            ColorProfileKind::ANSI => {
-                                if idx <= 7 {
-                                    parts.push(format!("{}", 30 + idx)); // 30-37
-                                } else if idx <= 15 {
-                                    parts.push(format!("{}", 82 + idx)); // 90-97
-                                } else if (30..=37).contains(&idx) {
-                                    parts.push(format!("{}", idx)); // Direct ANSI codes 30-37
-                                } else if (90..=97).contains(&idx) {
-                                    parts.push(format!("{}", idx)); // Direct ANSI codes 90-97
-                                } else {
-                                    parts.push("39".to_string()); // default
-                                }
+                None => paint.write_line(w, line)?,
+            }
+            w.write_str(&margins.right)?;
+        }
+        if let Some(b) = borders.as_ref().filter(|b| !b.bottom.is_empty()) {
+            row_start(w)?;
+            w.write_str(&margins.left)?;
+            w.write_str(&b.bottom)?;
+            w.write_str(&margins.right)?;
+        }
+        for _ in 0..margins.bottom {
+            row_start(w)?;
+            w.write_str(&margins.blank)?;
+        }
+        Ok(())
+    }
+
+    /// Builds the border rows and side pieces for content `w` columns wide.
+    fn border_rows(&self, w: usize) -> BorderRows {
+        let border = self.get_border_style();
+        let b = BoxGlyphs {
+            top: self.border_glyph(&border, BorderPiece::Top),
+            bottom: self.border_glyph(&border, BorderPiece::Bottom),
+            left: self.border_glyph(&border, BorderPiece::Left),
+            right: self.border_glyph(&border, BorderPiece::Right),
+            top_left: self.border_glyph(&border, BorderPiece::TopLeft),
+            top_right: self.border_glyph(&border, BorderPiece::TopRight),
+            bottom_left: self.border_glyph(&border, BorderPiece::BottomLeft),
+            bottom_right: self.border_glyph(&border, BorderPiece::BottomRight),
+        };
+
+        // Determine effective renderer/profile
+        let eff = self.r.clone().unwrap_or_else(|| default_renderer().clone());
+        let profile = eff.color_profile();
+
+        // Helper to build SGR for a side. The foreground falls back to the
+        // style's, the background is the border's own only.
+        let edge_sgr = |fg_opt: &Option<String>,
+                        bg_opt: &Option<String>,
+                        fg_combined: &Option<String>|
+         -> String {
+            if matches!(profile, ColorProfileKind::NoColor) {
+                return String::new();
+            }
+            let fg = fg_opt.as_ref().or(fg_combined.as_ref());
+            let bg = bg_opt.as_ref();
+            let mut parts: Vec<String> = Vec::new();
+            if let Some(tok) = fg {
+                if tok.starts_with('#') {
+                    if let Some((r, g, b, _)) = parse_hex_rgba(tok) {
+                        // RGB values are already 8-bit (0-255) cast to u32
+                        parts.push(format!("38;2;{};{};{}", r, g, b));
+                    } else {
+                        // fallback for ANSI profiles
+                        parts.push("38;5;0".to_string());
+                    }
+                } else if let Ok(idx) = tok.parse::<u32>() {
+                    let idx = idx % 256;
+                    match profile {
+                        ColorProfileKind::ANSI => {
+                            if idx <= 7 {
+                                parts.push(format!("{}", 30 + idx)); // 30-37
+                            } else if idx <= 15 {
+                                parts.push(format!("{}", 82 + idx)); // 90-97
+                            } else if (30..=37).contains(&idx) {
+                                parts.push(format!("{}", idx)); // Direct ANSI codes 30-37
+                            } else if (90..=97).contains(&idx) {
+                                parts.push(format!("{}", idx)); // Direct ANSI codes 90-97
+                            } else {
+                                parts.push("39".to_string()); // default
                             }
-                            _ => parts.push(format!("38;5;{}", idx)),
                         }
+                        _ => parts.push(format!("38;5;{}", idx)),
                     }
                 }
-                if let Some(tok) = bg {
-                    if tok.starts_with('#') {
-                        if let Some((r, g, b, _)) = parse_hex_rgba(tok) {
-                            // RGB values are already 8-bit (0-255) cast to u32
-                            parts.push(format!("48;2;{};{};{}", r, g, b));
-                        } else {
-                            parts.push("48;5;0".to_string());
-                        }
-                    } else if let Ok(idx) = tok.parse::<u32>() {
-                        let idx = idx % 256;
-                        match profile {
-                            ColorProfileKind::ANSI => {
-                                if idx <= 7 {
-                                    parts.push(format!("{}", 40 + idx)); // 40-47
-                                } else if idx <= 15 {
-                                    parts.push(format!("{}", 92 + idx)); // 100-107
-                                } else if (40..=47).contains(&idx) {
-                                    parts.push(format!("{}", idx)); // Direct ANSI codes 40-47
-                                } else if (100..=107).contains(&idx) {
-                                    parts.push(format!("{}", idx)); // Direct ANSI codes 100-107
-                                } else {
-                                    parts.push("49".to_string()); // default
-                                }
+            }
+            if let Some(tok) = bg {
+                if tok.starts_with('#') {
+                    if let Some((r, g, b, _)) = parse_hex_rgba(tok) {
+                        // RGB values are already 8-bit (0-255) cast to u32
+                        parts.push(format!("48;2;{};{};{}", r, g, b));
+                    } else {
+                        parts.push("48;5;0".to_string());
+                    }
+                } else if let Ok(idx) = tok.parse::<u32>() {
+                    let idx = idx % 256;
+                    match profile {
+                        ColorProfileKind::ANSI => {
+                            if idx <= 7 {
+                                parts.push(format!("{}", 40 + idx)); // 40-47
+                            } else if idx <= 15 {
+                                parts.push(format!("{}", 92 + idx)); // 100-107
+                            } else if (40..=47).contains(&idx) {
+                                parts.push(format!("{}", idx)); // Direct ANSI codes 40-47
+                            } else if (100..=107).contains(&idx) {
+                                parts.push(format!("{}", idx)); // Direct ANSI codes 100-107
+                            } else {
+                                parts.push("49".to_string()); // default
                             }
-                            _ => parts.push(format!("48;5;{}", idx)),
                         }
+                        _ => parts.push(format!("48;5;{}", idx)),
                     }
                 }
-                if parts.is_empty() {
-                    String::new()
-                } else {
-                    format!("\x1b[{}m", parts.join(";"))
-                }
-            };
+            }
+            if parts.is_empty() {
+                String::new()
+            } else {
+                format!("\x1b[{}m", parts.join(";"))
+            }
+        };
+
+        // Use stored combined fields if set via colors.rs helpers
+        let combined_fg = self.border_top_fg_color.is_some()
+            || self.border_right_fg_color.is_some()
+            || self.border_bottom_fg_color.is_some()
+            || self.border_left_fg_color.is_some();
+        let token = |c: &Option<StyleColor>| c.as_ref().map(|c| c.token(&eff));
+        let fg_combined = if combined_fg {
+            None
+        } else {
+            token(&self.fg_color)
+        };
 
-            // Use stored combined fields if set via colors.rs helpers
-            let combined_fg = self.border_top_fg_color.is_some()
-                || self.border_right_fg_color.is_some()
-                || self.border_bottom_fg_color.is_some()
-                || self.border_left_fg_color.is_some();
-            let combined_bg = self.border_top_bg_color.is_some()
-                || self.border_right_bg_color.is_some()
-                || self.border_bottom_bg_color.is_some()
-                || self.border_left_bg_color.is_some();
-            let fg_combined_ref = if combined_fg {
-                None
+        let top_sgr = edge_sgr(
+            &token(&self.border_top_fg_color),
+            &token(&self.border_top_bg_color),
+            &fg_combined,
+        );
+        let right_sgr = edge_sgr(
+            &token(&self.border_right_fg_color),
+            &token(&self.border_right_bg_color),
+            &fg_combined,
+        );
+        let bottom_sgr = edge_sgr(
+            &token(&self.border_bottom_fg_color),
+            &token(&self.border_bottom_bg_color),
+            &fg_combined,
+        );
+        let left_sgr = edge_sgr(
+            &token(&self.border_left_fg_color),
+            &token(&self.border_left_bg_color),
+            &fg_combined,
+        );
+        let reset = "\x1b[0m";
+
+        // Corners only join two drawn sides. As in Go, the corner of a side
+        // that is off is left out, so edges run flush with the content.
+        let (has_left, has_right) = (self.get_border_left(), self.get_border_right());
+        fn corner(glyph: &str, side: bool) -> &str {
+            if side {
+                glyph
             } else {
-                self.fg_color.as_ref()
-            };
-            let bg_combined_ref = if combined_bg {
-                None
+                ""
+            }
+        }
+        let edge = |sgr: &str, left: &str, middle: &str, right: &str| {
+            let line = format!("{}{}{}", left, safe_str_repeat(middle, w), right);
+            if sgr.is_empty() {
+                line
             } else {
-                self.bg_color.as_ref()
-            };
+                format!("{}{}{}", sgr, line, reset)
+            }
+        };
 
-            let top_sgr = edge_sgr(
-                &self.border_top_fg_color,
-                &self.border_top_bg_color,
-                &fg_combined_ref.cloned(),
-                &bg_combined_ref.cloned(),
-            );
-            let right_sgr = edge_sgr(
-                &self.border_right_fg_color,
-                &self.border_right_bg_color,
-                &fg_combined_ref.cloned(),
-                &bg_combined_ref.cloned(),
-            );
-            let bottom_sgr = edge_sgr(
-                &self.border_bottom_fg_color,
-                &self.border_bottom_bg_color,
-                &fg_combined_ref.cloned(),
-                &bg_combined_ref.cloned(),
-            );
-            let left_sgr = edge_sgr(
-                &self.border_left_fg_color,
-                &self.border_left_bg_color,
-                &fg_combined_ref.cloned(),
-                &bg_combined_ref.cloned(),
-            );
-            let reset = "\x1b[0m";
-
-            // Build top border (conditionally)
-            let top = if self.get_border_top() {
-                if top_sgr.is_empty() {
-                    format!(
-                        "{}{}{}",
-                        if self.get_border_left() {
-                            b.top_left
-                        } else {
-                            b.top
-                        },
-                        safe_str_repeat(b.top, w),
-                        if self.get_border_right() {
-                            b.top_right
-                        } else {
-                            b.top
-                        }
-                    )
-                } else {
-                    format!(
-                        "{}{}{}{}{}",
-                        top_sgr,
-                        if self.get_border_left() {
-                            b.top_left
-                        } else {
-                            b.top
-                        },
-                        safe_str_repeat(b.top, w),
-                        if self.get_border_right() {
-                            b.top_right
-                        } else {
-                            b.top
-                        },
-                        reset
-                    )
-                }
+        let top = if self.get_border_top() {
+            edge(
+                &top_sgr,
+                corner(b.top_left, has_left),
+                b.top,
+                corner(b.top_right, has_right),
+            )
+        } else {
+            String::new()
+        };
+
+        // Side pieces drawn on each content line
+        let left = if has_left {
+            if left_sgr.is_empty() {
+                b.left.to_string()
             } else {
-                String::new()
-            };
+                format!("{}{}{}", left_sgr, b.left, reset)
+            }
+        } else {
+            String::new()
+        };
+        let right = if has_right {
+            if right_sgr.is_empty() {
+                b.right.to_string()
+            } else {
+                format!("{}{}{}", right_sgr, b.right, reset)
+            }
+        } else {
+            String::new()
+        };
 
-            // Add left/right borders per line, padding each line to the max width
-            let mid = {
-                let mut out_lines: Vec<String> = Vec::with_capacity(final_lines.len());
-                let left_part_base = if self.get_border_left() {
-                    if left_sgr.is_empty() {
-                        b.left.to_string()
-                    } else {
-                        format!("{}{}{}", left_sgr, b.left, reset)
-                    }
-                } else {
-                    String::new()
-                };
-                let right_part_base = if self.get_border_right() {
-                    if right_sgr.is_empty() {
-                        b.right.to_string()
-                    } else {
-                        format!("{}{}{}", right_sgr, b.right, reset)
-                    }
-                } else {
-                    String::new()
-                };
-                for l in &final_lines {
-                    let lw = width_visible(l);
-                    let pad = w.saturating_sub(lw);
-                    let mut line_buf = String::with_capacity(w + 4);
-                    line_buf.push_str(&left_part_base);
-                    line_buf.push_str(l);
-                    if pad > 0 {
-                        line_buf.push_str(&safe_repeat(' ', pad));
-                    }
-                    line_buf.push_str(&right_part_base);
-                    out_lines.push(line_buf);
-                }
-                out_lines.join("\n")
-            };
+        let bot = if self.get_border_bottom() {
+            edge(
+                &bottom_sgr,
+                corner(b.bottom_left, has_left),
+                b.bottom,
+                corner(b.bottom_right, has_right),
+            )
+        } else {
+            String::new()
+        };
 
-            // Build bottom border (conditionally)
-            let bot = if self.get_border_bottom() {
-                if bottom_sgr.is_empty() {
-                    format!(
-                        "{}{}{}",
-                        if self.get_border_left() {
-                            b.bottom_left
-                        } else {
-                            b.bottom
-                        },
-                        safe_str_repeat(b.bottom, w),
-                        if self.get_border_right() {
-                            b.bottom_right
-                        } else {
-                            b.bottom
-                        }
-                    )
-                } else {
-                    format!(
-                        "{}{}{}{}{}",
-                        bottom_sgr,
-                        if self.get_border_left() {
-                            b.bottom_left
-                        } else {
-                            b.bottom
-                        },
-                        safe_str_repeat(b.bottom, w),
-                        if self.get_border_right() {
-                            b.bottom_right
-                        } else {
-                            b.bottom
-                        },
-                        reset
-                    )
-                }
-            } else {
-                String::new()
-            };
+        BorderRows {
+            top,
+            left,
+            right,
+            bottom: bot,
+            width: w,
+        }
+    }
 
-            // Combine the parts and update final_lines with bordered content
-            let bordered_content = if !top.is_empty() && !bot.is_empty() {
-                format!("{}\n{}\n{}", top, mid, bot)
-            } else if !top.is_empty() {
-                format!("{}\n{}", top, mid)
-            } else if !bot.is_empty() {
-                format!("{}\n{}", mid, bot)
+    /// Colors each visible character of `s` with its step of the foreground and
+    /// background gradients. Escape sequences already in `s` are copied through
+    /// and take no step. Each line that got colored ends with a reset, which
+    /// the styling pass restores the style's own attributes after.
+    fn apply_gradients(&self, s: &str) -> String {
+        let eff = self.r.clone().unwrap_or_else(|| default_renderer().clone());
+        let profile = eff.color_profile();
+        let fg_stops = self.get_foreground_gradient();
+        let bg_stops = self.get_background_gradient();
+        if matches!(profile, ColorProfileKind::NoColor)
+            || (fg_stops.is_empty() && bg_stops.is_empty())
+        {
+            return s.to_string();
+        }
+
+        let lines: Vec<Vec<GradientPiece>> = s.split('\n').map(gradient_pieces).collect();
+        let counts: Vec<usize> = lines
+            .iter()
+            .map(|l| {
+                l.iter()
+                    .filter(|p| matches!(p, GradientPiece::Cluster(_)))
+                    .count()
+            })
+            .collect();
+        let steps = |stops: &[Color], n: usize| -> Vec<Color> {
+            if stops.is_empty() || n == 0 {
+                return Vec::new();
+            }
+            let mut colors = blend_1d(n, stops.to_vec());
+            colors.truncate(n);
+            colors
+        };
+
+        // With the block spanned there is one run of steps, otherwise one per line
+        let span = self.get_gradient_spans_block();
+        let total: usize = counts.iter().sum();
+        let (block_fg, block_bg) = if span {
+            (steps(&fg_stops, total), steps(&bg_stops, total))
+        } else {
+            (Vec::new(), Vec::new())
+        };
+
+        let mut out = String::with_capacity(s.len() * 4);
+        let mut offset = 0;
+        for (li, (pieces, &count)) in lines.iter().zip(&counts).enumerate() {
+            if li > 0 {
+                out.push('\n');
+            }
+            let (line_fg, line_bg);
+            let (fg, bg, base) = if span {
+                (&block_fg, &block_bg, offset)
             } else {
-                mid
+                line_fg = steps(&fg_stops, count);
+                line_bg = steps(&bg_stops, count);
+                (&line_fg, &line_bg, 0)
             };
 
-            // Replace final_lines with the bordered content
-            final_lines = bordered_content
-                .split('\n')
-                .map(|s| s.to_string())
-                .collect();
-        }
-
-        // STYLING SECOND: Apply styling to entire canvas
-        if !sgr.is_empty() {
-            let prefix = format!("\x1b[{}m", sgr.join(";"));
-            let suffix = "\x1b[0m";
-
-            let style_whole_line = self.get_background().is_some()
-                || self.get_color_whitespace()
-                || (self.get_underline() && self.get_underline_spaces())
-                || (self.get_strikethrough() && self.get_strikethrough_spaces());
-
-            if style_whole_line {
-                // For background colors or styled spaces, style the entire canvas
-                final_lines = final_lines
-                    .into_iter()
-                    .map(|line| format!("{}{}{}", prefix, line, suffix))
-                    .collect();
-            } else {
-                // For foreground-only styling, style only non-whitespace parts
-                final_lines = final_lines
-                    .into_iter()
-                    .map(|line| {
-                        let leading_spaces = line.chars().take_while(|&c| c == ' ').count();
-                        let trailing_spaces = line.chars().rev().take_while(|&c| c == ' ').count();
-                        let content_start = leading_spaces;
-                        let content_end = line.len().saturating_sub(trailing_spaces);
-
-                        if content_start >= content_end {
-                            line
-                        } else {
-                            let lead = &line[..content_start];
-                            let mid = &line[content_start..content_end];
-                            let trail = &line[content_end..];
-                            format!("{}{}{}{}{}", lead, prefix, mid, suffix, trail)
+            let mut last = String::new();
+            let mut step = base;
+            for piece in pieces {
+                match piece {
+                    GradientPiece::Escape(seq) => {
+                        out.push_str(seq);
+                        // The content may have changed colors; repeat ours next
+                        last.clear();
+                    }
+                    GradientPiece::Cluster(cluster) => {
+                        let params: Vec<String> = [
+                            fg.get(step).and_then(|c| gradient_sgr(c, profile, false)),
+                            bg.get(step).and_then(|c| gradient_sgr(c, profile, true)),
+                        ]
+                        .into_iter()
+                        .flatten()
+                        .collect();
+                        let seq = format!("\x1b[{}m", params.join(";"));
+                        if !params.is_empty() && seq != last {
+                            out.push_str(&seq);
+                            last = seq;
                         }
-                    })
-                    .collect();
+                        out.push_str(cluster);
+                        step += 1;
+                    }
+                }
             }
+            if count > 0 {
+                out.push_str("\x1b[0m");
+            }
+            offset += count;
         }
+        out
+    }
 
-        let mut result = final_lines.join("\n");
-
-        // Apply all margins as final step (matches Go implementation)
-        result = self.apply_margins(&result);
-
-        result
+    /// Returns the foreground token adjusted for the minimum contrast ratio, if the
+    /// contrast guard is on and the foreground needed adjusting.
+    fn contrast_guarded_fg(&self, eff: &Renderer, profile: ColorProfileKind) -> Option<String> {
+        if !self.is_set(MIN_CONTRAST_KEY) || self.min_contrast <= 1.0 {
+            return None;
+        }
+        let fg = self.fg_color.as_ref()?.token(eff);
+        let backdrop = if eff.has_dark_background() {
+            "#000000"
+        } else {
+            "#ffffff"
+        };
+        let bg = match &self.bg_color {
+            Some(bg) if self.is_set(BACKGROUND_KEY) => bg.token(eff),
+            _ => backdrop.to_string(),
+        };
+        let faint = self.get_attr(ATTR_FAINT) && self.is_set(FAINT_KEY);
+        let adjusted = ensure_contrast(&fg, &bg, faint, self.min_contrast, profile);
+        (adjusted != fg).then_some(adjusted)
     }
 
-    /// Apply margins to a fully-rendered block, using margin background color if set.
-    /// This matches the Go implementation's applyMargins function.
-    fn apply_margins(&self, block: &str) -> String {
+    /// Works out the margin pieces for a block `block_width` columns wide, using
+    /// the margin background color if set. This matches the Go implementation's
+    /// applyMargins function.
+    fn margins(&self, block_width: usize) -> Margins {
         let top_margin = if self.is_set(MARGIN_TOP_KEY) {
             self.get_margin_top().max(0) as usize
         } else {
@@ -752,104 +1103,49 @@ impl Style {
         };
 
         if top_margin == 0 && right_margin == 0 && bottom_margin == 0 && left_margin == 0 {
-            return block.to_string();
+            return Margins::default();
         }
 
         // Determine margin background color
         // In Go: if marginBgColor is not set, margin is transparent (no background)
-        // Only inherit from main background if explicitly requested via margin_background
-        let margin_bg_color = if self.is_set(MARGIN_BACKGROUND_KEY) {
-            self.get_margin_background()
+        // Only painted when explicitly requested via margin_background
+        let margin_style = if self.is_set(MARGIN_BACKGROUND_KEY) {
+            self.margin_bg_color.clone().map(|bg| {
+                // Margin colors resolve against this style's renderer.
+                let eff = self.r.clone().unwrap_or_else(|| default_renderer().clone());
+                Style::new().renderer(eff).background(bg)
+            })
         } else {
             // Margins are transparent by default to match Go behavior
             None
         };
-
         // Pre-render margin strings once to avoid repeated render calls
-        let (left_margin_str, right_margin_str) = if margin_bg_color.is_some() {
-            let mut margin_style = Style::new();
-            if let Some(ref bg) = margin_bg_color {
-                margin_style = margin_style.background(bg.clone());
+        let fill = |n: usize| -> String {
+            if n == 0 {
+                return String::new();
             }
-            let left = if left_margin > 0 {
-                margin_style.render(&safe_repeat(' ', left_margin.min(1000)))
-            } else {
-                String::new()
-            };
-            let right = if right_margin > 0 {
-                margin_style.render(&safe_repeat(' ', right_margin.min(1000)))
-            } else {
-                String::new()
-            };
-            (left, right)
-        } else {
-            // No background color, just use plain spaces
-            let left = if left_margin > 0 {
-                safe_repeat(' ', left_margin.min(1000))
-            } else {
-                String::new()
-            };
-            let right = if right_margin > 0 {
-                safe_repeat(' ', right_margin.min(1000))
-            } else {
-                String::new()
-            };
-            (left, right)
-        };
-
-        // Apply left and right margins to each line
-        let lines: Vec<String> = block
-            .split('\n')
-            .map(|line| format!("{}{}{}", left_margin_str, line, right_margin_str))
-            .collect();
-
-        let mut result = Vec::new();
-
-        // Apply top margin
-        if top_margin > 0 {
-            let block_width = lines.iter().map(|l| width_visible(l)).max().unwrap_or(0);
-            let empty_line = if block_width > 0 && margin_bg_color.is_some() {
-                let mut margin_style = Style::new();
-                if let Some(ref bg) = margin_bg_color {
-                    margin_style = margin_style.background(bg.clone());
-                }
-                margin_style.render(&safe_repeat(' ', block_width.min(1000)))
-            } else if block_width > 0 {
-                safe_repeat(' ', block_width.min(1000))
-            } else {
-                String::new()
-            };
-            // Cap margin to prevent excessive allocations
-            let safe_top_margin = top_margin.min(1000);
-            for _ in 0..safe_top_margin {
-                result.push(empty_line.clone());
+            let spaces = safe_repeat(' ', n.min(1000));
+            match &margin_style {
+                Some(style) => style.render(&spaces),
+                None => spaces,
             }
-        }
-
-        result.extend(lines);
+        };
 
-        // Apply bottom margin
-        if bottom_margin > 0 {
-            let block_width = result.iter().map(|l| width_visible(l)).max().unwrap_or(0);
-            let empty_line = if block_width > 0 && margin_bg_color.is_some() {
-                let mut margin_style = Style::new();
-                if let Some(ref bg) = margin_bg_color {
-                    margin_style = margin_style.background(bg.clone());
-                }
-                margin_style.render(&safe_repeat(' ', block_width.min(1000)))
-            } else if block_width > 0 {
-                safe_repeat(' ', block_width.min(1000))
-            } else {
-                String::new()
-            };
-            // Cap margin to prevent excessive allocations
-            let safe_bottom_margin = bottom_margin.min(1000);
-            for _ in 0..safe_bottom_margin {
-                result.push(empty_line.clone());
-            }
+        let left = fill(left_margin);
+        let right = fill(right_margin);
+        let blank = if top_margin > 0 || bottom_margin > 0 {
+            fill(left_margin + block_width + right_margin)
+        } else {
+            String::new()
+        };
+        Margins {
+            // Cap margins to prevent excessive output
+            top: top_margin.min(1000),
+            bottom: bottom_margin.min(1000),
+            left,
+            right,
+            blank,
         }
-
-        result.join("\n")
     }
 
     /// Applies this style to a string as a convenience wrapper around `render()`.
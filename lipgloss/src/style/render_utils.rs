@@ -26,15 +26,39 @@
 //! wide characters (like CJK characters) and zero-width characters (like
 //! combining marks and emoji modifiers).
 
-use crate::security::{safe_repeat, MAX_ANSI_SEQ_LEN};
+use crate::security::MAX_ANSI_SEQ_LEN;
 use crate::style::{properties::*, Style};
+use crate::utils::SgrState;
+
+/// Reports whether `text` leaves SGR attributes in effect at its end.
+fn leaves_sgr_open(text: &str) -> bool {
+    let mut state = SgrState::new();
+    state.track(text);
+    state.is_open()
+}
+
+/// Appends a truncation `indicator` to `text` outside any styling the cut
+/// left open, and closes whatever the indicator leaves open in turn.
+fn push_indicator(text: &mut String, indicator: &str) {
+    if indicator.is_empty() {
+        return;
+    }
+    if leaves_sgr_open(text) {
+        text.push_str("\x1b[0m");
+    }
+    text.push_str(indicator);
+    if leaves_sgr_open(indicator) {
+        text.push_str("\x1b[0m");
+    }
+}
 
 #[allow(dead_code)]
 impl Style {
     /// Convert tabs to spaces if tab width is set
     ///
-    /// This method converts all tab characters (`\t`) in the input string to spaces
-    /// based on the style's configured tab width. If no tab width is set or the
+    /// This method expands all tab characters (`\t`) in the input string to the
+    /// next of the style's tab stops, as [`expand_tabs`](crate::utils::expand_tabs)
+    /// does. If no tab width is set or the
     /// tab width is zero or negative, the string is returned unchanged.
     ///
     /// # Arguments
@@ -53,7 +77,7 @@ impl Style {
     ///
     /// let style = Style::new().tab_width(4);
     /// let result = style.maybe_convert_tabs("hello\tworld");
-    /// assert_eq!(result, "hello    world"); // Tab replaced with 4 spaces
+    /// assert_eq!(result, "hello   world"); // Tab advanced to column 8
     ///
     /// // Without tab width set, tabs are preserved
     /// let style = Style::new();
@@ -65,8 +89,7 @@ impl Style {
             return s.to_string();
         }
 
-        let tab_spaces = safe_repeat(' ', self.tab_width as usize);
-        s.replace('\t', &tab_spaces)
+        crate::utils::expand_tabs(s, self.tab_width)
     }
 
     /// Truncate text to visible width while preserving ANSI escape sequences
@@ -150,11 +173,34 @@ impl Style {
         result
     }
 
+    /// Truncate a line to visible width, ending it with `indicator` when cut
+    ///
+    /// Lines that fit are returned unchanged. Otherwise the line is cut short
+    /// enough for the indicator to follow within `maxw`; an indicator wider
+    /// than `maxw` is itself truncated. Styling the cut leaves open is reset
+    /// before the indicator. With an empty indicator this is
+    /// [`truncate_visible_line`].
+    ///
+    /// [`truncate_visible_line`]: Style::truncate_visible_line
+    pub(crate) fn truncate_line_with_indicator(s: &str, maxw: usize, indicator: &str) -> String {
+        if indicator.is_empty() || crate::width_visible(s) <= maxw {
+            return Self::truncate_visible_line(s, maxw);
+        }
+        let indicator_width = crate::width_visible(indicator);
+        if indicator_width >= maxw {
+            return Self::truncate_visible_line(indicator, maxw);
+        }
+        let mut line = Self::truncate_visible_line(s, maxw - indicator_width);
+        push_indicator(&mut line, indicator);
+        line
+    }
+
     /// Truncate text to maximum height
     ///
     /// Truncates multi-line text to fit within the style's configured maximum height.
     /// If the input text has fewer lines than the maximum height, it is returned unchanged.
-    /// Otherwise, only the first `max_height` lines are kept.
+    /// Otherwise, only the first `max_height` lines are kept, the last one
+    /// ending with the truncation indicator if one is set.
     ///
     /// # Arguments
     ///
@@ -186,7 +232,11 @@ impl Style {
             return s.to_string();
         }
 
-        lines[0..self.max_height as usize].join("\n")
+        let mut kept = lines[0..self.max_height as usize].join("\n");
+        if self.max_height > 0 {
+            push_indicator(&mut kept, &self.get_truncation_indicator());
+        }
+        kept
     }
 
     /// Truncate each line to maximum width while preserving ANSI sequences
@@ -203,7 +253,8 @@ impl Style {
     /// # Returns
     ///
     /// A string where each line has been truncated to fit within `max_width` visible
-    /// characters, with all ANSI sequences preserved
+    /// characters, with all ANSI sequences preserved and the truncation
+    /// indicator ending each line that was cut
     ///
     /// # Examples
     ///
@@ -224,10 +275,13 @@ impl Style {
     ///
     /// [`truncate_visible_line`]: Style::truncate_visible_line
     pub fn truncate_width(&self, s: &str) -> String {
+        let indicator = self.get_truncation_indicator();
         let lines: Vec<&str> = s.split('\n').collect();
         let truncated: Vec<String> = lines
             .iter()
-            .map(|line| Self::truncate_visible_line(line, self.max_width as usize))
+            .map(|line| {
+                Self::truncate_line_with_indicator(line, self.max_width as usize, &indicator)
+            })
             .collect();
         truncated.join("\n")
     }
@@ -238,7 +292,12 @@ impl Style {
         }
 
         let mut lines = Vec::new();
-        let tokens = Self::tokenize_with_breakpoints(text, &[' ']);
+        // Ideographs may break anywhere, so split them out of space-separated words.
+        let tokens: Vec<String> = Self::tokenize_with_breakpoints(text, &[' '])
+            .iter()
+            .flat_map(|token| crate::utils::split_breakable(token))
+            .map(str::to_string)
+            .collect();
         if tokens.is_empty() {
             // If there are no tokens, return the original text as a single line
             // This handles empty or whitespace-only strings.
@@ -10,6 +10,8 @@
 //! - **Property Inheritance**: Only explicitly set properties are inherited
 //! - **Non-Overwriting**: Existing properties on the target style are preserved
 //! - **Selective Copying**: Margins, padding, and content strings are not inherited
+//! - **Property Classes**: `colors_only`, `attributes_only` and `box_model_only`
+//!   keep a single class of properties for composing with `inherit`
 //! - **Go Compatibility**: Behavior matches the original Go implementation
 //!
 //! # Examples
@@ -50,14 +52,14 @@ impl Style {
     ///    be overwritten by the source style's value.
     /// 3. **Excluded Properties**: Margins, padding, and underlying string values are
     ///    never inherited to maintain layout independence.
-    /// 4. **Special Background Handling**: Background colors may set margin background
-    ///    colors under specific conditions.
+    /// 4. **Margins Stay Transparent**: Inheriting a background never colors the
+    ///    margins; only an explicit margin background is inherited.
     ///
     /// # Properties That Are Inherited
     ///
     /// - **Text Attributes**: bold, italic, underline, strikethrough, reverse, blink, faint
     /// - **Text Behavior**: underline_spaces, strikethrough_spaces, color_whitespace
-    /// - **Colors**: foreground, background (with special margin background logic)
+    /// - **Colors**: foreground, background, margin background
     /// - **Dimensions**: width, height, max_width, max_height
     /// - **Alignment**: horizontal and vertical alignment
     /// - **Borders**: style, edge visibility, foreground/background colors
@@ -149,6 +151,8 @@ impl Style {
             BOLD_KEY,
             ITALIC_KEY,
             UNDERLINE_KEY,
+            UNDERLINE_STYLE_KEY,
+            UNDERLINE_COLOR_KEY,
             STRIKETHROUGH_KEY,
             REVERSE_KEY,
             BLINK_KEY,
@@ -158,6 +162,7 @@ impl Style {
             COLOR_WHITESPACE_KEY,
             FOREGROUND_KEY,
             BACKGROUND_KEY,
+            MARGIN_BACKGROUND_KEY,
             WIDTH_KEY,
             HEIGHT_KEY,
             ALIGN_HORIZONTAL_KEY,
@@ -178,8 +183,13 @@ impl Style {
             INLINE_KEY,
             MAX_WIDTH_KEY,
             MAX_HEIGHT_KEY,
+            TRUNCATION_INDICATOR_KEY,
             TAB_WIDTH_KEY,
             TRANSFORM_KEY,
+            MIN_CONTRAST_KEY,
+            FOREGROUND_GRADIENT_KEY,
+            BACKGROUND_GRADIENT_KEY,
+            GRADIENT_SPANS_BLOCK_KEY,
             // Skip padding and margin keys as they are not inherited per Go implementation
         ];
 
@@ -203,17 +213,6 @@ impl Style {
                 continue;
             }
 
-            // Special handling for background color - inherit margin background if we don't have it
-            if key == BACKGROUND_KEY
-                && !self.is_set(MARGIN_BACKGROUND_KEY)
-                && !other.is_set(MARGIN_BACKGROUND_KEY)
-            {
-                if let Some(ref bg) = other.bg_color {
-                    self.margin_bg_color = Some(bg.clone());
-                    self.set_prop(MARGIN_BACKGROUND_KEY);
-                }
-            }
-
             if self.is_set(key) {
                 continue; // We already have this property set, don't override
             }
@@ -232,6 +231,14 @@ impl Style {
                     self.set_attr(ATTR_UNDERLINE, other.get_attr(ATTR_UNDERLINE));
                     self.set_prop(key);
                 }
+                UNDERLINE_STYLE_KEY => {
+                    self.underline_style = other.underline_style;
+                    self.set_prop(key);
+                }
+                UNDERLINE_COLOR_KEY => {
+                    self.underline_color = other.underline_color.clone();
+                    self.set_prop(key);
+                }
                 STRIKETHROUGH_KEY => {
                     self.set_attr(ATTR_STRIKETHROUGH, other.get_attr(ATTR_STRIKETHROUGH));
                     self.set_prop(key);
@@ -271,6 +278,10 @@ impl Style {
                     self.bg_color = other.bg_color.clone();
                     self.set_prop(key);
                 }
+                MARGIN_BACKGROUND_KEY => {
+                    self.margin_bg_color = other.margin_bg_color.clone();
+                    self.set_prop(key);
+                }
                 WIDTH_KEY => {
                     self.width = other.width;
                     self.set_prop(key);
@@ -351,6 +362,10 @@ impl Style {
                     self.max_height = other.max_height;
                     self.set_prop(key);
                 }
+                TRUNCATION_INDICATOR_KEY => {
+                    self.truncation_indicator = other.truncation_indicator.clone();
+                    self.set_prop(key);
+                }
                 TAB_WIDTH_KEY => {
                     self.tab_width = other.tab_width;
                     self.set_prop(key);
@@ -359,10 +374,36 @@ impl Style {
                     self.transform = other.transform.clone();
                     self.set_prop(key);
                 }
+                MIN_CONTRAST_KEY => {
+                    self.min_contrast = other.min_contrast;
+                    self.set_prop(key);
+                }
+                FOREGROUND_GRADIENT_KEY => {
+                    self.fg_gradient = other.fg_gradient.clone();
+                    self.set_prop(key);
+                }
+                BACKGROUND_GRADIENT_KEY => {
+                    self.bg_gradient = other.bg_gradient.clone();
+                    self.set_prop(key);
+                }
+                GRADIENT_SPANS_BLOCK_KEY => {
+                    self.set_attr(
+                        ATTR_GRADIENT_SPANS_BLOCK,
+                        other.get_attr(ATTR_GRADIENT_SPANS_BLOCK),
+                    );
+                    self.set_prop(key);
+                }
                 _ => {} // Unknown key, skip
             }
         }
 
+        // Border piece overrides are inherited one piece at a time
+        for (mine, theirs) in self.border_chars.iter_mut().zip(&other.border_chars) {
+            if mine.is_none() {
+                mine.clone_from(theirs);
+            }
+        }
+
         self
     }
 
@@ -426,4 +467,268 @@ impl Style {
     pub fn copy(&self) -> Self {
         self.clone()
     }
+
+    /// Returns a new style with only this style's color properties set.
+    ///
+    /// Foreground, background, margin background, border and underline colors,
+    /// gradients and the minimum contrast ratio are kept; everything else is
+    /// left unset. The
+    /// renderer is kept as well.
+    ///
+    /// Together with [`Style::attributes_only`] and [`Style::box_model_only`]
+    /// this makes it easy to take one class of properties from a style and
+    /// combine it with another via [`Style::inherit`]. Since `inherit` does not
+    /// copy padding or margins, start from the box model and inherit the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let theme = Style::new().foreground("#ff0000").bold(true).padding(1, 1, 1, 1);
+    /// let card = Style::new().padding(0, 2, 0, 2).width(20).italic(true);
+    ///
+    /// // The colors of `theme` with the box model of `card`.
+    /// let combined = card.box_model_only().inherit(theme.colors_only());
+    /// assert_eq!(combined.get_padding_left(), 2);
+    /// assert!(!combined.get_bold());
+    /// assert!(!combined.get_italic());
+    /// ```
+    pub fn colors_only(&self) -> Style {
+        Style {
+            r: self.r.clone(),
+            props: self.props & COLOR_KEYS,
+            fg_color: self.fg_color.clone(),
+            bg_color: self.bg_color.clone(),
+            margin_bg_color: self.margin_bg_color.clone(),
+            border_top_fg_color: self.border_top_fg_color.clone(),
+            border_right_fg_color: self.border_right_fg_color.clone(),
+            border_bottom_fg_color: self.border_bottom_fg_color.clone(),
+            border_left_fg_color: self.border_left_fg_color.clone(),
+            border_top_bg_color: self.border_top_bg_color.clone(),
+            border_right_bg_color: self.border_right_bg_color.clone(),
+            border_bottom_bg_color: self.border_bottom_bg_color.clone(),
+            border_left_bg_color: self.border_left_bg_color.clone(),
+            min_contrast: self.min_contrast,
+            fg_gradient: self.fg_gradient.clone(),
+            bg_gradient: self.bg_gradient.clone(),
+            underline_color: self.underline_color.clone(),
+            attrs: self.attrs & ATTR_GRADIENT_SPANS_BLOCK,
+            ..Style::default()
+        }
+    }
+
+    /// Returns a new style with only this style's text attributes set.
+    ///
+    /// Bold, italic, underline, strikethrough, reverse, blink and faint are
+    /// kept, along with the underline style, the underline/strikethrough
+    /// spaces and color whitespace flags. See [`Style::colors_only`] for composing the result.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().bold(true).foreground("#ff0000").width(10);
+    /// let attrs = style.attributes_only();
+    /// assert!(attrs.get_bold());
+    /// assert_eq!(attrs.get_width(), 0);
+    /// ```
+    pub fn attributes_only(&self) -> Style {
+        let attr_mask = ATTR_BOLD
+            | ATTR_ITALIC
+            | ATTR_UNDERLINE
+            | ATTR_STRIKETHROUGH
+            | ATTR_REVERSE
+            | ATTR_BLINK
+            | ATTR_FAINT
+            | ATTR_UNDERLINE_SPACES
+            | ATTR_STRIKETHROUGH_SPACES
+            | ATTR_COLOR_WHITESPACE;
+        Style {
+            r: self.r.clone(),
+            props: self.props & ATTRIBUTE_KEYS,
+            attrs: self.attrs & attr_mask,
+            underline_style: self.underline_style,
+            ..Style::default()
+        }
+    }
+
+    /// Returns a new style with only this style's box model set.
+    ///
+    /// Width, height, their maximums and truncation indicator, alignment, padding, margins, the border
+    /// style and which border edges are drawn are kept. Border colors belong
+    /// to [`Style::colors_only`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{rounded_border, Style};
+    ///
+    /// let style = Style::new()
+    ///     .border(rounded_border())
+    ///     .border_foreground("#00ff00")
+    ///     .padding(0, 1, 0, 1)
+    ///     .underline(true);
+    /// let boxed = style.box_model_only();
+    /// assert!(boxed.get_border_top());
+    /// assert_eq!(boxed.get_padding_right(), 1);
+    /// assert!(!boxed.get_underline());
+    /// ```
+    pub fn box_model_only(&self) -> Style {
+        let attr_mask = ATTR_BORDER_TOP | ATTR_BORDER_RIGHT | ATTR_BORDER_BOTTOM | ATTR_BORDER_LEFT;
+        Style {
+            r: self.r.clone(),
+            props: self.props & BOX_MODEL_KEYS,
+            attrs: self.attrs & attr_mask,
+            width: self.width,
+            height: self.height,
+            max_width: self.max_width,
+            max_height: self.max_height,
+            truncation_indicator: self.truncation_indicator.clone(),
+            align_horizontal: self.align_horizontal,
+            align_vertical: self.align_vertical,
+            padding_top: self.padding_top,
+            padding_right: self.padding_right,
+            padding_bottom: self.padding_bottom,
+            padding_left: self.padding_left,
+            margin_top: self.margin_top,
+            margin_right: self.margin_right,
+            margin_bottom: self.margin_bottom,
+            margin_left: self.margin_left,
+            border_style: self.border_style,
+            border_chars: self.border_chars.clone(),
+            ..Style::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::border::rounded_border;
+    use crate::color::Color;
+
+    /// A style with every property in every class set.
+    fn everything() -> Style {
+        Style::new()
+            .bold(true)
+            .italic(true)
+            .underline(true)
+            .underline_style(crate::style::UnderlineStyle::Curly)
+            .underline_color("#ff00ff")
+            .strikethrough(true)
+            .reverse(true)
+            .blink(true)
+            .faint(true)
+            .underline_spaces(true)
+            .strikethrough_spaces(true)
+            .color_whitespace(true)
+            .foreground("#ff0000")
+            .background("#0000ff")
+            .margin_background("#00ff00")
+            .border_foreground("#ffff00")
+            .border_background("#00ffff")
+            .min_contrast(4.5)
+            .foreground_gradient(&[Color::from("#ff0000"), Color::from("#0000ff")])
+            .background_gradient(&[Color::from("#000000")])
+            .gradient_spans_block(true)
+            .width(20)
+            .height(3)
+            .max_width(30)
+            .max_height(5)
+            .truncation_indicator("…")
+            .align_horizontal(crate::position::CENTER)
+            .align_vertical(crate::position::BOTTOM)
+            .padding(1, 2, 1, 2)
+            .margin(1, 1, 1, 1)
+            .border(rounded_border())
+            .inline(true)
+            .tab_width(2)
+            .transform(|s| s.to_uppercase())
+    }
+
+    #[test]
+    fn test_only_projections_keep_exactly_their_class() {
+        let style = everything();
+        assert_eq!(style.colors_only().props, COLOR_KEYS);
+        assert_eq!(style.attributes_only().props, ATTRIBUTE_KEYS);
+        assert_eq!(style.box_model_only().props, BOX_MODEL_KEYS);
+
+        // The classes are disjoint and leave only misc properties uncovered.
+        assert_eq!(COLOR_KEYS & ATTRIBUTE_KEYS, 0);
+        assert_eq!(COLOR_KEYS & BOX_MODEL_KEYS, 0);
+        assert_eq!(ATTRIBUTE_KEYS & BOX_MODEL_KEYS, 0);
+        assert_eq!(
+            style.props & !(COLOR_KEYS | ATTRIBUTE_KEYS | BOX_MODEL_KEYS),
+            INLINE_KEY | TAB_WIDTH_KEY | TRANSFORM_KEY
+        );
+    }
+
+    #[test]
+    fn test_only_projections_of_empty_style_are_empty() {
+        let style = Style::new();
+        assert_eq!(style.colors_only().props, 0);
+        assert_eq!(style.attributes_only().props, 0);
+        assert_eq!(style.box_model_only().props, 0);
+    }
+
+    #[test]
+    fn test_only_projections_reset_other_values() {
+        let style = everything();
+
+        let colors = style.colors_only();
+        assert!(!colors.get_bold());
+        assert_eq!(colors.get_width(), 0);
+        assert_eq!(colors.get_padding_left(), 0);
+        assert!(colors.transform.is_none());
+        assert!(colors.fg_color.is_some() && colors.border_top_bg_color.is_some());
+
+        let attrs = style.attributes_only();
+        assert!(attrs.get_faint() && attrs.get_color_whitespace());
+        assert!(!attrs.get_inline());
+        assert!(attrs.fg_color.is_none());
+        assert_eq!(attrs.border_style, crate::border::hidden_border());
+
+        let boxed = style.box_model_only();
+        assert_eq!(boxed.get_max_width(), 30);
+        assert_eq!(boxed.get_truncation_indicator(), "…");
+        assert_eq!(boxed.get_margin_top(), 1);
+        assert!(boxed.get_border_left());
+        assert!(boxed.border_left_fg_color.is_none());
+        assert!(!boxed.get_underline());
+        assert_eq!(boxed.get_tab_width(), TAB_WIDTH_DEFAULT);
+    }
+
+    #[test]
+    fn test_only_projections_compose_via_inherit() {
+        let mut renderer = crate::renderer::Renderer::new();
+        renderer.set_color_profile(crate::renderer::ColorProfileKind::TrueColor);
+
+        let theme = Style::new()
+            .renderer(renderer.clone())
+            .foreground("#ff0000")
+            .bold(true)
+            .padding(2, 2, 2, 2);
+        let card = Style::new()
+            .renderer(renderer.clone())
+            .width(8)
+            .padding(0, 1, 0, 1)
+            .border(rounded_border())
+            .italic(true);
+
+        let combined = card
+            .box_model_only()
+            .inherit(theme.colors_only())
+            .inherit(theme.attributes_only());
+        let expected = Style::new()
+            .renderer(renderer)
+            .width(8)
+            .padding(0, 1, 0, 1)
+            .border(rounded_border())
+            .foreground("#ff0000")
+            .bold(true);
+        assert_eq!(combined.render("hi"), expected.render("hi"));
+        assert!(combined.render("hi").contains("\x1b["));
+    }
 }
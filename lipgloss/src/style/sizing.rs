@@ -450,4 +450,33 @@ impl Style {
         self.set_prop(MAX_HEIGHT_KEY);
         self
     }
+
+    /// Set the indicator shown where content is cut off by the maximum size.
+    ///
+    /// When [`max_width`](Style::max_width) trims a line, the indicator is
+    /// appended within the width budget, so the line still fits. When
+    /// [`max_height`](Style::max_height) drops lines, the indicator is appended
+    /// to the last line kept. Widths are measured ignoring ANSI sequences, and
+    /// a wide character that would straddle the cut is dropped whole. Styling
+    /// the cut leaves open is reset before the indicator, so the indicator is
+    /// drawn as given.
+    ///
+    /// The default is empty, which clips without any indication.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().max_width(8).truncation_indicator("…");
+    /// assert_eq!(style.render("Hello, World"), "Hello, …");
+    ///
+    /// let style = Style::new().max_height(2).truncation_indicator("…");
+    /// assert_eq!(style.render("one\ntwo\nthree"), "one\ntwo…");
+    /// ```
+    pub fn truncation_indicator(mut self, indicator: &str) -> Self {
+        self.truncation_indicator = indicator.to_string();
+        self.set_prop(TRUNCATION_INDICATOR_KEY);
+        self
+    }
 }
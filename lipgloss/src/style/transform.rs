@@ -28,13 +28,16 @@ use std::sync::Arc;
 impl Style {
     /// Sets the tab width for this style.
     ///
-    /// This determines how many spaces a tab character (`\t`) should be expanded to
-    /// when the text is rendered. This is particularly useful for maintaining consistent
-    /// indentation and alignment in terminal output.
+    /// Tab characters (`\t`) are expanded with spaces to the next tab stop, a
+    /// multiple of `n` columns from the start of their line, when the text is
+    /// rendered. Columns count display cells, so wide characters and styled
+    /// content line up like plain text. This is particularly useful for
+    /// maintaining consistent indentation and alignment in terminal output.
     ///
     /// # Arguments
     ///
-    /// * `n` - The number of spaces to use for each tab character. Must be non-negative.
+    /// * `n` - The distance between tab stops. `0` removes tabs and
+    ///   [`NO_TAB_CONVERSION`](crate::NO_TAB_CONVERSION) leaves them in place.
     ///
     /// # Returns
     ///
@@ -45,13 +48,14 @@ impl Style {
     /// ```rust
     /// use lipgloss::Style;
     ///
-    /// // Set tab width to 4 spaces (common programming convention)
+    /// // Tab stops every 4 columns (common programming convention)
     /// let style = Style::new().tab_width(4);
     ///
     /// // Render text with tabs
-    /// let text = "Line 1\n\tIndented line";
+    /// let text = "Line 1\n\tIndented line\nab\tc";
     /// let rendered = style.render(text);
-    /// // The tab will be rendered as 4 spaces
+    /// assert_eq!(rendered.lines().nth(1), Some("    Indented line"));
+    /// assert!(rendered.lines().nth(2).unwrap().starts_with("ab  c"));
     /// ```
     ///
     /// # Note
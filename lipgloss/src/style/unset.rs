@@ -63,9 +63,9 @@
 //!     .unset_padding();       // And have no padding
 //! ```
 
-use crate::border::hidden_border;
+use crate::border::{hidden_border, BorderPiece};
 use crate::position::{LEFT, TOP};
-use crate::style::{properties::*, Style};
+use crate::style::{properties::*, Style, UnderlineStyle};
 
 impl Style {
     // ---------- Text attribute unset methods ----------
@@ -154,6 +154,19 @@ impl Style {
         self
     }
 
+    /// Removes the underline shape from this style.
+    ///
+    /// The underline, if still on, is drawn as a single straight line again.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    pub fn unset_underline_style(mut self) -> Self {
+        self.unset_prop(UNDERLINE_STYLE_KEY);
+        self.underline_style = UnderlineStyle::Single;
+        self
+    }
+
     /// Removes the strikethrough text attribute from this style.
     ///
     /// This resets the strikethrough setting to its default (false) and removes it from the
@@ -353,6 +366,19 @@ impl Style {
         self
     }
 
+    /// Removes the underline color from this style.
+    ///
+    /// The underline, if on, takes the foreground color again.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    pub fn unset_underline_color(mut self) -> Self {
+        self.unset_prop(UNDERLINE_COLOR_KEY);
+        self.underline_color = None;
+        self
+    }
+
     /// Removes the background color from this style.
     ///
     /// This resets the background color to `None` and removes it from the style's property set.
@@ -439,6 +465,19 @@ impl Style {
         self
     }
 
+    /// Removes the truncation indicator from this style.
+    ///
+    /// Content cut off by the maximum width or height is clipped silently again.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    pub fn unset_truncation_indicator(mut self) -> Self {
+        self.unset_prop(TRUNCATION_INDICATOR_KEY);
+        self.truncation_indicator.clear();
+        self
+    }
+
     // ---------- Alignment unset methods ----------
 
     /// Removes both horizontal and vertical alignment settings from this style.
@@ -1180,6 +1219,45 @@ impl Style {
         self
     }
 
+    /// Removes the override for one border piece, so the border's own glyph
+    /// is drawn again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{normal_border, BorderPiece, Style};
+    ///
+    /// let style = Style::new()
+    ///     .border(normal_border())
+    ///     .border_char(BorderPiece::TopLeft, "+")
+    ///     .border_char(BorderPiece::TopRight, "+")
+    ///     .unset_border_char(BorderPiece::TopLeft);
+    /// assert_eq!(style.get_border_char(BorderPiece::TopLeft), None);
+    /// assert_eq!(style.get_border_char(BorderPiece::TopRight), Some("+"));
+    /// ```
+    pub fn unset_border_char(mut self, piece: BorderPiece) -> Self {
+        self.border_chars[piece.index()] = None;
+        self
+    }
+
+    /// Removes every border piece override.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{normal_border, BorderPiece, Style};
+    ///
+    /// let style = Style::new()
+    ///     .border(normal_border())
+    ///     .border_char(BorderPiece::Top, "=")
+    ///     .unset_border_chars();
+    /// assert_eq!(style.render("x"), Style::new().border(normal_border()).render("x"));
+    /// ```
+    pub fn unset_border_chars(mut self) -> Self {
+        self.border_chars = Default::default();
+        self
+    }
+
     // ---------- Other unset methods ----------
 
     /// Removes the custom tab width setting from this style.
@@ -1209,6 +1287,70 @@ impl Style {
         self
     }
 
+    /// Removes the minimum contrast ratio, turning the contrast guard off.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::Style;
+    ///
+    /// let style = Style::new().min_contrast(4.5).unset_min_contrast();
+    /// assert_eq!(style.get_min_contrast(), 0.0);
+    /// ```
+    pub fn unset_min_contrast(mut self) -> Self {
+        self.unset_prop(MIN_CONTRAST_KEY);
+        self.min_contrast = 0.0;
+        self
+    }
+
+    /// Removes the foreground gradient from this style.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use lipgloss::{Color, Style};
+    ///
+    /// let style = Style::new()
+    ///     .foreground_gradient(&[Color::from("#ff0000"), Color::from("#0000ff")])
+    ///     .unset_foreground_gradient();
+    /// assert!(style.get_foreground_gradient().is_empty());
+    /// ```
+    pub fn unset_foreground_gradient(mut self) -> Self {
+        self.unset_prop(FOREGROUND_GRADIENT_KEY);
+        self.fg_gradient.clear();
+        self
+    }
+
+    /// Removes the background gradient from this style.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    pub fn unset_background_gradient(mut self) -> Self {
+        self.unset_prop(BACKGROUND_GRADIENT_KEY);
+        self.bg_gradient.clear();
+        self
+    }
+
+    /// Removes the gradient span mode, so gradients restart on each line.
+    ///
+    /// # Returns
+    ///
+    /// Returns the modified `Style` instance for method chaining.
+    pub fn unset_gradient_spans_block(mut self) -> Self {
+        self.unset_prop(GRADIENT_SPANS_BLOCK_KEY);
+        self.set_attr(ATTR_GRADIENT_SPANS_BLOCK, false);
+        self
+    }
+
     /// Removes the text transformation function from this style.
     ///
     /// This resets the transform function to `None` and removes it from
@@ -82,8 +82,9 @@
 //! // Result contains "Hello" in bold and "World" in red
 //! ```
 
+use crate::security::{safe_repeat, MAX_ANSI_SEQ_LEN, MAX_OSC_SEQ_LEN};
 use strip_ansi_escapes as ansi;
-use unicode_width::UnicodeWidthStr;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Returns the display width of a string in terminal cells.
 ///
@@ -97,6 +98,9 @@ use unicode_width::UnicodeWidthStr;
 /// Note that this function does NOT strip ANSI escape sequences. For strings containing
 /// ANSI codes, use [`width_visible`] instead.
 ///
+/// Tabs advance to the next [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab
+/// stop, matching how a default [`Style`] renders them.
+///
 /// # Arguments
 ///
 /// * `s` - The string to measure
@@ -122,9 +126,71 @@ use unicode_width::UnicodeWidthStr;
 /// assert_eq!(width("Hello世界"), 9);  // 5 + 4 = 9 cells
 /// ```
 pub fn width(s: &str) -> usize {
+    if s.contains('\t') {
+        return UnicodeWidthStr::width(expand_tabs(s, crate::DEFAULT_TAB_WIDTH).as_str());
+    }
     UnicodeWidthStr::width(s)
 }
 
+/// Replaces tab characters the way [`Style::tab_width`] does.
+///
+/// A positive `tab_width` advances each tab with spaces to the next tab
+/// stop, a multiple of `tab_width` columns from the start of its line. `0`
+/// removes tabs, and a negative value (such as
+/// [`NO_TAB_CONVERSION`](crate::NO_TAB_CONVERSION)) leaves them in place.
+/// Columns are counted in display cells: escape sequences take none and wide
+/// characters take two, so styled text lines up like plain text. See
+/// [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) for how the rest of the
+/// crate applies this.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::expand_tabs;
+///
+/// assert_eq!(expand_tabs("a\tb", 4), "a   b");
+/// assert_eq!(expand_tabs("abcd\tb", 4), "abcd    b");
+/// assert_eq!(expand_tabs("中\tb", 4), "中  b");
+/// assert_eq!(expand_tabs("\x1b[1ma\x1b[0m\tb", 4), "\x1b[1ma\x1b[0m   b");
+/// assert_eq!(expand_tabs("a\tb", 0), "ab");
+/// assert_eq!(expand_tabs("a\tb", -1), "a\tb");
+/// ```
+pub fn expand_tabs(s: &str, tab_width: i32) -> String {
+    if tab_width < 0 || !s.contains('\t') {
+        return s.to_string();
+    }
+    let tab_width = tab_width as usize;
+    let mut out = String::with_capacity(s.len());
+    let mut column = 0usize;
+    let mut rest = s;
+    while let Some(ch) = rest.chars().next() {
+        if ch == '\x1b' {
+            let len = escape_len(rest);
+            out.push_str(&rest[..len]);
+            rest = &rest[len..];
+            continue;
+        }
+        match ch {
+            '\t' if tab_width > 0 => {
+                let spaces = tab_width - column % tab_width;
+                out.push_str(&safe_repeat(' ', spaces));
+                column += spaces;
+            }
+            '\t' => {}
+            '\n' => {
+                out.push(ch);
+                column = 0;
+            }
+            _ => {
+                out.push(ch);
+                column += UnicodeWidthChar::width(ch).unwrap_or(0);
+            }
+        }
+        rest = &rest[ch.len_utf8()..];
+    }
+    out
+}
+
 // Go-style aliases for API name parity
 
 /// Go-style alias for [`new_range`].
@@ -222,12 +288,315 @@ pub fn strip_ansi(s: &str) -> String {
     String::from_utf8_lossy(&bytes).into_owned()
 }
 
+/// Removes every escape sequence except SGR (colors and text attributes).
+///
+/// Cursor movement, erase and other CSI sequences, OSC sequences and
+/// two-character escapes are dropped, so text captured from program output
+/// can be placed inside a layout without moving the cursor. SGR sequences
+/// (`ESC [ ... m`) and all visible text are kept as-is.
+///
+/// # Arguments
+///
+/// * `s` - The string to sanitize (may contain ANSI escape sequences)
+///
+/// # Returns
+///
+/// A new `String` with only SGR escape sequences left
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::strip_non_sgr;
+///
+/// // Erase-line and cursor-up are removed, the red color is kept
+/// let s = "\x1b[2K\x1b[1A\x1b[31mRed\x1b[0m";
+/// assert_eq!(strip_non_sgr(s), "\x1b[31mRed\x1b[0m");
+/// ```
+pub fn strip_non_sgr(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('\x1b') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let len = escape_len(rest);
+        let seq = &rest[..len];
+        if seq.starts_with("\x1b[") && seq.ends_with('m') {
+            out.push_str(seq);
+        }
+        rest = &rest[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Reports whether `s` contains any escape sequence.
+///
+/// Useful for deciding whether a fragment was already styled by another
+/// component before embedding it.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::has_ansi;
+///
+/// assert!(has_ansi("\x1b[31mRed\x1b[0m"));
+/// assert!(!has_ansi("Plain text"));
+/// ```
+pub fn has_ansi(s: &str) -> bool {
+    s.contains('\x1b')
+}
+
+/// Re-applies `base_sgr` after every full SGR reset in `s`.
+///
+/// A pre-styled fragment usually ends its styling with `ESC[0m`, which also
+/// clears whatever the surrounding container set, such as a background. This
+/// inserts the container's own SGR sequence right after each reset so the
+/// container's styling resumes where the fragment's ends. `ESC[m`, `ESC[0m`
+/// and resets combined with other attributes (`ESC[0;31m`) are all treated
+/// as resets; in the combined form the base is placed between the reset and
+/// the fragment's own attributes so the latter still win.
+///
+/// # Arguments
+///
+/// * `s` - The styled fragment
+/// * `base_sgr` - The container's complete SGR sequence, e.g. `"\x1b[48;5;57m"`
+///
+/// # Returns
+///
+/// The fragment with the base restored after each reset; unchanged when
+/// `base_sgr` is empty or `s` holds no resets
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::reapply_after_resets;
+///
+/// let child = "\x1b[31mred\x1b[0m rest";
+/// assert_eq!(
+///     reapply_after_resets(child, "\x1b[44m"),
+///     "\x1b[31mred\x1b[0m\x1b[44m rest"
+/// );
+/// ```
+pub fn reapply_after_resets(s: &str, base_sgr: &str) -> String {
+    if base_sgr.is_empty() || !s.contains("\x1b[") {
+        return s.to_string();
+    }
+
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some(pos) = rest.find('\x1b') {
+        out.push_str(&rest[..pos]);
+        rest = &rest[pos..];
+        let len = escape_len(rest);
+        let seq = &rest[..len];
+        rest = &rest[len..];
+
+        let params = seq.strip_prefix("\x1b[").and_then(|q| q.strip_suffix('m'));
+        match params {
+            Some("" | "0") => {
+                out.push_str(seq);
+                out.push_str(base_sgr);
+            }
+            Some(params) if params.starts_with("0;") => {
+                out.push_str("\x1b[0m");
+                out.push_str(base_sgr);
+                out.push_str(&format!("\x1b[{}m", &params[2..]));
+            }
+            _ => out.push_str(seq),
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+/// The SGR attributes in effect at a point in styled text.
+///
+/// Feed it the text that came before with [`SgrState::track`]. It keeps every
+/// SGR sequence since the last reset, so the same styling can be reopened
+/// elsewhere, e.g. at the start of the next line once the current one has
+/// been closed with a reset. Other escape sequences are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::SgrState;
+///
+/// let mut state = SgrState::new();
+/// state.track("\x1b[1mbold \x1b[31mred");
+/// assert!(state.is_open());
+/// assert_eq!(state.reopen(), "\x1b[1m\x1b[31m");
+///
+/// state.track("\x1b[0m plain");
+/// assert!(!state.is_open());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SgrState<'a> {
+    /// SGR sequences since the last reset, in order
+    seqs: Vec<&'a str>,
+}
+
+impl<'a> SgrState<'a> {
+    /// Creates a state with no attributes in effect.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the state with a single escape sequence, such as one measured
+    /// with [`escape_len`]. A reset closes the state; any other SGR sequence
+    /// is added to it.
+    ///
+    /// # Returns
+    ///
+    /// Whether `seq` was an SGR sequence.
+    pub fn apply(&mut self, seq: &'a str) -> bool {
+        let Some(params) = seq.strip_prefix("\x1b[").and_then(|q| q.strip_suffix('m')) else {
+            return false;
+        };
+        if params.is_empty() || params == "0" {
+            self.seqs.clear();
+        } else {
+            if params.starts_with("0;") {
+                self.seqs.clear();
+            }
+            self.seqs.push(seq);
+        }
+        true
+    }
+
+    /// Updates the state with every escape sequence in `text`.
+    pub fn track(&mut self, text: &'a str) {
+        let mut rest = text;
+        while let Some(pos) = rest.find('\x1b') {
+            rest = &rest[pos..];
+            let len = escape_len(rest);
+            self.apply(&rest[..len]);
+            rest = &rest[len..];
+        }
+    }
+
+    /// Reports whether any SGR attributes are in effect.
+    pub fn is_open(&self) -> bool {
+        !self.seqs.is_empty()
+    }
+
+    /// Returns the sequences in effect, in the order they appeared.
+    pub fn sequences(&self) -> &[&'a str] {
+        &self.seqs
+    }
+
+    /// Returns the sequences in effect as one string, which reopens the
+    /// state when written out. Empty when nothing is in effect.
+    pub fn reopen(&self) -> String {
+        self.seqs.concat()
+    }
+}
+
+/// Writes `s` to `w` with `prefix` in front of every line.
+///
+/// The prefix stays outside any styling in `s`: when SGR attributes are
+/// still open at the end of a line, the line is closed with a reset and the
+/// attributes are reopened after the next line's prefix. The last line is
+/// written as it is, so output that ends without a reset keeps doing so.
+/// Empty input writes nothing.
+///
+/// # Arguments
+///
+/// * `w` - The writer to write to
+/// * `s` - The text, possibly styled and spanning several lines
+/// * `prefix` - The text to put in front of each line, e.g. `"// "`
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::write_prefixed;
+///
+/// let mut out = String::new();
+/// write_prefixed(&mut out, "\x1b[1mone\ntwo\x1b[0m", "// ").unwrap();
+/// assert_eq!(out, "// \x1b[1mone\x1b[0m\n// \x1b[1mtwo\x1b[0m");
+/// ```
+pub fn write_prefixed<W: std::fmt::Write>(w: &mut W, s: &str, prefix: &str) -> std::fmt::Result {
+    if s.is_empty() {
+        return Ok(());
+    }
+
+    let mut sgr_state = SgrState::new();
+    let mut lines = s.split('\n').peekable();
+    while let Some(line) = lines.next() {
+        w.write_str(prefix)?;
+        for seq in sgr_state.sequences() {
+            w.write_str(seq)?;
+        }
+        w.write_str(line)?;
+        sgr_state.track(line);
+
+        if lines.peek().is_some() {
+            if sgr_state.is_open() {
+                w.write_str("\x1b[0m")?;
+            }
+            w.write_char('\n')?;
+        }
+    }
+    Ok(())
+}
+
+/// Drops resets that directly follow another reset, so `"\x1b[0m\x1b[0m"`
+/// becomes a single `"\x1b[0m"`. Everything else is copied unchanged.
+pub(crate) fn collapse_resets(s: &str) -> String {
+    if !s.contains("\x1b[") {
+        return s.to_string();
+    }
+
+    let is_reset = |seq: &str| seq == "\x1b[0m" || seq == "\x1b[m";
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+    let mut after_reset = false;
+    while let Some(pos) = rest.find('\x1b') {
+        if pos > 0 {
+            out.push_str(&rest[..pos]);
+            after_reset = false;
+        }
+        rest = &rest[pos..];
+        let seq = &rest[..escape_len(rest)];
+        rest = &rest[seq.len()..];
+        if is_reset(seq) {
+            if after_reset {
+                continue;
+            }
+            after_reset = true;
+        } else {
+            after_reset = false;
+        }
+        out.push_str(seq);
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Returns `s` with `prefix` in front of every line, keeping the prefix
+/// outside any styling. See [`write_prefixed`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::prefix_lines;
+///
+/// assert_eq!(prefix_lines("a\nb", "    "), "    a\n    b");
+/// ```
+pub fn prefix_lines(s: &str, prefix: &str) -> String {
+    let mut out = String::with_capacity(s.len() + prefix.len() * height(s));
+    write_prefixed(&mut out, s, prefix).expect("writing to a String cannot fail");
+    out
+}
+
 /// Returns the visible display width of a string, ignoring ANSI escape sequences.
 ///
 /// This function first strips all ANSI escape sequences from the string, then calculates
 /// the display width of the remaining visible text. This is essential for layout
 /// calculations when working with styled terminal output.
 ///
+/// Tabs advance to the next [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab
+/// stop, matching how a default [`Style`] renders them.
+///
 /// # Arguments
 ///
 /// * `s` - The string to measure (may contain ANSI escape sequences)
@@ -262,7 +631,7 @@ pub fn strip_ansi(s: &str) -> String {
 /// - [`width`] - For measuring strings without ANSI codes
 /// - [`strip_ansi`] - For removing ANSI codes without measuring
 pub fn width_visible(s: &str) -> usize {
-    let clean = strip_ansi(s);
+    let clean = strip_ansi(&expand_tabs(s, crate::DEFAULT_TAB_WIDTH));
     UnicodeWidthStr::width(clean.as_str())
 }
 
@@ -270,7 +639,8 @@ pub fn width_visible(s: &str) -> usize {
 ///
 /// This function processes multi-line text by:
 /// 1. Splitting the input into lines at newline characters
-/// 2. Stripping ANSI escape sequences from each line
+/// 2. Expanding tabs to [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab stops
+///    and stripping ANSI escape sequences from each line
 /// 3. Calculating the maximum visible width across all lines
 ///
 /// This is particularly useful for layout calculations when working with styled
@@ -312,7 +682,7 @@ pub fn get_lines_visible(s: &str) -> (Vec<String>, usize) {
     let mut lines = Vec::new();
     let mut maxw = 0usize;
     for raw in s.split('\n') {
-        let clean = strip_ansi(raw);
+        let clean = strip_ansi(&expand_tabs(raw, crate::DEFAULT_TAB_WIDTH));
         let w = UnicodeWidthStr::width(clean.as_str());
         if w > maxw {
             maxw = w;
@@ -346,6 +716,255 @@ mod tests {
         // height counting remains separate behavior
         assert_eq!(height(s), 2);
     }
+
+    #[test]
+    fn test_truncate_ellipsis_closes_styles() {
+        let red = "\x1b[31mHello World\x1b[0m";
+        assert_eq!(
+            truncate_ellipsis(red, 8, ASCII_ELLIPSIS),
+            "\x1b[31mHello\x1b[0m..."
+        );
+        assert_eq!(truncate_ellipsis("中文字", 4, ELLIPSIS), "中 …");
+        assert_eq!(truncate_ellipsis("Hello", 0, ELLIPSIS), "");
+    }
+
+    #[test]
+    fn test_slice_ansi_plain() {
+        assert_eq!(slice_ansi("Hello, World", 0, 5), "Hello");
+        assert_eq!(slice_ansi("Hello, World", 7, 12), "World");
+        assert_eq!(slice_ansi("Hello, World", 3, 4), "l");
+        assert_eq!(slice_ansi("Hello", 0, 5), "Hello");
+    }
+
+    #[test]
+    fn test_slice_ansi_empty_range() {
+        assert_eq!(slice_ansi("Hello", 2, 2), "");
+        assert_eq!(slice_ansi("Hello", 4, 1), "");
+        assert_eq!(slice_ansi("\x1b[31mHello\x1b[0m", 3, 3), "");
+    }
+
+    #[test]
+    fn test_slice_ansi_pads_short_lines() {
+        assert_eq!(slice_ansi("abc", 1, 6), "bc   ");
+        assert_eq!(slice_ansi("abc", 5, 8), "   ");
+        assert_eq!(slice_ansi("", 0, 4), "    ");
+        // Padding comes after the style is closed
+        assert_eq!(slice_ansi("\x1b[31mab\x1b[0m", 0, 4), "\x1b[31mab\x1b[0m  ");
+        assert_eq!(slice_ansi("\x1b[31mab", 1, 4), "\x1b[31mb\x1b[0m  ");
+    }
+
+    #[test]
+    fn test_slice_ansi_carries_sgr_into_slice() {
+        let s = "\x1b[31mHello World\x1b[0m";
+        assert_eq!(slice_ansi(s, 6, 11), "\x1b[31mWorld\x1b[0m");
+        assert_eq!(slice_ansi(s, 0, 5), "\x1b[31mHello\x1b[0m");
+        assert_eq!(slice_ansi(s, 2, 4), "\x1b[31mll\x1b[0m");
+
+        // Stacked attributes are all re-emitted in order
+        let stacked = "\x1b[1m\x1b[4m\x1b[32mabcdef\x1b[0m";
+        assert_eq!(slice_ansi(stacked, 2, 4), "\x1b[1m\x1b[4m\x1b[32mcd\x1b[0m");
+    }
+
+    #[test]
+    fn test_slice_ansi_resets_before_slice_are_respected() {
+        // Style closed before the slice: nothing to carry, nothing to close
+        let s = "\x1b[31mred\x1b[0m plain";
+        assert_eq!(slice_ansi(s, 4, 9), "plain");
+
+        // A reset with parameters clears previous state before applying new ones
+        let s = "\x1b[1mab\x1b[0;34mcd";
+        assert_eq!(slice_ansi(s, 2, 4), "\x1b[0;34mcd\x1b[0m");
+        assert_eq!(slice_ansi(s, 3, 4), "\x1b[0;34md\x1b[0m");
+    }
+
+    #[test]
+    fn test_slice_ansi_copies_sequences_inside_slice() {
+        let s = "ab\x1b[31mcd\x1b[0mef";
+        assert_eq!(slice_ansi(s, 0, 6), s);
+        assert_eq!(slice_ansi(s, 1, 5), "b\x1b[31mcd\x1b[0me");
+        // Slice ends while the style is still open: it gets closed
+        assert_eq!(slice_ansi(s, 1, 3), "b\x1b[31mc\x1b[0m");
+        // Carried and newly opened state are both present
+        let s = "\x1b[1mab\x1b[31mcd";
+        assert_eq!(slice_ansi(s, 1, 4), "\x1b[1mb\x1b[31mcd\x1b[0m");
+    }
+
+    #[test]
+    fn test_slice_ansi_sequences_at_end_boundary_are_dropped() {
+        let s = "\x1b[31mab\x1b[32mcd";
+        assert_eq!(slice_ansi(s, 0, 2), "\x1b[31mab\x1b[0m");
+    }
+
+    #[test]
+    fn test_slice_ansi_wide_chars() {
+        // Whole wide characters are kept
+        assert_eq!(slice_ansi("中文字", 0, 4), "中文");
+        assert_eq!(slice_ansi("中文字", 2, 6), "文字");
+        // Straddling the start boundary
+        assert_eq!(slice_ansi("中文字", 1, 4), " 文");
+        // Straddling the end boundary
+        assert_eq!(slice_ansi("中文字", 0, 3), "中 ");
+        // Straddling both boundaries
+        assert_eq!(slice_ansi("中文字", 1, 5), " 文 ");
+        assert_eq!(slice_ansi("中文", 1, 3), "  ");
+        // A single column inside one wide character
+        assert_eq!(slice_ansi("中", 1, 2), " ");
+        // Mixed with ASCII
+        assert_eq!(slice_ansi("a中b", 0, 2), "a ");
+        assert_eq!(slice_ansi("a中b", 2, 4), " b");
+    }
+
+    #[test]
+    fn test_slice_ansi_wide_chars_keep_style() {
+        let s = "\x1b[35m中文\x1b[0m";
+        assert_eq!(slice_ansi(s, 1, 4), "\x1b[35m 文\x1b[0m");
+        assert_eq!(slice_ansi(s, 1, 5), "\x1b[35m 文\x1b[0m ");
+        assert_eq!(slice_ansi(s, 2, 4), "\x1b[35m文\x1b[0m");
+    }
+
+    #[test]
+    fn test_slice_ansi_zero_width_marks_follow_base() {
+        // e + combining acute accent
+        let s = "cafe\u{301} ok";
+        assert_eq!(slice_ansi(s, 0, 4), "cafe\u{301}");
+        assert_eq!(slice_ansi(s, 3, 5), "e\u{301} ");
+        assert_eq!(slice_ansi(s, 4, 7), " ok");
+    }
+
+    #[test]
+    fn test_slice_ansi_osc_hyperlinks() {
+        let open = "\x1b]8;;https://example.com\x1b\\";
+        let close = "\x1b]8;;\x1b\\";
+        let s = format!("see {}link text{} end", open, close);
+
+        // Slice fully inside the hyperlink re-opens and closes it
+        assert_eq!(slice_ansi(&s, 9, 13), format!("{}text{}", open, close));
+        // Slice after the hyperlink closed carries nothing
+        assert_eq!(slice_ansi(&s, 14, 17), "end");
+        // Slice spanning the whole link copies sequences through
+        assert_eq!(slice_ansi(&s, 4, 13), format!("{}link text{}", open, close));
+        // BEL-terminated OSC sequences are understood as well
+        let bel = "\x1b]8;;https://example.com\x07abc\x1b]8;;\x07";
+        assert_eq!(
+            slice_ansi(bel, 1, 2),
+            "\x1b]8;;https://example.com\x07b\x1b]8;;\x1b\\"
+        );
+    }
+
+    #[test]
+    fn test_slice_ansi_other_osc_is_not_visible() {
+        // Window title OSC before the slice contributes no columns
+        let s = "\x1b]0;title\x07abcdef";
+        assert_eq!(slice_ansi(s, 2, 4), "cd");
+        assert_eq!(slice_ansi(s, 0, 2), "\x1b]0;title\x07ab");
+    }
+
+    #[test]
+    fn test_slice_ansi_result_width_is_exact() {
+        let lines = [
+            "plain text line",
+            "\x1b[1;31mbold red\x1b[0m and \x1b[42mgreen bg\x1b[0m",
+            "中文 mixed 日本語",
+            "\x1b[36m한국어\x1b[0m text",
+            "",
+        ];
+        for line in lines {
+            for start in 0..12 {
+                for end in start..20 {
+                    let got = slice_ansi(line, start, end);
+                    assert_eq!(
+                        width_visible(&got),
+                        end - start,
+                        "line={:?} start={} end={} got={:?}",
+                        line,
+                        start,
+                        end,
+                        got
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_slice_ansi_unterminated_escape() {
+        // Malformed input must not panic or loop forever
+        let s = "ab\x1b[31";
+        assert_eq!(slice_ansi(s, 0, 3), "ab\x1b[31 ");
+        let s = "ab\x1b]8;;never-terminated";
+        assert_eq!(width_visible(&slice_ansi(s, 0, 4)), 4);
+    }
+
+    #[test]
+    fn test_strip_non_sgr() {
+        assert_eq!(strip_non_sgr("plain 漢字"), "plain 漢字");
+        assert_eq!(
+            strip_non_sgr("a\x1b[2Kb\x1b[3Cc\x1b[1;32md\x1b[0m"),
+            "abc\x1b[1;32md\x1b[0m"
+        );
+        // OSC and two-character escapes are dropped too
+        assert_eq!(strip_non_sgr("\x1b]0;title\x07x\x1b7y\x1b8"), "xy");
+        // Malformed input must not panic or swallow following text forever
+        assert_eq!(strip_non_sgr("ok\x1b[12"), "ok");
+        assert_eq!(strip_non_sgr("end\x1b"), "end");
+    }
+
+    #[test]
+    fn test_split_breakable_mixed_scripts() {
+        assert_eq!(split_breakable("hello"), vec!["hello"]);
+        assert_eq!(split_breakable("世界abc"), vec!["世", "界", "abc"]);
+        assert_eq!(split_breakable("abc世界"), vec!["abc", "世", "界"]);
+        // Kinsoku: closing punctuation and small kana stay with the previous
+        // character, opening brackets with the next one.
+        assert_eq!(split_breakable("はい。"), vec!["は", "い。"]);
+        assert_eq!(split_breakable("「東京」"), vec!["「東", "京」"]);
+        assert_eq!(split_breakable("ちょっと"), vec!["ちょっ", "と"]);
+    }
+
+    #[test]
+    fn test_split_breakable_keeps_escapes_attached() {
+        let s = "\x1b[31m東京\x1b[0m";
+        let parts = split_breakable(s);
+        assert_eq!(parts, vec!["\x1b[31m東", "京\x1b[0m"]);
+        assert_eq!(parts.concat(), s);
+    }
+
+    #[test]
+    fn test_prefix_lines_reopens_spans_after_prefix() {
+        assert_eq!(prefix_lines("", "> "), "");
+        assert_eq!(prefix_lines("a\n\nb", "> "), "> a\n> \n> b");
+        // Attributes set since the last reset, combined resets included,
+        // carry over to the next line behind the prefix.
+        assert_eq!(
+            prefix_lines("\x1b[1mx\x1b[0;31my\nz\x1b[m\nw", "> "),
+            "> \x1b[1mx\x1b[0;31my\x1b[0m\n> \x1b[0;31mz\x1b[m\n> w"
+        );
+        // Other sequences pass through without affecting the state.
+        assert_eq!(
+            prefix_lines("\x1b]8;;http://x\x07a\nb", "> "),
+            "> \x1b]8;;http://x\x07a\n> b"
+        );
+    }
+
+    #[test]
+    fn test_reapply_after_resets_forms() {
+        let base = "\x1b[44m";
+        assert_eq!(
+            reapply_after_resets("a\x1b[mb\x1b[0mc", base),
+            "a\x1b[m\x1b[44mb\x1b[0m\x1b[44mc"
+        );
+        // Combined resets keep the fragment's attributes after the base.
+        assert_eq!(
+            reapply_after_resets("\x1b[0;31mx", base),
+            "\x1b[0m\x1b[44m\x1b[31mx"
+        );
+        // Other sequences are left alone.
+        assert_eq!(
+            reapply_after_resets("\x1b[31mx\x1b[2K", base),
+            "\x1b[31mx\x1b[2K"
+        );
+        assert_eq!(reapply_after_resets("x\x1b[0m", ""), "x\x1b[0m");
+    }
 }
 
 /// Returns the number of lines in a string.
@@ -393,7 +1012,8 @@ pub fn height(s: &str) -> usize {
 /// This function processes multi-line text by splitting it at newline characters
 /// and calculating the maximum display width across all lines. Unlike [`get_lines_visible`],
 /// this function does NOT strip ANSI escape sequences, so styled text may affect
-/// the width calculations.
+/// the width calculations. Lines are returned unchanged, but tabs are measured
+/// to the next [`DEFAULT_TAB_WIDTH`](crate::DEFAULT_TAB_WIDTH) tab stop.
 ///
 /// # Arguments
 ///
@@ -444,6 +1064,371 @@ pub fn get_lines(s: &str) -> (Vec<&str>, usize) {
     (lines, maxw)
 }
 
+/// Reports whether a line may break next to `ch` without whitespace.
+///
+/// Han ideographs, kana, and CJK symbols are written without spaces, and a
+/// line may break between any two of them (as in UAX #14's ideographic
+/// class). Latin and other space-separated scripts are not breakable this way.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::is_breakable_ideograph;
+///
+/// assert!(is_breakable_ideograph('世'));
+/// assert!(is_breakable_ideograph('こ'));
+/// assert!(!is_breakable_ideograph('a'));
+/// ```
+pub fn is_breakable_ideograph(ch: char) -> bool {
+    matches!(ch,
+        '\u{2E80}'..='\u{2FDF}'     // CJK radicals, Kangxi radicals
+        | '\u{3000}'..='\u{303F}'   // CJK symbols and punctuation
+        | '\u{3040}'..='\u{30FF}'   // Hiragana, Katakana
+        | '\u{31F0}'..='\u{31FF}'   // Katakana phonetic extensions
+        | '\u{3400}'..='\u{4DBF}'   // CJK extension A
+        | '\u{4E00}'..='\u{9FFF}'   // CJK unified ideographs
+        | '\u{F900}'..='\u{FAFF}'   // CJK compatibility ideographs
+        | '\u{FF01}'..='\u{FF60}'   // Fullwidth forms
+        | '\u{FF61}'..='\u{FF9F}'   // Halfwidth katakana
+        | '\u{20000}'..='\u{3FFFF}' // Supplementary ideographic planes
+    )
+}
+
+/// Characters that must not start a line (closing punctuation, small kana).
+fn no_break_before(ch: char) -> bool {
+    matches!(
+        ch,
+        '、' | '。'
+            | '，'
+            | '．'
+            | '：'
+            | '；'
+            | '！'
+            | '？'
+            | '）'
+            | '」'
+            | '』'
+            | '】'
+            | '〕'
+            | '〉'
+            | '》'
+            | '〟'
+            | 'ー'
+            | '々'
+            | 'ゝ'
+            | 'ゞ'
+            | 'ヽ'
+            | 'ヾ'
+            | 'ぁ'
+            | 'ぃ'
+            | 'ぅ'
+            | 'ぇ'
+            | 'ぉ'
+            | 'っ'
+            | 'ゃ'
+            | 'ゅ'
+            | 'ょ'
+            | 'ァ'
+            | 'ィ'
+            | 'ゥ'
+            | 'ェ'
+            | 'ォ'
+            | 'ッ'
+            | 'ャ'
+            | 'ュ'
+            | 'ョ'
+            | ','
+            | '.'
+            | ')'
+            | '!'
+            | '?'
+            | ':'
+            | ';'
+    )
+}
+
+/// Characters that must not end a line (opening brackets).
+fn no_break_after(ch: char) -> bool {
+    matches!(
+        ch,
+        '（' | '「' | '『' | '【' | '〔' | '〈' | '《' | '〝' | '('
+    )
+}
+
+/// Splits a space-free word at the points where a line may break.
+///
+/// Runs of Latin (or other space-separated) text stay whole, while each
+/// ideograph or kana character becomes its own piece, except that closing
+/// punctuation stays with the character before it and opening brackets stay
+/// with the character after them. Escape sequences are kept with the piece
+/// they follow, so concatenating the pieces gives back `word`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::split_breakable;
+///
+/// assert_eq!(split_breakable("hello"), vec!["hello"]);
+/// assert_eq!(split_breakable("世界abc"), vec!["世", "界", "abc"]);
+/// assert_eq!(split_breakable("はい。"), vec!["は", "い。"]);
+/// ```
+pub fn split_breakable(word: &str) -> Vec<&str> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    let mut prev: Option<char> = None;
+    let mut i = 0;
+    while i < word.len() {
+        if word.as_bytes()[i] == 0x1b {
+            i += escape_len(&word[i..]);
+            continue;
+        }
+        let ch = word[i..].chars().next().unwrap_or(' ');
+        if let Some(p) = prev {
+            let ideographic = is_breakable_ideograph(ch) || is_breakable_ideograph(p);
+            if ideographic && !no_break_before(ch) && !no_break_after(p) && i > start {
+                pieces.push(&word[start..i]);
+                start = i;
+            }
+        }
+        // Zero-width marks attach to their base without changing the break class.
+        if unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0) > 0 {
+            prev = Some(ch);
+        }
+        i += ch.len_utf8();
+    }
+    if start < word.len() || pieces.is_empty() {
+        pieces.push(&word[start..]);
+    }
+    pieces
+}
+
+/// Returns the byte length of the escape sequence starting at `s[0]` (which
+/// must be ESC). CSI sequences end at their final byte, OSC sequences at BEL
+/// or ST, and anything else is treated as a two-character escape. Scanning is
+/// bounded so malformed input cannot cause unbounded work.
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::escape_len;
+///
+/// assert_eq!(escape_len("\x1b[1;31mtext"), 7);
+/// assert_eq!(escape_len("\x1b]8;;https://x\x07link"), 15);
+/// ```
+pub fn escape_len(s: &str) -> usize {
+    // Byte offset just past the first `n` characters (or the end of `s`)
+    let end_after = |n: usize| s.char_indices().nth(n).map_or(s.len(), |(i, _)| i);
+
+    let mut chars = s.char_indices().skip(1);
+    match chars.next() {
+        Some((_, '[')) => {
+            for (i, c) in chars.by_ref().take(MAX_ANSI_SEQ_LEN) {
+                if ('@'..='~').contains(&c) {
+                    return i + c.len_utf8();
+                }
+            }
+            end_after(2 + MAX_ANSI_SEQ_LEN)
+        }
+        Some((_, ']')) => {
+            let mut prev_esc = false;
+            for (i, c) in chars.by_ref().take(MAX_OSC_SEQ_LEN) {
+                if c == '\x07' || (prev_esc && c == '\\') {
+                    return i + c.len_utf8();
+                }
+                prev_esc = c == '\x1b';
+            }
+            end_after(2 + MAX_OSC_SEQ_LEN)
+        }
+        Some((i, c)) => i + c.len_utf8(),
+        None => 1,
+    }
+}
+
+/// Returns the display columns `start_col..end_col` of a single rendered line,
+/// keeping styling intact.
+///
+/// Escape sequences are skipped while counting columns. Any SGR state (and
+/// OSC 8 hyperlink) active at the cut point is re-emitted at the start of the
+/// slice, and closed again at the end so the slice never leaks styling into
+/// whatever follows it. Escape sequences encountered inside the slice are
+/// copied through unchanged.
+///
+/// The result is always exactly `end_col - start_col` columns wide:
+/// - A wide character straddling either boundary is replaced by a space for
+///   each of its columns that fall inside the slice.
+/// - If the line is shorter than `end_col`, the result is padded with
+///   (unstyled) spaces.
+///
+/// # Arguments
+///
+/// * `line` - A single line of text (may contain ANSI escape sequences)
+/// * `start_col` - First display column to keep (inclusive)
+/// * `end_col` - Display column to stop at (exclusive)
+///
+/// # Returns
+///
+/// The sliced line; empty if `end_col <= start_col`
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::{slice_ansi, width_visible};
+///
+/// assert_eq!(slice_ansi("Hello, World", 7, 12), "World");
+///
+/// // Styles active at the cut are re-opened and closed
+/// let red = "\x1b[31mHello World\x1b[0m";
+/// assert_eq!(slice_ansi(red, 6, 11), "\x1b[31mWorld\x1b[0m");
+///
+/// // Wide characters cut in half become spaces; short lines are padded
+/// assert_eq!(slice_ansi("中文", 1, 3), "  ");
+/// assert_eq!(width_visible(&slice_ansi("abc", 1, 6)), 5);
+/// ```
+pub fn slice_ansi(line: &str, start_col: usize, end_col: usize) -> String {
+    if end_col <= start_col {
+        return String::new();
+    }
+
+    let mut out = String::new();
+    // SGR sequences in effect since the last reset, and the open hyperlink
+    let mut sgr_state = SgrState::new();
+    let mut hyperlink: Option<&str> = None;
+    let mut opened = false;
+    let mut col = 0usize;
+    // Whether the last visible character was kept, so trailing zero-width
+    // marks (combining accents, variation selectors) follow their base
+    let mut kept_prev = start_col == 0;
+
+    // Re-emits the state carried in from before the slice, once
+    let open = |out: &mut String, opened: &mut bool, sgr: &[&str], link: Option<&str>| {
+        if !*opened {
+            *opened = true;
+            sgr.iter().for_each(|seq| out.push_str(seq));
+            if let Some(link) = link {
+                out.push_str(link);
+            }
+        }
+    };
+
+    let mut i = 0usize;
+    while i < line.len() {
+        let rest = &line[i..];
+        let ch = rest.chars().next().unwrap_or_default();
+
+        if ch == '\x1b' {
+            let seq = &rest[..escape_len(rest)];
+            i += seq.len();
+            if col >= end_col {
+                break;
+            }
+
+            let tracked = if sgr_state.apply(seq) {
+                true
+            } else if let Some(osc) = seq.strip_prefix("\x1b]8;") {
+                let target = osc.split_once(';').map(|(_, t)| t).unwrap_or("");
+                let closing = target.is_empty() || target == "\x07" || target == "\x1b\\";
+                hyperlink = if closing { None } else { Some(seq) };
+                true
+            } else {
+                false
+            };
+
+            // Until the slice is opened, tracked state is only recorded; it is
+            // emitted in one go in front of the first visible character.
+            if opened {
+                out.push_str(seq);
+            } else if col >= start_col && !tracked {
+                open(&mut out, &mut opened, sgr_state.sequences(), hyperlink);
+                out.push_str(seq);
+            }
+            continue;
+        }
+
+        i += ch.len_utf8();
+        let w = unicode_width::UnicodeWidthChar::width(ch).unwrap_or(0);
+
+        if w == 0 {
+            if kept_prev {
+                open(&mut out, &mut opened, sgr_state.sequences(), hyperlink);
+                out.push(ch);
+            }
+            continue;
+        }
+
+        let (c_start, c_end) = (col, col + w);
+        if c_start >= end_col {
+            break;
+        }
+        col = c_end;
+        if c_end <= start_col {
+            kept_prev = false;
+            continue;
+        }
+
+        open(&mut out, &mut opened, sgr_state.sequences(), hyperlink);
+        if c_start >= start_col && c_end <= end_col {
+            out.push(ch);
+            kept_prev = true;
+        } else {
+            // Wide character straddling a boundary
+            let inside = c_end.min(end_col) - c_start.max(start_col);
+            out.push_str(&safe_repeat(' ', inside));
+            kept_prev = false;
+        }
+    }
+
+    if opened && hyperlink.is_some() {
+        out.push_str("\x1b]8;;\x1b\\");
+    }
+    if opened && sgr_state.is_open() {
+        out.push_str("\x1b[0m");
+    }
+    let written = col.clamp(start_col, end_col) - start_col;
+    out.push_str(&safe_repeat(' ', (end_col - start_col) - written));
+    out
+}
+
+/// The ellipsis [`truncate_ellipsis`] callers use by default.
+pub const ELLIPSIS: &str = "…";
+
+/// An ellipsis for terminals without Unicode.
+pub const ASCII_ELLIPSIS: &str = "...";
+
+/// Cuts each line of `s` wider than `width` columns so that, with `ellipsis`
+/// appended, it is exactly `width` columns wide.
+///
+/// The ellipsis is measured by display width, so `"..."` leaves three fewer
+/// columns of text than `"…"` leaves one fewer. When the ellipsis is itself
+/// wider than `width`, it is cut to fit. Lines that fit are returned
+/// unchanged. Escape sequences do not count toward the width, and styling
+/// open at the cut is closed before the ellipsis, as with [`slice_ansi`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lipgloss::utils::{truncate_ellipsis, ASCII_ELLIPSIS, ELLIPSIS};
+///
+/// assert_eq!(truncate_ellipsis("Hello, World", 8, ELLIPSIS), "Hello, …");
+/// assert_eq!(truncate_ellipsis("Hello, World", 8, ASCII_ELLIPSIS), "Hello...");
+/// assert_eq!(truncate_ellipsis("Hello", 2, ASCII_ELLIPSIS), "..");
+/// assert_eq!(truncate_ellipsis("short\nlonger line", 6, "~"), "short\nlonge~");
+/// ```
+pub fn truncate_ellipsis(s: &str, width: usize, ellipsis: &str) -> String {
+    let tail = crate::width(ellipsis);
+    s.split('\n')
+        .map(|line| {
+            if crate::width(line) <= width {
+                line.to_string()
+            } else if tail >= width {
+                slice_ansi(ellipsis, 0, width)
+            } else {
+                format!("{}{}", slice_ansi(line, 0, width - tail), ellipsis)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 // -----------------------------
 // Style range helpers (Go parity)
 // -----------------------------
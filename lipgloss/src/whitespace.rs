@@ -1,5 +1,5 @@
-use crate::color::TerminalColor;
-use crate::renderer::Renderer;
+use crate::color::{StyleColor, TerminalColor};
+use crate::renderer::{ColorProfileKind, Renderer};
 use crate::security::safe_repeat;
 use crate::utils::{width as display_width, width_visible as visible_width};
 
@@ -39,7 +39,7 @@ use crate::utils::{width as display_width, width_visible as visible_width};
 #[derive(Debug)]
 pub struct Whitespace {
     re: Renderer,
-    style: String, // termenv.Style equivalent - will store ANSI codes
+    style: Vec<StyleSegment>, // termenv.Style equivalent, resolved at render time
     chars: String,
 }
 
@@ -84,7 +84,7 @@ pub struct Whitespace {
 pub fn new_whitespace(r: &Renderer, opts: &[WhitespaceOption]) -> Whitespace {
     let mut w = Whitespace {
         re: r.clone(),
-        style: String::new(), // Start with empty style, will be built by options
+        style: Vec::new(), // Start with empty style, will be built by options
         chars: String::new(),
     };
 
@@ -174,12 +174,67 @@ impl Whitespace {
         }
 
         // Apply styling like Go's w.style.Styled(b.String())
-        if !self.style.is_empty() {
-            format!("{}{}\x1b[0m", self.style, output)
+        let sgr = self.sgr();
+        if !sgr.is_empty() {
+            format!("\x1b[{}m{}\x1b[0m", sgr, output)
         } else {
             output
         }
     }
+
+    /// Builds the SGR parameters for the configured style, resolving colors
+    /// against the renderer's current profile.
+    fn sgr(&self) -> String {
+        let profile = self.re.color_profile();
+        let parts: Vec<String> = self
+            .style
+            .iter()
+            .filter_map(|seg| match seg {
+                StyleSegment::Attr(code) => Some(code.to_string()),
+                StyleSegment::Foreground(c) => color_sgr(&c.token(&self.re), profile, false),
+                StyleSegment::Background(c) => color_sgr(&c.token(&self.re), profile, true),
+            })
+            .collect();
+        parts.join(";")
+    }
+}
+
+/// One piece of a whitespace style, kept in the order options were applied.
+#[derive(Debug, Clone)]
+enum StyleSegment {
+    Attr(&'static str),
+    Foreground(StyleColor),
+    Background(StyleColor),
+}
+
+/// Converts a color token into SGR parameters for the given profile.
+///
+/// Returns `None` for empty tokens (the NoColor profile) and unparseable ones.
+fn color_sgr(token: &str, profile: ColorProfileKind, background: bool) -> Option<String> {
+    if token.is_empty() || matches!(profile, ColorProfileKind::NoColor) {
+        return None;
+    }
+    let (base, bright, extended) = if background {
+        (40, 100, 48)
+    } else {
+        (30, 90, 38)
+    };
+    if let Some(hex) = token.strip_prefix('#') {
+        // Parse #RRGGBB or #RRGGBBAA; ignore alpha if present.
+        if hex.len() < 6 {
+            return None;
+        }
+        let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(0);
+        let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(0);
+        let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0);
+        return Some(format!("{};2;{};{};{}", extended, r, g, b));
+    }
+    let idx = token.parse::<u32>().ok()? % 256;
+    match profile {
+        ColorProfileKind::ANSI if idx < 8 => Some(format!("{}", base + idx)),
+        ColorProfileKind::ANSI if idx < 16 => Some(format!("{}", bright + idx - 8)),
+        _ => Some(format!("{};5;{}", extended, idx)),
+    }
 }
 
 /// A configuration option for customizing whitespace appearance and behavior.
@@ -235,37 +290,9 @@ pub type WhitespaceOption = Box<dyn Fn(&mut Whitespace)>;
 /// // Result will include ANSI color codes for red text
 /// ```
 pub fn with_whitespace_foreground<C: TerminalColor + 'static>(c: C) -> WhitespaceOption {
+    let color = c.to_style_color();
     Box::new(move |w: &mut Whitespace| {
-        let fg_color = c.token(&w.re);
-        if fg_color.is_empty() {
-            // NoColor profile returns empty token; do not add malformed SGR.
-            return;
-        }
-        // Decide between 24-bit truecolor and indexed color based on token format.
-        // token starting with '#' denotes hex RGB (e.g. "#383838").
-        let new_seg = if let Some(hex) = fg_color.strip_prefix('#') {
-            // Parse #RRGGBB or #RRGGBBAA; ignore alpha if present.
-            // Fallback to 0 on parse errors.
-            let (r, g, b) = if hex.len() >= 6 {
-                let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(0);
-                let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(0);
-                let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0);
-                (r, g, b)
-            } else {
-                (0, 0, 0)
-            };
-            format!("38;2;{};{};{}", r, g, b)
-        } else {
-            // Numeric index for ANSI/ANSI256
-            format!("38;5;{}", fg_color)
-        };
-
-        if w.style.is_empty() {
-            w.style = format!("\x1b[{}m", new_seg);
-        } else {
-            let base = w.style.trim_end_matches('m');
-            w.style = format!("{};{}m", base, new_seg);
-        }
+        w.style.push(StyleSegment::Foreground(color.clone()));
     })
 }
 
@@ -298,32 +325,9 @@ pub fn with_whitespace_foreground<C: TerminalColor + 'static>(c: C) -> Whitespac
 /// // Result will include ANSI color codes for blue background
 /// ```
 pub fn with_whitespace_background<C: TerminalColor + 'static>(c: C) -> WhitespaceOption {
+    let color = c.to_style_color();
     Box::new(move |w: &mut Whitespace| {
-        let bg_color = c.token(&w.re);
-        if bg_color.is_empty() {
-            // NoColor profile returns empty token; do not add malformed SGR.
-            return;
-        }
-        let new_seg = if let Some(hex) = bg_color.strip_prefix('#') {
-            let (r, g, b) = if hex.len() >= 6 {
-                let r = u8::from_str_radix(&hex[0..2], 16).unwrap_or(0);
-                let g = u8::from_str_radix(&hex[2..4], 16).unwrap_or(0);
-                let b = u8::from_str_radix(&hex[4..6], 16).unwrap_or(0);
-                (r, g, b)
-            } else {
-                (0, 0, 0)
-            };
-            format!("48;2;{};{};{}", r, g, b)
-        } else {
-            format!("48;5;{}", bg_color)
-        };
-
-        if w.style.is_empty() {
-            w.style = format!("\x1b[{}m", new_seg);
-        } else {
-            let base = w.style.trim_end_matches('m');
-            w.style = format!("{};{}m", base, new_seg);
-        }
+        w.style.push(StyleSegment::Background(color.clone()));
     })
 }
 
@@ -353,12 +357,7 @@ pub fn with_whitespace_background<C: TerminalColor + 'static>(c: C) -> Whitespac
 /// ```
 pub fn with_whitespace_underline() -> WhitespaceOption {
     Box::new(move |w: &mut Whitespace| {
-        if w.style.is_empty() {
-            w.style = "\x1b[4m".to_string();
-        } else {
-            let base = w.style.trim_end_matches('m');
-            w.style = format!("{};4m", base);
-        }
+        w.style.push(StyleSegment::Attr("4"));
     })
 }
 
@@ -388,12 +387,7 @@ pub fn with_whitespace_underline() -> WhitespaceOption {
 /// ```
 pub fn with_whitespace_strikethrough() -> WhitespaceOption {
     Box::new(move |w: &mut Whitespace| {
-        if w.style.is_empty() {
-            w.style = "\x1b[9m".to_string();
-        } else {
-            let base = w.style.trim_end_matches('m');
-            w.style = format!("{};9m", base);
-        }
+        w.style.push(StyleSegment::Attr("9"));
     })
 }
 
@@ -1,6 +1,8 @@
 use lipgloss::{
-    border::{normal_border, rounded_border},
+    border::{normal_border, rounded_border, thick_border, BorderPiece},
+    join_horizontal,
     style::Style,
+    TOP,
 };
 
 #[test]
@@ -38,3 +40,179 @@ fn render_rounded_border_single_line() {
     );
     assert_eq!(out, expected);
 }
+
+/// The layout-demo tab row, built by mutating `Border` structs by hand.
+fn tab_row_from_borders() -> String {
+    let mut active_tab_border = rounded_border();
+    active_tab_border.bottom = " ";
+    active_tab_border.bottom_left = "┘";
+    active_tab_border.bottom_right = "└";
+
+    let mut tab_border = rounded_border();
+    tab_border.bottom_left = "┴";
+    tab_border.bottom_right = "┴";
+
+    let tab = Style::new().border(tab_border).padding(0, 1, 0, 1);
+    let active_tab = tab.clone().border_style(active_tab_border);
+    join_horizontal(
+        TOP,
+        &[
+            &active_tab.render("Lip Gloss"),
+            &tab.render("Blush"),
+            &tab.render("Eye Shadow"),
+        ],
+    )
+}
+
+/// The same row, built declaratively with `border_char`.
+fn tab_row_from_border_chars() -> String {
+    let tab = Style::new()
+        .border(rounded_border())
+        .border_char(BorderPiece::BottomLeft, "┴")
+        .border_char(BorderPiece::BottomRight, "┴")
+        .padding(0, 1, 0, 1);
+    let active_tab = tab
+        .clone()
+        .border_char(BorderPiece::Bottom, " ")
+        .border_char(BorderPiece::BottomLeft, "┘")
+        .border_char(BorderPiece::BottomRight, "└");
+    join_horizontal(
+        TOP,
+        &[
+            &active_tab.render("Lip Gloss"),
+            &tab.render("Blush"),
+            &tab.render("Eye Shadow"),
+        ],
+    )
+}
+
+#[test]
+fn border_char_rebuilds_layout_demo_tabs() {
+    let expected = [
+        "╭───────────╮╭───────╮╭────────────╮",
+        "│ Lip Gloss ││ Blush ││ Eye Shadow │",
+        "┘           └┴───────┴┴────────────┴",
+    ]
+    .join("\n");
+    assert_eq!(tab_row_from_borders(), expected);
+    assert_eq!(tab_row_from_border_chars(), expected);
+}
+
+#[test]
+fn border_char_survives_border_change() {
+    let s = Style::new()
+        .border(rounded_border())
+        .border_char(BorderPiece::TopLeft, "*")
+        .border_style(thick_border());
+    assert_eq!(s.render("x"), "*━┓\n┃x┃\n┗━┛");
+    assert_eq!(s.get_border_char(BorderPiece::TopLeft), Some("*"));
+
+    let s = s.unset_border_char(BorderPiece::TopLeft);
+    assert_eq!(
+        s.render("x"),
+        Style::new().border(thick_border()).render("x")
+    );
+}
+
+#[test]
+fn border_char_rejects_invalid_glyphs() {
+    let base = Style::new().border(normal_border());
+    for glyph in ["", "--", "太", "\x1b", "\t"] {
+        let s = base.clone().border_char(BorderPiece::Top, glyph);
+        assert_eq!(s.get_border_char(BorderPiece::Top), None, "{:?}", glyph);
+        assert_eq!(s.render("hi"), base.render("hi"));
+    }
+}
+
+#[test]
+fn border_char_inherits_per_piece() {
+    let parent = Style::new()
+        .border_char(BorderPiece::TopLeft, "+")
+        .border_char(BorderPiece::TopRight, "+");
+    let child = Style::new()
+        .border(normal_border())
+        .border_char(BorderPiece::TopLeft, "#")
+        .inherit(parent);
+    assert_eq!(child.get_border_char(BorderPiece::TopLeft), Some("#"));
+    assert_eq!(child.get_border_char(BorderPiece::TopRight), Some("+"));
+    assert_eq!(child.render("x"), "#─+\n│x│\n└─┘");
+
+    let plain = Style::new().border(normal_border());
+    assert!(!child.is_equivalent(&plain));
+    assert!(child.clone().unset_border_chars().is_equivalent(&plain));
+}
+
+/// The block rendered under every combination of the four side flags.
+///
+/// Each entry is (top, right, bottom, left, expected). As in Go, a corner is
+/// drawn only where both of its sides are; edges otherwise run flush with the
+/// content, so every line of a block has the same width.
+const SIDE_COMBINATIONS: [(bool, bool, bool, bool, &str); 16] = [
+    (false, false, false, false, "abc\ndef\nghi"),
+    (false, false, false, true, "│abc\n│def\n│ghi"),
+    (false, false, true, false, "abc\ndef\nghi\n───"),
+    (false, false, true, true, "│abc\n│def\n│ghi\n└───"),
+    (false, true, false, false, "abc│\ndef│\nghi│"),
+    (false, true, false, true, "│abc│\n│def│\n│ghi│"),
+    (false, true, true, false, "abc│\ndef│\nghi│\n───┘"),
+    (false, true, true, true, "│abc│\n│def│\n│ghi│\n└───┘"),
+    (true, false, false, false, "───\nabc\ndef\nghi"),
+    (true, false, false, true, "┌───\n│abc\n│def\n│ghi"),
+    (true, false, true, false, "───\nabc\ndef\nghi\n───"),
+    (true, false, true, true, "┌───\n│abc\n│def\n│ghi\n└───"),
+    (true, true, false, false, "───┐\nabc│\ndef│\nghi│"),
+    (true, true, false, true, "┌───┐\n│abc│\n│def│\n│ghi│"),
+    (true, true, true, false, "───┐\nabc│\ndef│\nghi│\n───┘"),
+    (true, true, true, true, "┌───┐\n│abc│\n│def│\n│ghi│\n└───┘"),
+];
+
+#[test]
+fn border_side_combinations_match_go() {
+    for (top, right, bottom, left, expected) in SIDE_COMBINATIONS {
+        let out = Style::new()
+            .border(normal_border())
+            .border_top(top)
+            .border_right(right)
+            .border_bottom(bottom)
+            .border_left(left)
+            .render("abc\ndef\nghi");
+        assert_eq!(
+            out, expected,
+            "top={top} right={right} bottom={bottom} left={left}"
+        );
+        let widths: Vec<usize> = out.lines().map(lipgloss::width).collect();
+        assert!(widths.windows(2).all(|w| w[0] == w[1]), "{out}");
+    }
+}
+
+#[test]
+fn border_colored_edge_without_corners_keeps_width() {
+    let mut r = lipgloss::renderer::Renderer::new();
+    r.set_color_profile(lipgloss::renderer::ColorProfileKind::TrueColor);
+    let out = Style::new()
+        .renderer(r)
+        .border(normal_border())
+        .border_left(false)
+        .border_right(false)
+        .border_foreground("#ff0000")
+        .render("ab");
+    assert_eq!(
+        out,
+        "\x1b[38;2;255;0;0m──\x1b[0m\nab\n\x1b[38;2;255;0;0m──\x1b[0m"
+    );
+}
+
+#[test]
+fn border_corners_override_each_corner() {
+    let style = Style::new()
+        .border(rounded_border())
+        .border_corners("1", "2", "3", "4");
+    assert_eq!(style.render("x"), "1─2\n│x│\n4─3");
+    assert_eq!(style.get_border_char(BorderPiece::BottomRight), Some("3"));
+
+    // Invalid glyphs leave that corner alone.
+    let partial = Style::new()
+        .border(rounded_border())
+        .border_corners("+", "++", "+", "");
+    assert_eq!(partial.render("x"), "+─╮\n│x│\n╰─+");
+}
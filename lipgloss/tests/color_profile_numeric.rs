@@ -1,5 +1,6 @@
-use lipgloss::color::{Color, TerminalColor};
+use lipgloss::color::{Color, CompleteColor, TerminalColor};
 use lipgloss::renderer::{ColorProfileKind, Renderer};
+use lipgloss::Style;
 
 #[test]
 fn numeric_string_passthrough_for_ansi_profiles() {
@@ -38,3 +39,55 @@ fn hex_converts_to_indices_for_limited_profiles() {
     r.set_color_profile(ColorProfileKind::ANSI);
     assert_eq!(c.token(&r), "9");
 }
+
+/// A color whose per-profile tokens stay hex, so rendering has to convert
+/// them itself.
+fn hex_everywhere(hex: &str) -> CompleteColor {
+    CompleteColor {
+        TrueColor: hex.to_string(),
+        ANSI256: hex.to_string(),
+        ANSI: hex.to_string(),
+    }
+}
+
+fn render_with(profile: ColorProfileKind, style: Style) -> String {
+    let mut r = Renderer::new();
+    r.set_color_profile(profile);
+    style.renderer(r).render("x")
+}
+
+#[test]
+fn hex_foreground_tokens_convert_for_limited_profiles() {
+    let fg = |hex| Style::new().foreground(hex_everywhere(hex));
+
+    assert_eq!(
+        render_with(ColorProfileKind::ANSI256, fg("#ff0000")),
+        "\x1b[38;5;196mx\x1b[0m"
+    );
+    assert_eq!(
+        render_with(ColorProfileKind::ANSI, fg("#800000")),
+        "\x1b[31mx\x1b[0m"
+    );
+    assert_eq!(
+        render_with(ColorProfileKind::ANSI, fg("#ff0000")),
+        "\x1b[91mx\x1b[0m"
+    );
+}
+
+#[test]
+fn hex_background_tokens_convert_for_limited_profiles() {
+    let bg = |hex| Style::new().background(hex_everywhere(hex));
+
+    assert_eq!(
+        render_with(ColorProfileKind::ANSI256, bg("#ff0000")),
+        "\x1b[48;5;196mx\x1b[0m"
+    );
+    assert_eq!(
+        render_with(ColorProfileKind::ANSI, bg("#800000")),
+        "\x1b[41mx\x1b[0m"
+    );
+    assert_eq!(
+        render_with(ColorProfileKind::ANSI, bg("#ff0000")),
+        "\x1b[101mx\x1b[0m"
+    );
+}
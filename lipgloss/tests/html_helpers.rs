@@ -0,0 +1,100 @@
+use lipgloss::{html, AdaptiveColor, Color, Style, UnderlineStyle, CENTER, RIGHT};
+
+#[test]
+fn style_css_maps_colors_and_attributes() {
+    let style = Style::new()
+        .foreground("#112233")
+        .background(Color::from("9"))
+        .bold(true)
+        .italic(true)
+        .underline(true)
+        .faint(true);
+    assert_eq!(
+        html::style_css(&style),
+        "color:#112233;background-color:#ff0000;font-weight:bold;font-style:italic;\
+         text-decoration:underline;opacity:0.6"
+    );
+}
+
+#[test]
+fn style_css_maps_underline_style_and_color() {
+    let style = Style::new()
+        .underline_style(UnderlineStyle::Curly)
+        .underline_color("#ff0000");
+    assert_eq!(
+        html::style_css(&style),
+        "text-decoration:underline;text-decoration-style:wavy;text-decoration-color:#ff0000"
+    );
+
+    // Neither applies once underline is off.
+    assert_eq!(html::style_css(&style.underline(false)), "");
+}
+
+#[test]
+fn style_css_swaps_colors_for_reverse() {
+    let style = Style::new()
+        .foreground("#ffffff")
+        .background("#000000")
+        .reverse(true);
+    assert_eq!(
+        html::style_css(&style),
+        "color:#000000;background-color:#ffffff"
+    );
+}
+
+#[test]
+fn style_css_alignment_only_when_set() {
+    assert_eq!(html::style_css(&Style::new()), "");
+    assert_eq!(
+        html::style_css(&Style::new().align_horizontal(CENTER)),
+        "text-align:center"
+    );
+    assert_eq!(
+        html::style_css(&Style::new().align_horizontal(RIGHT)),
+        "text-align:right"
+    );
+}
+
+#[test]
+fn padding_css_and_style_attr() {
+    assert_eq!(html::padding_css(&Style::new()), "");
+    assert_eq!(
+        html::padding_css(&Style::new().padding(1, 2, 0, 3)),
+        "padding:1em 2ch 0em 3ch"
+    );
+    assert_eq!(html::style_attr(""), "");
+    assert_eq!(html::style_attr("color:red"), " style=\"color:red\"");
+}
+
+#[test]
+fn escape_text_escapes_markup_and_strips_ansi() {
+    assert_eq!(
+        html::escape_text("\x1b[31m<a href=\"x\">Tom & Jerry's</a>\x1b[0m"),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+    );
+}
+
+#[test]
+fn style_css_resolves_adaptive_colors_for_dark_whatever_the_terminal() {
+    let style = Style::new().foreground(AdaptiveColor {
+        Light: "#000000",
+        Dark: "#ffffff",
+    });
+    // A light terminal must not change the export.
+    std::env::set_var("COLORFGBG", "0;15");
+    let css = html::style_css(&style);
+    std::env::remove_var("COLORFGBG");
+    assert_eq!(css, "color:#ffffff");
+}
+
+#[test]
+fn check_tags_reports_unbalanced_markup() {
+    assert_eq!(
+        html::check_tags("<div style=\"a\"><b>x</b></div>").unwrap(),
+        vec!["div".to_string(), "b".to_string()]
+    );
+    assert!(html::check_tags("<div><b>x</div></b>").is_err());
+    assert!(html::check_tags("<div>x").is_err());
+    assert!(html::check_tags("<div>x</div>>").is_err());
+    assert!(html::check_tags("<div").is_err());
+}
@@ -110,3 +110,126 @@ fn join_vertical_left_right_and_center_with_ansi() {
     let expected_center = format!("X \nY \n{}", b);
     assert_eq!(out_center, expected_center);
 }
+
+#[test]
+fn join_horizontal_wide_trailing_chars_keep_right_block_aligned() {
+    // The left block's longest line ends in a double-width character.
+    let left = "ab\n名前漢\nx";
+    let styled = "\x1b[1mok\x1b[0m\n\x1b[31m😀\x1b[0m";
+    let right = "R1\nR2\nR3";
+
+    for pos in [TOP, CENTER, BOTTOM] {
+        for blocks in [[left, right], [styled, left], [left, styled]] {
+            let out = join::join_horizontal(pos, &blocks);
+            let widths: Vec<usize> = out.split('\n').map(lipgloss::width).collect();
+            let expected: usize = blocks.iter().map(|b| lipgloss::width(b)).sum();
+            assert!(
+                widths.iter().all(|&w| w == expected),
+                "pos {:?}: uneven line widths {:?} in {:?}",
+                pos,
+                widths,
+                out
+            );
+        }
+    }
+
+    let out = join::join_horizontal(TOP, &[left, right]);
+    assert_eq!(out, "ab    R1\n名前漢R2\nx     R3");
+}
+
+#[test]
+fn join_vertical_wide_chars_pad_to_display_width() {
+    let cjk = "漢字\n名";
+    let emoji = "🎉🎉🎉";
+    let ansi = "\x1b[32mgreen\x1b[0m";
+
+    for pos in [LEFT, CENTER, RIGHT] {
+        let out = join::join_vertical(pos, &[cjk, emoji, ansi]);
+        let widths: Vec<usize> = out.split('\n').map(lipgloss::width).collect();
+        assert_eq!(widths, vec![6; 4], "pos {:?}: {:?}", pos, out);
+    }
+
+    let out = join::join_vertical(RIGHT, &[cjk, emoji]);
+    assert_eq!(out, "  漢字\n    名\n🎉🎉🎉");
+}
+
+#[test]
+fn join_horizontal_with_tab_block_matches_measured_width() {
+    let tabbed = "\tab\nx";
+    let plain = "Z\nZ";
+    assert_eq!(lipgloss::width(tabbed), 6);
+
+    let out = join::join_horizontal(TOP, &[tabbed, plain]);
+    assert_eq!(out, "    abZ\nx     Z");
+    assert!(!out.contains('\t'));
+    for line in out.split('\n') {
+        assert_eq!(lipgloss::width(line), 7, "{:?}", line);
+    }
+
+    // A rendered block and its raw source measure the same.
+    let rendered = lipgloss::Style::new().render(tabbed);
+    assert_eq!(lipgloss::width(&rendered), lipgloss::width(tabbed));
+    assert_eq!(
+        join::join_horizontal(TOP, &[&rendered, plain]),
+        join::join_horizontal(TOP, &[tabbed, plain])
+    );
+}
+
+#[test]
+fn join_vertical_with_tab_block_pads_to_expanded_width() {
+    let out = join::join_vertical(RIGHT, &["\tfoo", "bar"]);
+    assert_eq!(out, "    foo\n    bar");
+
+    let (lines, widest) = lipgloss::get_lines("\tfoo\nbar");
+    assert_eq!((lines, widest), (vec!["\tfoo", "bar"], 7));
+    let (lines, widest) = lipgloss::get_lines_visible("\t\x1b[1mfoo\x1b[0m");
+    assert_eq!((lines, widest), (vec!["    foo".to_string()], 7));
+}
+
+#[test]
+fn join_pads_outside_open_styling() {
+    // A block that ends with its background still open
+    let open = "\x1b[41mab";
+    let out = join::join_horizontal(TOP, &[open, "x\ny"]);
+    assert_eq!(out, "\x1b[41mab\x1b[0mx\n  y");
+
+    let out = join::join_vertical(LEFT, &[open, "wide"]);
+    assert_eq!(out, "\x1b[41mab\x1b[0m  \nwide");
+
+    // The reset ends the block's line, before the next line's padding
+    let out = join::join_vertical(RIGHT, &["wide", open, "z"]);
+    assert_eq!(out, "wide\n  \x1b[41mab\x1b[0m\n   z");
+}
+
+#[test]
+fn join_reopens_styling_on_the_next_line_of_a_block() {
+    // Bold spans both lines of the first block; the short first line is
+    // padded unstyled and bold picks up again on the second.
+    let out = join::join_horizontal(TOP, &["\x1b[1mab\ncdef\x1b[0m", "X\nY"]);
+    assert_eq!(out, "\x1b[1mab\x1b[0m  X\n\x1b[1mcdef\x1b[0mY");
+
+    let out = join::join_vertical(LEFT, &["\x1b[1mab\ncdef\x1b[0m", "wider"]);
+    assert_eq!(out, "\x1b[1mab\x1b[0m   \n\x1b[1mcdef\x1b[0m \nwider");
+
+    // A block's styling stays with it: the next block's lines start plain.
+    let out = join::join_vertical(LEFT, &["\x1b[31mred", "plain"]);
+    assert_eq!(out, "\x1b[31mred\x1b[0m  \nplain");
+    let out = join::join_horizontal(TOP, &["\x1b[31mr\n", "a\nb"]);
+    assert_eq!(out, "\x1b[31mr\x1b[0ma\n b");
+}
+
+#[test]
+fn join_collapses_duplicate_resets() {
+    let a = "\x1b[31mred\x1b[0m\x1b[0m";
+    let b = "\x1b[32mgreen\x1b[0m";
+    for out in [
+        join::join_horizontal(TOP, &[a, b]),
+        join::join_vertical(CENTER, &[a, b]),
+    ] {
+        assert!(!out.contains("\x1b[0m\x1b[0m"), "{:?}", out);
+    }
+    assert_eq!(
+        join::join_vertical(LEFT, &[a, b]),
+        "\x1b[31mred\x1b[0m  \n\x1b[32mgreen\x1b[0m"
+    );
+}
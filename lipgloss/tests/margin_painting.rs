@@ -0,0 +1,176 @@
+//! Which cells of a rendered block carry a background: the style's background
+//! covers content and padding, the border has its own colors and margins are
+//! transparent unless a margin background is set.
+
+use lipgloss::renderer::{ColorProfileKind, Renderer};
+use lipgloss::{normal_border, Style};
+
+const CONTENT_BG: &str = "48;5;57";
+const MARGIN_BG: &str = "48;5;196";
+const BORDER_BG: &str = "48;5;22";
+
+fn renderer() -> Renderer {
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::ANSI256);
+    r
+}
+
+/// The toggle-tree block style on a 256-color renderer.
+fn block() -> Style {
+    Style::new()
+        .renderer(renderer())
+        .background("57")
+        .padding(1, 2, 1, 2)
+        .margin(1, 3, 1, 3)
+}
+
+/// One character per cell: `B` for the content background, `M` for the
+/// margin background, `X` for the border background and `.` for none.
+fn background_map(rendered: &str) -> String {
+    let mut map = Vec::new();
+    for line in rendered.split('\n') {
+        let mut row = String::new();
+        let mut bg: Option<String> = None;
+        let mut chars = line.chars();
+        while let Some(c) = chars.next() {
+            if c != '\x1b' {
+                row.push(match bg.as_deref() {
+                    None => '.',
+                    Some(CONTENT_BG) => 'B',
+                    Some(MARGIN_BG) => 'M',
+                    Some(BORDER_BG) => 'X',
+                    Some(other) => panic!("unexpected background {}", other),
+                });
+                continue;
+            }
+            let seq: String = chars.by_ref().take_while(|&d| d != 'm').collect();
+            let params: Vec<&str> = seq.trim_start_matches('[').split(';').collect();
+            let mut i = 0;
+            while i < params.len() {
+                match params[i] {
+                    "" | "0" | "49" => bg = None,
+                    "38" => i += 2,
+                    "48" => {
+                        bg = Some(params[i..i + 3].join(";"));
+                        i += 2;
+                    }
+                    _ => {}
+                }
+                i += 1;
+            }
+        }
+        map.push(row);
+    }
+    map.join("\n")
+}
+
+#[test]
+fn test_margins_are_transparent_by_default() {
+    assert_eq!(
+        background_map(&block().render("hi\nthere")),
+        "...............\n\
+         ...BBBBBBBBB...\n\
+         ...BBBBBBBBB...\n\
+         ...BBBBBBBBB...\n\
+         ...BBBBBBBBB...\n\
+         ..............."
+    );
+
+    // A width and colored whitespace only widen the painted area.
+    assert_eq!(
+        background_map(&block().width(12).color_whitespace(true).render("hi")),
+        "..................\n\
+         ...BBBBBBBBBBBB...\n\
+         ...BBBBBBBBBBBB...\n\
+         ...BBBBBBBBBBBB...\n\
+         .................."
+    );
+}
+
+#[test]
+fn test_bordered_block_paints_up_to_the_padding() {
+    let style = block().border(normal_border());
+    let out = style.render("hi\nthere");
+    assert_eq!(
+        lipgloss::strip_ansi(&out),
+        "                 \n\
+         \x20  ┌─────────┐   \n\
+         \x20  │         │   \n\
+         \x20  │  hi     │   \n\
+         \x20  │  there  │   \n\
+         \x20  │         │   \n\
+         \x20  └─────────┘   \n\
+         \x20                "
+    );
+    assert_eq!(
+        background_map(&out),
+        ".................\n\
+         .................\n\
+         ....BBBBBBBBB....\n\
+         ....BBBBBBBBB....\n\
+         ....BBBBBBBBB....\n\
+         ....BBBBBBBBB....\n\
+         .................\n\
+         ................."
+    );
+
+    // The border carries its own background only.
+    assert_eq!(
+        background_map(&style.border_background("22").render("hi\nthere")),
+        ".................\n\
+         ...XXXXXXXXXXX...\n\
+         ...XBBBBBBBBBX...\n\
+         ...XBBBBBBBBBX...\n\
+         ...XBBBBBBBBBX...\n\
+         ...XBBBBBBBBBX...\n\
+         ...XXXXXXXXXXX...\n\
+         ................."
+    );
+}
+
+#[test]
+fn test_margin_background_opts_in() {
+    let out = block()
+        .border(normal_border())
+        .margin_background("196")
+        .render("hi\nthere");
+    assert_eq!(
+        background_map(&out),
+        "MMMMMMMMMMMMMMMMM\n\
+         MMM...........MMM\n\
+         MMM.BBBBBBBBB.MMM\n\
+         MMM.BBBBBBBBB.MMM\n\
+         MMM.BBBBBBBBB.MMM\n\
+         MMM.BBBBBBBBB.MMM\n\
+         MMM...........MMM\n\
+         MMMMMMMMMMMMMMMMM"
+    );
+}
+
+#[test]
+fn test_inherited_background_does_not_paint_margins() {
+    let parent = block();
+    let child = Style::new()
+        .renderer(renderer())
+        .margin(1, 3, 1, 3)
+        .inherit(parent.clone());
+    assert_eq!(child.get_margin_background(), None);
+    assert_eq!(
+        background_map(&child.render("hi")),
+        "........\n\
+         ...BB...\n\
+         ........"
+    );
+
+    // An explicit margin background is inherited like any other color.
+    let child = Style::new()
+        .renderer(renderer())
+        .margin(1, 3, 1, 3)
+        .inherit(parent.margin_background("196"));
+    assert_eq!(
+        background_map(&child.render("hi")),
+        "MMMMMMMM\n\
+         MMMBBMMM\n\
+         MMMMMMMM"
+    );
+}
@@ -0,0 +1,84 @@
+use lipgloss::color::{contrast_ratio, Color};
+use lipgloss::renderer::{ColorProfileKind, Renderer};
+use lipgloss::Style;
+
+fn renderer(profile: ColorProfileKind, dark: bool) -> Renderer {
+    let mut r = Renderer::new();
+    r.set_color_profile(profile);
+    r.set_has_dark_background(dark);
+    r
+}
+
+/// Extracts the SGR parameters of the first escape sequence.
+fn first_sgr(s: &str) -> Vec<String> {
+    let start = s.find("\x1b[").expect("missing SGR") + 2;
+    let end = start + s[start..].find('m').expect("unterminated SGR");
+    s[start..end].split(';').map(str::to_string).collect()
+}
+
+#[test]
+fn faint_bright_black_is_lifted_under_ansi() {
+    let r = renderer(ColorProfileKind::ANSI, true);
+    let base = Style::new().foreground("8").faint(true).renderer(r);
+
+    let plain = base.render("muted");
+    assert_eq!(first_sgr(&plain), vec!["2", "90"]);
+
+    let guarded = base.clone().min_contrast(4.5).render("muted");
+    let params = first_sgr(&guarded);
+    assert!(params.contains(&"2".to_string()), "faint is kept");
+    let code: u32 = params.last().unwrap().parse().unwrap();
+    assert_ne!(code, 90, "color should change: {:?}", guarded);
+
+    // Dimmed by faint, the chosen color still meets the ratio on black
+    let idx = if code >= 90 { code - 90 + 8 } else { code - 30 };
+    let (r, g, b) = match idx {
+        7 => (0xc0 / 2, 0xc0 / 2, 0xc0 / 2),
+        15 => (0xff / 2, 0xff / 2, 0xff / 2),
+        other => panic!("unexpected color index {}", other),
+    };
+    let dimmed = Color::from(format!("#{:02x}{:02x}{:02x}", r, g, b).as_str());
+    assert!(contrast_ratio(&dimmed, &Color::from("#000000")) >= 4.5);
+}
+
+#[test]
+fn guard_is_off_by_default_and_keeps_readable_colors() {
+    let r = renderer(ColorProfileKind::ANSI256, true);
+    let readable = Style::new().foreground("#ffffff").renderer(r.clone());
+    assert_eq!(
+        readable.render("x"),
+        readable.clone().min_contrast(4.5).render("x")
+    );
+
+    let dim = Style::new().foreground("#303030").renderer(r);
+    assert_eq!(dim.get_min_contrast(), 0.0);
+    assert_ne!(dim.render("x"), dim.clone().min_contrast(4.5).render("x"));
+}
+
+#[test]
+fn guard_darkens_on_light_backgrounds() {
+    let r = renderer(ColorProfileKind::TrueColor, false);
+    let style = Style::new()
+        .foreground("#dddddd")
+        .min_contrast(4.5)
+        .renderer(r);
+    let params = first_sgr(&style.render("x"));
+    assert_eq!(&params[..2], ["38", "2"]);
+    let rgb: Vec<u8> = params[2..].iter().map(|p| p.parse().unwrap()).collect();
+    let fg = Color::from(format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2]).as_str());
+    assert!(contrast_ratio(&fg, &Color::from("#ffffff")) >= 4.5);
+}
+
+#[test]
+fn guard_measures_against_explicit_background() {
+    let r = renderer(ColorProfileKind::TrueColor, false);
+    let style = Style::new()
+        .foreground("#777777")
+        .background("#707070")
+        .min_contrast(3.0)
+        .renderer(r);
+    let params = first_sgr(&style.render("x"));
+    let rgb: Vec<u8> = params[2..5].iter().map(|p| p.parse().unwrap()).collect();
+    let fg = Color::from(format!("#{:02x}{:02x}{:02x}", rgb[0], rgb[1], rgb[2]).as_str());
+    assert!(contrast_ratio(&fg, &Color::from("#707070")) >= 3.0);
+}
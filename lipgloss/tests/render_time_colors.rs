@@ -0,0 +1,136 @@
+use lipgloss::color::{AdaptiveColor, CompleteColor, TerminalColor};
+use lipgloss::renderer::{ColorProfileKind, Renderer};
+use lipgloss::whitespace::{
+    new_whitespace, with_whitespace_background, with_whitespace_foreground,
+};
+use lipgloss::{normal_border, Style};
+
+fn renderer(profile: ColorProfileKind) -> Renderer {
+    let mut r = Renderer::new();
+    r.set_color_profile(profile);
+    r.set_has_dark_background(true);
+    r
+}
+
+fn red() -> CompleteColor {
+    CompleteColor {
+        TrueColor: "#ff0000".to_string(),
+        ANSI256: "196".to_string(),
+        ANSI: "9".to_string(),
+    }
+}
+
+#[test]
+fn foreground_set_under_no_color_renders_after_profile_switch() {
+    let mut r = renderer(ColorProfileKind::NoColor);
+    let style = Style::new().foreground("#ff0000").renderer(r.clone());
+    assert_eq!(style.render("x"), "x");
+
+    r.set_color_profile(ColorProfileKind::TrueColor);
+    assert_eq!(style.render("x"), "\x1b[38;2;255;0;0mx\x1b[0m");
+}
+
+#[test]
+fn border_colors_follow_profile_changes() {
+    let mut r = renderer(ColorProfileKind::TrueColor);
+    let style = Style::new()
+        .border(normal_border())
+        .border_foreground(red())
+        .border_top_background(red())
+        .renderer(r.clone());
+
+    let truecolor = style.render("x");
+    assert!(truecolor.contains("\x1b[38;2;255;0;0;48;2;255;0;0m┌"));
+    assert!(truecolor.contains("\x1b[38;2;255;0;0m│"));
+
+    r.set_color_profile(ColorProfileKind::ANSI256);
+    let ansi256 = style.render("x");
+    assert!(ansi256.contains("\x1b[38;5;196;48;5;196m┌"));
+    assert!(ansi256.contains("\x1b[38;5;196m│"));
+
+    // CompleteColor's ANSI value is used as-is rather than a downsampled hex.
+    r.set_color_profile(ColorProfileKind::ANSI);
+    let ansi = style.render("x");
+    assert!(ansi.contains("\x1b[91;101m┌"));
+    assert!(ansi.contains("\x1b[91m│"));
+
+    r.set_color_profile(ColorProfileKind::NoColor);
+    assert!(!style.render("x").contains('\x1b'));
+}
+
+#[test]
+fn adaptive_colors_follow_background_changes() {
+    let mut r = renderer(ColorProfileKind::TrueColor);
+    let style = Style::new()
+        .foreground(AdaptiveColor {
+            Light: "#000000",
+            Dark: "#ffffff",
+        })
+        .renderer(r.clone());
+    assert!(style.render("x").contains("38;2;255;255;255"));
+
+    r.set_has_dark_background(false);
+    assert!(style.render("x").contains("38;2;0;0;0"));
+}
+
+#[test]
+fn margin_background_follows_profile_changes() {
+    let mut r = renderer(ColorProfileKind::TrueColor);
+    let style = Style::new()
+        .margin_left(1)
+        .margin_background(red())
+        .renderer(r.clone());
+    assert!(style.render("x").starts_with("\x1b[48;2;255;0;0m "));
+
+    r.set_color_profile(ColorProfileKind::ANSI);
+    assert!(style.render("x").starts_with("\x1b[101m "));
+}
+
+#[test]
+fn whitespace_colors_follow_profile_changes() {
+    let mut r = renderer(ColorProfileKind::TrueColor);
+    let ws = new_whitespace(
+        &r,
+        &[
+            with_whitespace_foreground(red()),
+            with_whitespace_background(red()),
+        ],
+    );
+    assert_eq!(ws.render(1), "\x1b[38;2;255;0;0;48;2;255;0;0m \x1b[0m");
+
+    r.set_color_profile(ColorProfileKind::ANSI256);
+    assert_eq!(ws.render(1), "\x1b[38;5;196;48;5;196m \x1b[0m");
+
+    r.set_color_profile(ColorProfileKind::ANSI);
+    assert_eq!(ws.render(1), "\x1b[91;101m \x1b[0m");
+
+    r.set_color_profile(ColorProfileKind::NoColor);
+    assert_eq!(ws.render(1), " ");
+}
+
+/// A color type defined outside the crate, resolved through a snapshot.
+struct Brand;
+
+impl TerminalColor for Brand {
+    fn token(&self, r: &Renderer) -> String {
+        match r.color_profile() {
+            ColorProfileKind::TrueColor => "#00ff00".to_string(),
+            ColorProfileKind::NoColor => String::new(),
+            _ => "2".to_string(),
+        }
+    }
+
+    fn rgba(&self) -> (u32, u32, u32, u32) {
+        (0, 255, 0, 0xFFFF)
+    }
+}
+
+#[test]
+fn custom_color_types_follow_profile_changes() {
+    let mut r = renderer(ColorProfileKind::ANSI);
+    let style = Style::new().foreground(Brand).renderer(r.clone());
+    assert_eq!(style.render("x"), "\x1b[32mx\x1b[0m");
+
+    r.set_color_profile(ColorProfileKind::TrueColor);
+    assert_eq!(style.render("x"), "\x1b[38;2;0;255;0mx\x1b[0m");
+}
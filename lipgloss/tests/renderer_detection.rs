@@ -157,3 +157,86 @@ fn global_setters_always_mutate_default() {
         assert!(has_dark_background());
     });
 }
+
+#[test]
+fn debug_report_round_trips_detected_settings() {
+    with_env_lock(|| {
+        clear_env(&["NO_COLOR", "COLORTERM"]);
+        env::set_var("TERM", "xterm-256color");
+        env::set_var("COLORFGBG", "0;15");
+
+        let r = Renderer::new_with_output(Output {
+            supports_ansi: true,
+            is_tty_like: true,
+        });
+        let report = r.debug_report();
+        assert_eq!(
+            report,
+            [
+                "lipgloss renderer report v1",
+                "profile = ANSI256",
+                "dark_background = false",
+                "output.supports_ansi = true",
+                "output.is_tty_like = true",
+                "env.NO_COLOR = (unset)",
+                "env.COLORTERM = (unset)",
+                "env.TERM = xterm-256color",
+                "env.COLORFGBG = 0;15",
+            ]
+            .join("\n")
+        );
+
+        // The replay ignores the environment it runs in.
+        clear_env(&["TERM", "COLORFGBG"]);
+        env::set_var("NO_COLOR", "1");
+        let replay = Renderer::from_debug_report(&report).unwrap();
+        assert_eq!(replay.color_profile(), ColorProfileKind::ANSI256);
+        assert!(!replay.has_dark_background());
+        assert_eq!(replay.debug_report(), report);
+
+        env::remove_var("NO_COLOR");
+    });
+}
+
+#[test]
+fn debug_report_redacts_unusual_values() {
+    with_env_lock(|| {
+        clear_env(&["NO_COLOR", "COLORFGBG"]);
+        env::set_var("TERM", "/home/someone/.terminfo/x");
+        env::set_var("COLORTERM", "x".repeat(100));
+
+        let report = Renderer::new().debug_report();
+        assert!(report.contains("\nenv.TERM = (redacted)\n"));
+        assert!(report.contains("\nenv.COLORTERM = (redacted)\n"));
+        assert!(!report.contains("someone"));
+
+        clear_env(&["TERM", "COLORTERM"]);
+    });
+}
+
+#[test]
+fn from_debug_report_rejects_malformed_reports() {
+    let err = Renderer::from_debug_report("profile = ANSI").unwrap_err();
+    assert_eq!(err.line, 1);
+
+    let err = Renderer::from_debug_report(
+        "lipgloss renderer report v1\nprofile = Sepia\ndark_background = true",
+    )
+    .unwrap_err();
+    assert_eq!(err.line, 2);
+    assert_eq!(
+        err.to_string(),
+        "invalid renderer report, line 2: unknown color profile"
+    );
+
+    let err =
+        Renderer::from_debug_report("lipgloss renderer report v1\nprofile = ANSI").unwrap_err();
+    assert_eq!(err.line, 0);
+
+    // Unknown keys from newer reports are skipped.
+    let r = Renderer::from_debug_report(
+        "lipgloss renderer report v1\nprofile = ANSI\ndark_background = true\nunicode = full",
+    )
+    .unwrap();
+    assert_eq!(r.color_profile(), ColorProfileKind::ANSI);
+}
@@ -8,10 +8,10 @@ fn inline_removes_newlines() {
 }
 
 #[test]
-fn tab_width_default_replaces_with_4_spaces() {
-    // Default tab width is 4
+fn tab_width_default_advances_to_column_4() {
+    // Default tab stops are every 4 columns
     let out = Style::default().render("a\tb");
-    assert_eq!(out, format!("a{}b", " ".repeat(4)));
+    assert_eq!(out, format!("a{}b", " ".repeat(3)));
 }
 
 #[test]
@@ -21,9 +21,9 @@ fn tab_width_zero_removes_tabs() {
 }
 
 #[test]
-fn tab_width_two_replaces_with_2_spaces() {
-    let out = Style::default().tab_width(2).render("x\ty");
-    assert_eq!(out, "x  y");
+fn tab_width_two_advances_to_next_even_column() {
+    let out = Style::default().tab_width(2).render("x\ty\t\tz");
+    assert_eq!(out, "x y   z");
 }
 
 #[test]
@@ -32,6 +32,63 @@ fn tab_width_minus_one_keeps_tabs() {
     assert_eq!(out, "x\ty");
 }
 
+#[test]
+fn tab_stops_count_display_columns() {
+    // Every tab lands on column 4, whether a wide character or styled text
+    // comes before it.
+    let content = "中\tx\n\x1b[31mab\x1b[0m\ty\n\tz";
+    assert_eq!(lipgloss::width(content), 5);
+    let (lines, widest) = lipgloss::get_lines_visible(content);
+    assert_eq!(
+        (lines, widest),
+        (vec!["中  x".into(), "ab  y".into(), "    z".into()], 5)
+    );
+
+    let out = Style::new()
+        .border(lipgloss::normal_border())
+        .width(8)
+        .render(content);
+    let lines: Vec<String> = out.lines().map(lipgloss::strip_ansi).collect();
+    assert_eq!(
+        lines,
+        [
+            "┌────────┐",
+            "│中  x   │",
+            "│ab  y   │",
+            "│    z   │",
+            "└────────┘"
+        ]
+    );
+    assert_eq!(lipgloss::width(&out), 10);
+    assert!(out.lines().all(|line| lipgloss::width(line) == 10));
+}
+
+#[test]
+fn tab_stops_measured_before_wrapping() {
+    // Tabs expand before wrapping, against the columns of the source line.
+    let out = Style::new()
+        .border(lipgloss::normal_border())
+        .tab_width(3)
+        .width(6)
+        .render("日本\tx\x1b[1m\tyz\x1b[0m\tq");
+    let lines: Vec<String> = out.lines().map(lipgloss::strip_ansi).collect();
+    assert_eq!(
+        lines,
+        ["┌──────┐", "│日本  │", "│x  yz │", "│q     │", "└──────┘"]
+    );
+    assert!(out.lines().all(|line| lipgloss::width(line) == 8));
+
+    // Left in place, a tab is measured as one cell.
+    let kept = Style::new()
+        .tab_width(lipgloss::NO_TAB_CONVERSION)
+        .render("中\tx");
+    assert_eq!(kept, "中\tx");
+    assert_eq!(
+        lipgloss::width_with_tabs("中\tx", lipgloss::NO_TAB_CONVERSION),
+        4
+    );
+}
+
 #[test]
 fn max_height_truncates_lines() {
     let s = Style::default().max_height(1);
@@ -49,6 +106,71 @@ fn max_width_truncates_visible_width_with_ansi_preserved() {
     assert_eq!(out, expected);
 }
 
+#[test]
+fn truncation_indicator_defaults_to_silent_clipping() {
+    let s = Style::default().max_width(3).max_height(1);
+    assert_eq!(s.render("abcdef\nnext"), "abc");
+    let cleared = s
+        .clone()
+        .truncation_indicator("…")
+        .unset_truncation_indicator();
+    assert_eq!(cleared.render("abcdef\nnext"), "abc");
+}
+
+#[test]
+fn truncation_indicator_fits_within_max_width() {
+    let s = Style::default().max_width(5).truncation_indicator("...");
+    assert_eq!(s.render("abcdefgh"), "ab...");
+    // Lines that already fit are left alone.
+    assert_eq!(s.render("abcde"), "abcde");
+    // An indicator wider than the budget is itself cut.
+    let narrow = Style::default().max_width(2).truncation_indicator("...");
+    assert_eq!(narrow.render("abcdef"), "..");
+}
+
+#[test]
+fn truncation_indicator_drops_wide_char_straddling_the_cut() {
+    let s = Style::default().max_width(4).truncation_indicator("…");
+    // "你" would span columns 3-4, leaving no room for the indicator.
+    assert_eq!(s.render("ab你好"), "ab…");
+    // "好" would straddle column 4 once "你" fills columns 1-2.
+    assert_eq!(s.render("你好世界"), "你…");
+    // A double-width indicator still fits the budget.
+    let wide = Style::default().max_width(5).truncation_indicator("中");
+    assert_eq!(wide.render("你好世界"), "你中");
+    assert_eq!(wide.render("ab你好"), "ab中");
+}
+
+#[test]
+fn truncation_indicator_is_ansi_aware() {
+    let input = "\x1b[31mabcdef\x1b[0m";
+    let out = Style::default()
+        .max_width(4)
+        .truncation_indicator("…")
+        .render(input);
+    assert_eq!(out, "\x1b[31mabc\x1b[0m…");
+    assert_eq!(lipgloss::width_visible(&out), 4);
+
+    // A styled indicator is closed in turn, and so is a span left open
+    // across the lines that max_height drops.
+    let out = Style::default()
+        .max_height(1)
+        .truncation_indicator("\x1b[2m…")
+        .render("\x1b[1mone\ntwo\x1b[0m");
+    assert_eq!(out, "\x1b[1mone\x1b[0m\x1b[2m…\x1b[0m");
+}
+
+#[test]
+fn truncation_indicator_marks_dropped_lines() {
+    let s = Style::default().max_height(2).truncation_indicator("…");
+    assert_eq!(s.render("one\ntwo\nthree"), "one\ntwo…");
+    // Nothing is marked when no lines are dropped.
+    assert_eq!(s.render("one\ntwo"), "one\ntwo");
+    // Both limits together still yield a single indicator.
+    let both = s.max_width(3);
+    assert_eq!(both.render("one\ntwo\nthree"), "one\ntw…");
+}
+
 #[test]
 fn align_horizontal_only_effective_with_width_or_multiline() {
     let base = Style::default().align_horizontal(position::RIGHT);
@@ -102,3 +224,34 @@ fn unset_boolean_attrs_clear_getters() {
     assert!(!s.get_blink());
     assert!(!s.get_faint());
 }
+
+#[test]
+fn width_wraps_between_ideographs_and_keeps_latin_words() {
+    let out = Style::new().width(8).render("Say こんにちは now");
+    let lines: Vec<&str> = out.lines().map(str::trim_end).collect();
+    assert_eq!(lines, vec!["Say こん", "にちは", "now"]);
+    assert!(out.lines().all(|l| lipgloss::width(l) == 8));
+}
+
+#[test]
+fn render_to_appends_what_render_returns() {
+    let styles = [
+        Style::new(),
+        Style::new()
+            .bold(true)
+            .foreground("#ff0000")
+            .padding(1, 2, 1, 2),
+        Style::new()
+            .background("#0000ff")
+            .width(12)
+            .align_horizontal(position::CENTER)
+            .border(lipgloss::rounded_border())
+            .margin(1, 2, 1, 2)
+            .margin_background("#00ff00"),
+    ];
+    for style in styles {
+        let mut buf = String::from("> ");
+        style.render_to(&mut buf, "one\nthree").unwrap();
+        assert_eq!(buf, format!("> {}", style.render("one\nthree")));
+    }
+}
@@ -0,0 +1,106 @@
+use lipgloss::renderer::{ColorProfileKind, Renderer};
+use lipgloss::utils::strip_ansi;
+use lipgloss::{width_visible, Color, Style};
+
+fn style(profile: ColorProfileKind) -> Style {
+    let mut r = Renderer::new();
+    r.set_color_profile(profile);
+    Style::new().renderer(r)
+}
+
+fn red_to_blue() -> [Color; 2] {
+    [Color::from("#ff0000"), Color::from("#0000ff")]
+}
+
+// Foreground SGR sequences in the order they appear
+fn fg_codes(s: &str) -> Vec<&str> {
+    s.match_indices("\x1b[38;2;")
+        .map(|(i, _)| &s[i..i + s[i..].find('m').unwrap() + 1])
+        .collect()
+}
+
+#[test]
+fn foreground_gradient_runs_from_first_to_last_stop() {
+    let out = style(ColorProfileKind::TrueColor)
+        .foreground_gradient(&red_to_blue())
+        .render("abc");
+    let codes = fg_codes(&out);
+    assert_eq!(codes.len(), 3);
+    assert_eq!(codes[0], "\x1b[38;2;255;0;0m");
+    assert_eq!(codes[2], "\x1b[38;2;0;0;255m");
+    assert_eq!(strip_ansi(&out), "abc");
+}
+
+#[test]
+fn wide_and_composed_characters_take_one_step() {
+    let out = style(ColorProfileKind::TrueColor)
+        .foreground_gradient(&red_to_blue())
+        .render("猫e\u{301}👍🏽");
+    assert_eq!(fg_codes(&out).len(), 3);
+    assert_eq!(width_visible(&out), width_visible("猫e\u{301}👍🏽"));
+}
+
+#[test]
+fn existing_escapes_are_kept_and_take_no_step() {
+    let out = style(ColorProfileKind::TrueColor)
+        .foreground_gradient(&red_to_blue())
+        .render("a\x1b[1mb\x1b[0mc");
+    assert_eq!(fg_codes(&out).len(), 3);
+    assert!(out.contains("\x1b[1m"));
+    assert_eq!(strip_ansi(&out), "abc");
+}
+
+#[test]
+fn gradient_restarts_per_line_unless_spanning_the_block() {
+    let base = style(ColorProfileKind::TrueColor).foreground_gradient(&red_to_blue());
+
+    let per_line = base.render("ab\ncd");
+    let lines: Vec<&str> = per_line.lines().collect();
+    assert_eq!(fg_codes(lines[0]), fg_codes(lines[1]));
+
+    let spanned = base.gradient_spans_block(true).render("ab\ncd");
+    let lines: Vec<&str> = spanned.lines().collect();
+    assert_eq!(fg_codes(lines[0])[0], "\x1b[38;2;255;0;0m");
+    assert_eq!(fg_codes(lines[1])[1], "\x1b[38;2;0;0;255m");
+    assert_ne!(fg_codes(lines[0]), fg_codes(lines[1]));
+}
+
+#[test]
+fn background_gradient_leaves_padding_on_the_plain_background() {
+    let out = style(ColorProfileKind::TrueColor)
+        .background("#000000")
+        .background_gradient(&red_to_blue())
+        .padding_left(2)
+        .render("ab");
+    // The padding comes before the first gradient step
+    let first_step = out.find("\x1b[48;2;255;0;0m").unwrap();
+    assert_eq!(strip_ansi(&out[..first_step]), "  ");
+    assert!(out.contains("\x1b[48;2;0;0;255m"));
+}
+
+#[test]
+fn gradients_follow_the_color_profile() {
+    let out = style(ColorProfileKind::ANSI256)
+        .foreground_gradient(&red_to_blue())
+        .render("ab");
+    assert!(out.starts_with("\x1b[38;5;196m"), "{:?}", out);
+
+    let out = style(ColorProfileKind::NoColor)
+        .foreground_gradient(&red_to_blue())
+        .render("ab");
+    assert_eq!(out, "ab");
+}
+
+#[test]
+fn gradient_is_inherited_and_unset() {
+    let parent = Style::new()
+        .foreground_gradient(&red_to_blue())
+        .gradient_spans_block(true);
+    let child = Style::new().inherit(parent.clone());
+    assert_eq!(child.get_foreground_gradient(), red_to_blue());
+    assert!(child.get_gradient_spans_block());
+    assert!(child.is_equivalent(&parent.colors_only()));
+
+    let cleared = child.unset_foreground_gradient();
+    assert!(cleared.get_foreground_gradient().is_empty());
+}
@@ -300,11 +300,11 @@ fn underline_and_strikethrough_with_spaces_render() {
 fn tab_width_conversion() {
     let s4 = Style::default().tab_width(4);
     let out4 = s4.render("a\tb");
-    assert_eq!(out4, "a    b"); // current impl expands to full width regardless of column
+    assert_eq!(out4, "a   b"); // advances to the tab stop at column 4
 
     let s2 = Style::default().tab_width(2);
     let out2 = s2.render("a\tb");
-    assert_eq!(out2, "a  b"); // expands to full width
+    assert_eq!(out2, "a b"); // advances to the tab stop at column 2
 }
 
 #[test]
@@ -429,7 +429,7 @@ fn tab_width_then_wrapping_interaction() {
     let s = Style::default().tab_width(4).width(4);
 
     let out = s.render("a\tbcd"); // expands to "a   bcd" (7 cols)
-    let want = "a   \n bcd"; // current impl wraps with a leading space on the next line
+    let want = "a   \nbcd "; // the break falls on the tab's spaces
     assert_eq!(
         out,
         want,
@@ -438,3 +438,42 @@ fn tab_width_then_wrapping_interaction() {
         show_escapes(want)
     );
 }
+
+#[test]
+fn background_continues_after_prestyled_child() {
+    use lipgloss::color::Color;
+    use lipgloss::renderer::{ColorProfileKind, Renderer};
+
+    let mut r = Renderer::new();
+    r.set_color_profile(ColorProfileKind::TrueColor);
+
+    let child = Style::new()
+        .renderer(r.clone())
+        .foreground(Color::from("#FF5F87"))
+        .render("child");
+    assert!(child.ends_with("\x1b[0m"));
+
+    let purple = "\x1b[48;2;125;86;244m";
+    let out = Style::new()
+        .renderer(r)
+        .background(Color::from("#7D56F4"))
+        .width(20)
+        .render(&format!("{} tail\nplain", child));
+
+    for line in out.lines() {
+        assert!(line.starts_with(purple), "{}", show_escapes(line));
+        let body = line.strip_suffix("\x1b[0m").expect("line closes its style");
+        // Every reset inside the line is followed by the background again.
+        for (i, _) in body.match_indices("\x1b[0m") {
+            assert!(
+                body[i + 4..].starts_with(purple),
+                "background dropped after reset: {}",
+                show_escapes(line)
+            );
+        }
+    }
+    assert_eq!(
+        lipgloss::strip_ansi(&out).lines().next(),
+        Some("child tail          ")
+    );
+}
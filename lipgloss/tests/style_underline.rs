@@ -0,0 +1,111 @@
+use lipgloss::{Color, ColorProfileKind, Renderer, Style, UnderlineStyle};
+
+fn style(profile: ColorProfileKind) -> Style {
+    let mut r = Renderer::new();
+    r.set_color_profile(profile);
+    Style::new().renderer(r)
+}
+
+#[test]
+fn underline_styles_in_true_color() {
+    let cases = [
+        (UnderlineStyle::Single, "4"),
+        (UnderlineStyle::Double, "4:2"),
+        (UnderlineStyle::Curly, "4:3"),
+        (UnderlineStyle::Dotted, "4:4"),
+        (UnderlineStyle::Dashed, "4:5"),
+    ];
+    for (shape, code) in cases {
+        let out = style(ColorProfileKind::TrueColor)
+            .underline_style(shape)
+            .render("ab");
+        assert_eq!(out, format!("\x1b[{code}mab\x1b[0m"), "{shape:?}");
+    }
+}
+
+#[test]
+fn underline_color_per_profile() {
+    let squiggle = |profile| {
+        style(profile)
+            .underline_style(UnderlineStyle::Curly)
+            .underline_color("#ff0000")
+            .render("ab")
+    };
+    assert_eq!(
+        squiggle(ColorProfileKind::TrueColor),
+        "\x1b[4:3;58;2;255;0;0mab\x1b[0m"
+    );
+    assert_eq!(
+        squiggle(ColorProfileKind::ANSI256),
+        "\x1b[4:3;58;5;196mab\x1b[0m"
+    );
+    // Without extended colors, a plain underline in the foreground color.
+    assert_eq!(squiggle(ColorProfileKind::ANSI), "\x1b[4mab\x1b[0m");
+    assert_eq!(squiggle(ColorProfileKind::NoColor), "\x1b[4mab\x1b[0m");
+}
+
+#[test]
+fn underline_color_combines_with_foreground() {
+    let out = style(ColorProfileKind::TrueColor)
+        .underline(true)
+        .foreground("#00ff00")
+        .underline_color(Color::from("9"))
+        .render("ab");
+    assert_eq!(out, "\x1b[4;38;2;0;255;0;58;2;255;0;0mab\x1b[0m");
+}
+
+#[test]
+fn underline_color_needs_underline() {
+    let base = style(ColorProfileKind::TrueColor).underline_color("#ff0000");
+    assert_eq!(base.render("ab"), "ab");
+    assert_eq!(
+        base.clone().underline(true).render("ab"),
+        "\x1b[4;58;2;255;0;0mab\x1b[0m"
+    );
+    assert_eq!(
+        base.underline_style(UnderlineStyle::Dotted)
+            .underline(false)
+            .render("ab"),
+        "ab"
+    );
+}
+
+#[test]
+fn underline_properties_inherit_and_unset() {
+    let parent = style(ColorProfileKind::TrueColor)
+        .underline_style(UnderlineStyle::Dashed)
+        .underline_color("#0000ff");
+    let child = style(ColorProfileKind::TrueColor).inherit(parent.clone());
+    assert!(child.get_underline());
+    assert_eq!(child.get_underline_style(), UnderlineStyle::Dashed);
+    assert_eq!(child.get_underline_color(), Some(Color::from("#0000ff")));
+    assert!(child.is_equivalent(&parent));
+
+    // The child's own settings win.
+    let own = Style::new()
+        .underline_style(UnderlineStyle::Curly)
+        .inherit(parent.clone());
+    assert_eq!(own.get_underline_style(), UnderlineStyle::Curly);
+
+    let cleared = parent
+        .clone()
+        .unset_underline_style()
+        .unset_underline_color();
+    assert!(cleared.get_underline());
+    assert_eq!(cleared.get_underline_style(), UnderlineStyle::Single);
+    assert_eq!(cleared.get_underline_color(), None);
+    assert!(!cleared.is_equivalent(&parent));
+}
+
+#[test]
+fn underline_properties_follow_their_class() {
+    let style = style(ColorProfileKind::TrueColor)
+        .underline_style(UnderlineStyle::Curly)
+        .underline_color("#ff0000");
+    let attrs = style.attributes_only();
+    assert_eq!(attrs.get_underline_style(), UnderlineStyle::Curly);
+    assert_eq!(attrs.get_underline_color(), None);
+    let colors = style.colors_only();
+    assert_eq!(colors.get_underline_style(), UnderlineStyle::Single);
+    assert_eq!(colors.get_underline_color(), Some(Color::from("#ff0000")));
+}